@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Aggregate Contract (Core Module)
+//!
+//! This module defines the [`Aggregate`] trait for event-sourced state. An
+//! aggregate starts from its `Default` value and is rebuilt by folding the events
+//! of its stream, in order, through [`Aggregate::apply`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{Aggregate, EventEnvelope, HexaError};
+//! use serde_json::json;
+//!
+//! #[derive(Default)]
+//! struct Counter {
+//!     total: i64,
+//! }
+//!
+//! impl Aggregate for Counter {
+//!     fn apply(&mut self, event: &EventEnvelope) -> Result<(), Box<dyn HexaError>> {
+//!         if event.event_type == "counter.added" {
+//!             self.total += event.payload["by"].as_i64().unwrap_or_default();
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let events = vec![
+//!     EventEnvelope::new("counter.added", json!({ "by": 2 })),
+//!     EventEnvelope::new("counter.added", json!({ "by": 3 })),
+//! ];
+//! assert_eq!(Counter::replay(&events).unwrap().total, 5);
+//! ```
+
+use super::error::HexaError;
+use crate::domain::value_objects::EventEnvelope;
+
+/// State rebuilt by folding the events of one stream.
+pub trait Aggregate: Default + Send + Sync {
+    /// Apply one event to the state.
+    ///
+    /// Events of unknown types should be ignored so that older code can replay
+    /// streams written by newer code.
+    ///
+    /// # Errors
+    ///
+    /// Return an error if the event payload cannot be interpreted.
+    fn apply(&mut self, event: &EventEnvelope) -> Result<(), Box<dyn HexaError>>;
+
+    /// Rebuild state from the default value by applying `events` in order.
+    ///
+    /// # Errors
+    ///
+    /// The first error returned by [`Aggregate::apply`].
+    fn replay<'a, I>(events: I) -> Result<Self, Box<dyn HexaError>>
+    where
+        I: IntoIterator<Item = &'a EventEnvelope>,
+    {
+        events
+            .into_iter()
+            .try_fold(Self::default(), |mut state, event| {
+                state.apply(event)?;
+                Ok(state)
+            })
+    }
+}
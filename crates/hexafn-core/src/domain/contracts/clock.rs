@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Clock Contract (Core Module)
+//!
+//! This module defines the [`Clock`] trait, the single source of wall-clock time
+//! and delays for hexaFn components. Code that stamps events, computes deadlines,
+//! waits for retries or enforces timeouts asks a clock instead of calling
+//! `Utc::now()` or `tokio::time::sleep` directly, so tests can substitute a
+//! [`ManualClock`](crate::ManualClock) and advance time deterministically.
+//!
+//! The pipeline engine installs its clock into every
+//! [`PipelineContext`](crate::PipelineContext); stages reach it through
+//! [`PipelineContext::clock`](crate::PipelineContext::clock).
+//!
+//! ## Example
+//!
+//! ```rust
+//! use chrono::{TimeZone, Utc};
+//! use hexafn_core::{Clock, ManualClock};
+//! use std::time::Duration;
+//!
+//! # tokio_test::block_on(async {
+//! let clock = ManualClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+//! let sleeper = {
+//!     let clock = clock.clone();
+//!     tokio::spawn(async move { clock.sleep(Duration::from_secs(60)).await })
+//! };
+//!
+//! while clock.sleeper_count() == 0 {
+//!     tokio::task::yield_now().await;
+//! }
+//! clock.advance(Duration::from_secs(60));
+//! sleeper.await.unwrap();
+//! assert_eq!(clock.now(), Utc.with_ymd_and_hms(2025, 1, 1, 0, 1, 0).unwrap());
+//! # });
+//! ```
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// Source of the current time and of delays.
+#[async_trait]
+pub trait Clock: Debug + Send + Sync {
+    /// Current wall-clock time.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Wait until `duration` has elapsed on this clock.
+    async fn sleep(&self, duration: Duration);
+}
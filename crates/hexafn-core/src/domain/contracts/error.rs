@@ -84,18 +84,10 @@
 //! // Classify errors for appropriate response strategies
 //! fn handle_error_by_kind(kind: HexaErrorKind) -> &'static str {
 //!     match kind {
-//!         HexaErrorKind::Validation | HexaErrorKind::NotFound => {
-//!             "User-fixable error - return 4xx status"
-//!         }
-//!         HexaErrorKind::Internal | HexaErrorKind::External => {
-//!             "System error - return 5xx status"
-//!         }
-//!         HexaErrorKind::Timeout => {
-//!             "Retry-able error - implement exponential backoff"
-//!         }
-//!         HexaErrorKind::Unknown => {
-//!             "Unknown error - log for investigation"
-//!         }
+//!         HexaErrorKind::Unknown => "Unknown error - log for investigation",
+//!         kind if kind.is_retryable() => "Retry-able error - implement exponential backoff",
+//!         kind if kind.is_client_fault() => "User-fixable error - return 4xx status",
+//!         _ => "System error - return 5xx status",
 //!     }
 //! }
 //!
@@ -122,6 +114,12 @@
 //! }
 //! ```
 
+use crate::domain::contracts::Clock;
+use crate::domain::services::SystemClock;
+use crate::domain::value_objects::ErrorReport;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error as StdError;
 use std::fmt::{Debug, Display};
 
 /// Represents the category of errors that can occur in the hexaFn system.
@@ -149,7 +147,7 @@ use std::fmt::{Debug, Display};
 ///     _ => println!("Other error type"),
 /// }
 /// ```
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum HexaErrorKind {
     /// Resource or entity was not found
     ///
@@ -191,6 +189,54 @@ pub enum HexaErrorKind {
     /// - Network infrastructure issues
     External,
 
+    /// Operation conflicts with the current state of a resource
+    ///
+    /// Typically occurs in Function and Forward phases when:
+    /// - Optimistic concurrency check fails
+    /// - Resource already exists
+    /// - Concurrent modification is detected
+    Conflict,
+
+    /// Caller is not authenticated or not permitted
+    ///
+    /// Commonly occurs in Feed and Forward phases when:
+    /// - Credentials are missing or expired
+    /// - Access to a store key or topic is denied
+    /// - Webhook signature verification fails
+    Unauthorized,
+
+    /// Caller exceeded a rate limit or quota
+    ///
+    /// Occurs mainly in Forward phases when:
+    /// - External API returns a throttling response
+    /// - Per-tenant quota is exhausted
+    /// - Publish rate on a topic is capped
+    RateLimited,
+
+    /// Dependency is temporarily unavailable
+    ///
+    /// Transient errors that are expected to resolve on their own:
+    /// - Connection refused or reset
+    /// - Service is overloaded or in maintenance
+    /// - Circuit breaker is open
+    Unavailable,
+
+    /// Operation was cancelled before completion
+    ///
+    /// Can occur in any phase when:
+    /// - Caller dropped the request
+    /// - Pipeline is shutting down
+    /// - Parent operation was aborted
+    Cancelled,
+
+    /// Data could not be encoded or decoded
+    ///
+    /// Commonly occurs in Feed and Format phases when:
+    /// - Event payload is malformed JSON
+    /// - Stored value does not match the expected type
+    /// - Message encoding is unsupported
+    Serialization,
+
     /// Unclassified or unexpected error
     ///
     /// Fallback for errors that don't fit other categories:
@@ -218,11 +264,87 @@ impl Display for HexaErrorKind {
             HexaErrorKind::Timeout => write!(f, "Timeout"),
             HexaErrorKind::Internal => write!(f, "Internal"),
             HexaErrorKind::External => write!(f, "External"),
+            HexaErrorKind::Conflict => write!(f, "Conflict"),
+            HexaErrorKind::Unauthorized => write!(f, "Unauthorized"),
+            HexaErrorKind::RateLimited => write!(f, "RateLimited"),
+            HexaErrorKind::Unavailable => write!(f, "Unavailable"),
+            HexaErrorKind::Cancelled => write!(f, "Cancelled"),
+            HexaErrorKind::Serialization => write!(f, "Serialization"),
             HexaErrorKind::Unknown => write!(f, "Unknown"),
         }
     }
 }
 
+impl HexaErrorKind {
+    /// All error kinds, in declaration order.
+    pub const ALL: [HexaErrorKind; 12] = [
+        HexaErrorKind::NotFound,
+        HexaErrorKind::Validation,
+        HexaErrorKind::Timeout,
+        HexaErrorKind::Internal,
+        HexaErrorKind::External,
+        HexaErrorKind::Conflict,
+        HexaErrorKind::Unauthorized,
+        HexaErrorKind::RateLimited,
+        HexaErrorKind::Unavailable,
+        HexaErrorKind::Cancelled,
+        HexaErrorKind::Serialization,
+        HexaErrorKind::Unknown,
+    ];
+
+    /// Returns whether retrying the same operation may succeed.
+    ///
+    /// Transient kinds (`Timeout`, `External`, `RateLimited`, `Unavailable`) are
+    /// retryable. Everything else fails the same way on every attempt, or, for
+    /// `Cancelled`, should not be attempted again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexafn_core::HexaErrorKind;
+    ///
+    /// assert!(HexaErrorKind::Unavailable.is_retryable());
+    /// assert!(!HexaErrorKind::Validation.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            HexaErrorKind::Timeout
+                | HexaErrorKind::External
+                | HexaErrorKind::RateLimited
+                | HexaErrorKind::Unavailable
+        )
+    }
+
+    /// Returns whether the error was caused by the caller's request rather than
+    /// by hexaFn or one of its dependencies.
+    ///
+    /// Client-fault kinds map to 4xx-style responses; all others to 5xx-style
+    /// responses. [`Serialization`](Self::Serialization) is not a client fault:
+    /// it usually means stored or encoded data does not have the shape the code
+    /// expects. Malformed client input should be reported as
+    /// [`Validation`](Self::Validation).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexafn_core::HexaErrorKind;
+    ///
+    /// assert!(HexaErrorKind::Unauthorized.is_client_fault());
+    /// assert!(!HexaErrorKind::Internal.is_client_fault());
+    /// ```
+    pub fn is_client_fault(&self) -> bool {
+        matches!(
+            self,
+            HexaErrorKind::NotFound
+                | HexaErrorKind::Validation
+                | HexaErrorKind::Conflict
+                | HexaErrorKind::Unauthorized
+                | HexaErrorKind::RateLimited
+        )
+    }
+}
+
 /// Represents the severity level of errors in the hexaFn system.
 ///
 /// Severity levels help determine appropriate response strategies,
@@ -253,7 +375,7 @@ impl Display for HexaErrorKind {
 ///     }
 /// }
 /// ```
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum HexaErrorSeverity {
     /// System-threatening error requiring immediate attention
     ///
@@ -549,6 +671,95 @@ pub trait HexaError: Debug + Display + Send + Sync + 'static {
             self.error_message(),
         )
     }
+
+    /// Returns the underlying cause of this error, if any.
+    ///
+    /// Used to build the cause chain in [`HexaError::to_report`]. The default
+    /// implementation reports no cause.
+    fn error_source(&self) -> Option<&(dyn StdError + 'static)> {
+        None
+    }
+
+    /// Returns structured key/value context for this error.
+    ///
+    /// The default implementation returns an empty map.
+    fn error_context(&self) -> BTreeMap<String, String> {
+        BTreeMap::new()
+    }
+
+    /// Builds a serializable [`ErrorReport`] for this error.
+    ///
+    /// The report contains the code, kind, severity, message, display text,
+    /// cause chain, context, and the time the report was created, read from
+    /// the [`SystemClock`]. Use [`HexaError::to_report_with`] to stamp the
+    /// report from another [`Clock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexafn_core::{HexaError, HexaErrorKind, PipelineError};
+    ///
+    /// let report = PipelineError::EmptyPipeline.to_report();
+    /// assert_eq!(report.code, "core.pipeline.empty");
+    /// assert_eq!(report.kind, HexaErrorKind::Validation);
+    /// assert_eq!(report.detail, "pipeline has no stages");
+    ///
+    /// let json = serde_json::to_value(&report).unwrap();
+    /// assert_eq!(json["severity"], "High");
+    /// ```
+    fn to_report(&self) -> ErrorReport {
+        self.to_report_with(&SystemClock)
+    }
+
+    /// Builds a serializable [`ErrorReport`] stamped with `clock`'s current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use hexafn_core::{HexaError, ManualClock, PipelineError};
+    ///
+    /// let clock = ManualClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+    /// let report = PipelineError::EmptyPipeline.to_report_with(&clock);
+    /// assert_eq!(report.timestamp, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+    /// ```
+    fn to_report_with(&self, clock: &dyn Clock) -> ErrorReport {
+        let causes = std::iter::successors(self.error_source(), |error| (*error).source())
+            .map(|error| error.to_string())
+            .collect();
+        ErrorReport {
+            code: self.error_code().to_string(),
+            kind: self.error_kind(),
+            severity: self.error_severity(),
+            message: self.error_message().to_string(),
+            detail: self.to_string(),
+            causes,
+            context: self.error_context(),
+            timestamp: clock.now(),
+        }
+    }
+}
+
+/// Boxes any concrete [`HexaError`], so `?` converts it into `Box<dyn HexaError>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexafn_core::{HexaError, PipelineError};
+///
+/// fn check(stages: usize) -> Result<(), Box<dyn HexaError>> {
+///     if stages == 0 {
+///         Err(PipelineError::EmptyPipeline)?;
+///     }
+///     Ok(())
+/// }
+///
+/// assert_eq!(check(0).unwrap_err().error_code(), "core.pipeline.empty");
+/// ```
+impl<E: HexaError> From<E> for Box<dyn HexaError> {
+    fn from(error: E) -> Self {
+        Box::new(error)
+    }
 }
 
 #[cfg(test)]
@@ -599,6 +810,47 @@ mod tests {
             assert_eq!(format!("{}", HexaErrorKind::Internal), "Internal");
             assert_eq!(format!("{}", HexaErrorKind::External), "External");
             assert_eq!(format!("{}", HexaErrorKind::Unknown), "Unknown");
+            for kind in HexaErrorKind::ALL {
+                assert_eq!(format!("{}", kind), format!("{:?}", kind));
+            }
+        }
+
+        #[test]
+        fn test_error_kind_retryability() {
+            let retryable: Vec<HexaErrorKind> = HexaErrorKind::ALL
+                .into_iter()
+                .filter(HexaErrorKind::is_retryable)
+                .collect();
+            assert_eq!(
+                retryable,
+                [
+                    HexaErrorKind::Timeout,
+                    HexaErrorKind::External,
+                    HexaErrorKind::RateLimited,
+                    HexaErrorKind::Unavailable,
+                ]
+            );
+        }
+
+        #[test]
+        fn test_error_kind_client_fault() {
+            let client: Vec<HexaErrorKind> = HexaErrorKind::ALL
+                .into_iter()
+                .filter(HexaErrorKind::is_client_fault)
+                .collect();
+            assert_eq!(
+                client,
+                [
+                    HexaErrorKind::NotFound,
+                    HexaErrorKind::Validation,
+                    HexaErrorKind::Conflict,
+                    HexaErrorKind::Unauthorized,
+                    HexaErrorKind::RateLimited,
+                ]
+            );
+            assert!(!HexaErrorKind::Serialization.is_client_fault());
+            assert!(!HexaErrorKind::Cancelled.is_client_fault());
+            assert!(!HexaErrorKind::Cancelled.is_retryable());
         }
 
         #[test]
@@ -637,8 +889,15 @@ mod tests {
                 HexaErrorKind::Timeout,
                 HexaErrorKind::Internal,
                 HexaErrorKind::External,
+                HexaErrorKind::Conflict,
+                HexaErrorKind::Unauthorized,
+                HexaErrorKind::RateLimited,
+                HexaErrorKind::Unavailable,
+                HexaErrorKind::Cancelled,
+                HexaErrorKind::Serialization,
                 HexaErrorKind::Unknown,
             ];
+            assert_eq!(variants, HexaErrorKind::ALL);
 
             // Check that all variants are different
             for (i, variant1) in variants.iter().enumerate() {
@@ -776,6 +1035,8 @@ mod tests {
                 (HexaErrorKind::Timeout, "Timeout"),
                 (HexaErrorKind::Internal, "Internal"),
                 (HexaErrorKind::External, "External"),
+                (HexaErrorKind::Conflict, "Conflict"),
+                (HexaErrorKind::RateLimited, "RateLimited"),
                 (HexaErrorKind::Unknown, "Unknown"),
             ];
 
@@ -1033,8 +1294,64 @@ mod tests {
             let log = error.to_log_entry();
             assert!(log.contains("test.default.log"));
         }
+
+        #[test]
+        fn test_default_trait_method_to_report() {
+            let error: Box<dyn HexaError> = Box::new(TestError {
+                code: "test.default.report".into(),
+                message: "From default trait".into(),
+                kind: HexaErrorKind::Timeout,
+                severity: HexaErrorSeverity::High,
+            });
+
+            let report = error.to_report();
+            assert_eq!(report.code, "test.default.report");
+            assert_eq!(report.kind, HexaErrorKind::Timeout);
+            assert_eq!(report.severity, HexaErrorSeverity::High);
+            assert_eq!(report.detail, "From default trait");
+            assert!(report.causes.is_empty());
+            assert!(report.context.is_empty());
+            assert!(error.error_source().is_none());
+        }
+
+        #[test]
+        fn test_to_report_with_takes_timestamp_from_clock() {
+            use crate::ManualClock;
+            use chrono::{TimeZone, Utc};
+            use std::time::Duration;
+
+            let clock = ManualClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+            clock.advance(Duration::from_secs(90));
+            let error: Box<dyn HexaError> = Box::new(TestError {
+                code: "test.default.report".into(),
+                message: "From default trait".into(),
+                kind: HexaErrorKind::Timeout,
+                severity: HexaErrorSeverity::High,
+            });
+
+            let report = error.to_report_with(&clock);
+            assert_eq!(
+                report.timestamp,
+                Utc.with_ymd_and_hms(2025, 1, 1, 0, 1, 30).unwrap()
+            );
+            assert_eq!(report.code, "test.default.report");
+        }
+
+        #[test]
+        fn test_kind_and_severity_serde_roundtrip() {
+            let kind_json = serde_json::to_string(&HexaErrorKind::NotFound).unwrap();
+            assert_eq!(kind_json, "\"NotFound\"");
+            let kind: HexaErrorKind = serde_json::from_str(&kind_json).unwrap();
+            assert_eq!(kind, HexaErrorKind::NotFound);
+
+            let severity_json = serde_json::to_string(&HexaErrorSeverity::Critical).unwrap();
+            assert_eq!(severity_json, "\"Critical\"");
+            let severity: HexaErrorSeverity = serde_json::from_str(&severity_json).unwrap();
+            assert_eq!(severity, HexaErrorSeverity::Critical);
+        }
     }
 
+    #[allow(clippy::useless_vec)]
     mod integration_tests {
         use super::*;
 
@@ -1046,6 +1363,12 @@ mod tests {
                 (HexaErrorKind::Timeout, "Performance errors"),
                 (HexaErrorKind::Internal, "System errors"),
                 (HexaErrorKind::External, "Dependency errors"),
+                (HexaErrorKind::Conflict, "State errors"),
+                (HexaErrorKind::Unauthorized, "Access errors"),
+                (HexaErrorKind::RateLimited, "Quota errors"),
+                (HexaErrorKind::Unavailable, "Availability errors"),
+                (HexaErrorKind::Cancelled, "Cancellation errors"),
+                (HexaErrorKind::Serialization, "Encoding errors"),
                 (HexaErrorKind::Unknown, "Unclassified errors"),
             ];
 
@@ -1064,6 +1387,12 @@ mod tests {
                     HexaErrorKind::Timeout => assert!(category.contains("Performance")),
                     HexaErrorKind::Internal => assert!(category.contains("System")),
                     HexaErrorKind::External => assert!(category.contains("Dependency")),
+                    HexaErrorKind::Conflict => assert!(category.contains("State")),
+                    HexaErrorKind::Unauthorized => assert!(category.contains("Access")),
+                    HexaErrorKind::RateLimited => assert!(category.contains("Quota")),
+                    HexaErrorKind::Unavailable => assert!(category.contains("Availability")),
+                    HexaErrorKind::Cancelled => assert!(category.contains("Cancellation")),
+                    HexaErrorKind::Serialization => assert!(category.contains("Encoding")),
                     HexaErrorKind::Unknown => assert!(category.contains("Unclassified")),
                 }
             }
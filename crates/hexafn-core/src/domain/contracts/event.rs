@@ -8,10 +8,19 @@
 //! in the 6F Lifecycle Flow (Feed → Filter → Format → Function → Forward → Feedback).
 //!
 //! ## Design
-//! - Each event has a unique identity (`EventId`)
+//! - Each event has a unique identity (`EventId`), random by default or
+//!   time-ordered / deterministic via an [`IdGenerator`](crate::IdGenerator)
 //! - Events are immutable and serializable
 //! - The `Event` trait is implemented by all event types in the system
 //!
+//! ## Breaking Change: `event_type` Lifetime
+//!
+//! [`Event::event_type`] returns `&str` borrowed from the event instead of
+//! `&'static str`, so types loaded at runtime can be reported. Implementations
+//! that return a string literal compile unchanged. Callers that kept the result
+//! beyond the event's lifetime, e.g. as a `&'static str` map key, must copy it
+//! with `.to_string()` or keep the event alive.
+//!
 //! ## Example
 //!
 //! ```rust
@@ -38,6 +47,7 @@
 
 use std::fmt::Display;
 
+use crate::domain::value_objects::EventIdError;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -88,6 +98,87 @@ impl EventId {
     pub fn from_string(s: &str) -> Result<Self, uuid::Error> {
         Ok(Self(Uuid::parse_str(s)?))
     }
+
+    /// Creates a new time-ordered UUIDv7 event id.
+    ///
+    /// Ids created by this process sort in creation order, which keeps storage
+    /// indexes append-mostly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexafn_core::EventId;
+    /// let first = EventId::new_v7();
+    /// let second = EventId::new_v7();
+    /// assert!(first.0 < second.0);
+    /// ```
+    pub fn new_v7() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Parses an event id from its 26-character ULID text form.
+    ///
+    /// Decoding is case-insensitive and accepts the Crockford aliases `I`/`L`
+    /// for `1` and `O` for `0`.
+    ///
+    /// # Errors
+    /// Returns [`EventIdError::InvalidUlid`] if the text is not a valid ULID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hexafn_core::EventId;
+    /// let id = EventId::from_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+    /// assert_eq!(id.to_ulid(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    /// ```
+    pub fn from_ulid(s: &str) -> Result<Self, EventIdError> {
+        let invalid = |reason: &str| EventIdError::InvalidUlid {
+            value: s.to_string(),
+            reason: reason.to_string(),
+        };
+        if s.len() != ULID_LENGTH {
+            return Err(invalid("expected 26 characters"));
+        }
+        let mut value: u128 = 0;
+        for (index, byte) in s.bytes().enumerate() {
+            let digit = crockford_digit(byte).ok_or_else(|| invalid("invalid character"))?;
+            // The first character only carries 3 of the 130 encoded bits.
+            if index == 0 && digit > 7 {
+                return Err(invalid("value exceeds 128 bits"));
+            }
+            value = (value << 5) | u128::from(digit);
+        }
+        Ok(Self(Uuid::from_u128(value)))
+    }
+
+    /// Formats the id in the 26-character ULID text form.
+    ///
+    /// Any id can be formatted; for ids from a ULID or UUIDv7 generator the text
+    /// sorts in creation order.
+    pub fn to_ulid(&self) -> String {
+        let value = self.0.as_u128();
+        (0..ULID_LENGTH)
+            .rev()
+            .map(|index| CROCKFORD[((value >> (index * 5)) & 0x1f) as usize] as char)
+            .collect()
+    }
+}
+
+/// Length of the ULID text form.
+const ULID_LENGTH: usize = 26;
+
+/// Crockford's base32 alphabet used by ULIDs.
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+fn crockford_digit(byte: u8) -> Option<u8> {
+    match byte.to_ascii_uppercase() {
+        b'O' => Some(0),
+        b'I' | b'L' => Some(1),
+        upper => CROCKFORD
+            .iter()
+            .position(|&c| c == upper)
+            .map(|index| index as u8),
+    }
 }
 
 impl Default for EventId {
@@ -126,7 +217,12 @@ impl Display for EventId {
 /// }
 /// ```
 pub trait Event: Send + Sync {
-    /// Returns the static event type identifier.
+    /// Returns the event type identifier.
+    ///
+    /// Most event types return a string literal; events whose type is only known
+    /// at runtime, such as [`EventEnvelope`](crate::EventEnvelope), return a
+    /// borrowed field. Callers that need the value beyond the event's
+    /// lifetime must copy it.
     ///
     /// # Examples
     ///
@@ -143,7 +239,7 @@ pub trait Event: Send + Sync {
     /// let e = Evt { id: EventId::new(), occurred_at: Utc::now() };
     /// assert_eq!(e.event_type(), "evt.type");
     /// ```
-    fn event_type(&self) -> &'static str;
+    fn event_type(&self) -> &str;
 
     /// Returns the unique event id.
     ///
@@ -203,6 +299,32 @@ pub trait Event: Send + Sync {
     /// assert_eq!(payload["value"], 42);
     /// ```
     fn payload(&self) -> serde_json::Value;
+
+    /// Returns the schema version of the payload.
+    ///
+    /// Bump it whenever the payload shape of an event type changes, and register
+    /// an [`Upcaster`](crate::Upcaster) that converts the previous version. Defaults
+    /// to `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use hexafn_core::{Event, EventId};
+    /// # use chrono::Utc;
+    /// struct Evt { id: EventId, occurred_at: chrono::DateTime<Utc> }
+    /// impl Event for Evt {
+    ///     fn event_type(&self) -> &'static str { "evt.type" }
+    ///     fn event_id(&self) -> &EventId { &self.id }
+    ///     fn timestamp(&self) -> chrono::DateTime<Utc> { self.occurred_at }
+    ///     fn payload(&self) -> serde_json::Value { serde_json::json!({}) }
+    ///     fn schema_version(&self) -> u32 { 2 }
+    /// }
+    /// let e = Evt { id: EventId::new(), occurred_at: Utc::now() };
+    /// assert_eq!(e.schema_version(), 2);
+    /// ```
+    fn schema_version(&self) -> u32 {
+        1
+    }
 }
 
 #[cfg(test)]
@@ -225,6 +347,43 @@ mod tests {
         assert_eq!(event_id.to_string(), uuid_str);
     }
 
+    #[test]
+    fn event_id_ulid_roundtrip() {
+        let id = EventId::from_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        assert_eq!(id.to_ulid(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert_eq!(
+            EventId::from_ulid("01arz3ndektsv4rrffq69g5fav").unwrap(),
+            id
+        );
+        assert_eq!(
+            EventId::from_ulid("O1ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap(),
+            id
+        );
+
+        let random = EventId::new();
+        assert_eq!(EventId::from_ulid(&random.to_ulid()).unwrap(), random);
+        assert_eq!(EventId(Uuid::max()).to_ulid(), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    }
+
+    #[test]
+    fn event_id_rejects_invalid_ulid() {
+        for text in [
+            "",
+            "01ARZ3NDEKTSV4RRFFQ69G5FA",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAU",
+            "81ARZ3NDEKTSV4RRFFQ69G5FAV",
+        ] {
+            assert!(EventId::from_ulid(text).is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn event_id_v7_is_time_ordered() {
+        let ids: Vec<EventId> = (0..100).map(|_| EventId::new_v7()).collect();
+        assert!(ids.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(ids[0].0.get_version_num(), 7);
+    }
+
     #[derive(Debug)]
     struct TestEvent {
         id: EventId,
@@ -258,5 +417,6 @@ mod tests {
         assert_eq!(event.event_type(), "test.event");
         assert_eq!(event.event_id(), &id);
         assert_eq!(event.payload(), json!({ "value": 42 }));
+        assert_eq!(event.schema_version(), 1);
     }
 }
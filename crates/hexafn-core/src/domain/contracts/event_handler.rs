@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # EventHandler Contract (Core Module)
+//!
+//! This module defines the [`EventHandler`] trait implemented by subscribers of the
+//! in-process [`EventBus`](crate::EventBus).
+//!
+//! A handler receives events either as `Arc<dyn Event>` (the default, for
+//! subscriptions by event type pattern) or as `Arc<E>` for a concrete event type
+//! (typed subscriptions). Any async closure taking the event and returning
+//! `Result<(), Box<dyn HexaError>>` is a handler.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use async_trait::async_trait;
+//! use hexafn_core::{Event, EventHandler, HexaError};
+//! use std::sync::Arc;
+//!
+//! struct AuditLog;
+//!
+//! #[async_trait]
+//! impl EventHandler for AuditLog {
+//!     async fn handle(&self, event: Arc<dyn Event>) -> Result<(), Box<dyn HexaError>> {
+//!         println!("{} {}", event.event_type(), event.event_id());
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let closure = |event: Arc<dyn Event>| async move {
+//!     println!("{}", event.event_type());
+//!     Ok::<_, Box<dyn HexaError>>(())
+//! };
+//! fn assert_handler<H: EventHandler>(_: &H) {}
+//! assert_handler(&AuditLog);
+//! assert_handler(&closure);
+//! ```
+
+use super::error::HexaError;
+use super::event::Event;
+use async_trait::async_trait;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Asynchronous subscriber for events of type `E`.
+///
+/// Handlers for the same aggregate are invoked one event at a time, so a handler
+/// observes an aggregate's events in publish order. A returned error is logged by
+/// the bus and does not stop delivery to other handlers.
+#[async_trait]
+pub trait EventHandler<E: ?Sized + Send + Sync = dyn Event>: Send + Sync {
+    /// Handle a single event.
+    async fn handle(&self, event: Arc<E>) -> Result<(), Box<dyn HexaError>>;
+}
+
+#[async_trait]
+impl<E, F, Fut> EventHandler<E> for F
+where
+    E: ?Sized + Send + Sync + 'static,
+    F: Fn(Arc<E>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), Box<dyn HexaError>>> + Send,
+{
+    async fn handle(&self, event: Arc<E>) -> Result<(), Box<dyn HexaError>> {
+        self(event).await
+    }
+}
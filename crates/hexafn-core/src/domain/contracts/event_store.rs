@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # EventStore Contract (Core Module)
+//!
+//! This module defines the [`EventStore`] trait, the outbound port for
+//! append-only, per-aggregate event streams.
+//!
+//! ## Streams and Versions
+//!
+//! Each aggregate id owns one stream. Events in a stream are numbered from 1, and
+//! a stream's version is the sequence number of its last event (0 when empty).
+//! Appends carry the version the caller last observed; if another writer got
+//! there first the append fails with
+//! [`EventStoreError::ConcurrencyConflict`](crate::EventStoreError::ConcurrencyConflict)
+//! and nothing is written. The store stamps each appended
+//! [`EventEnvelope`] with the stream's aggregate id and its sequence number.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventEnvelope, EventStore, InMemoryEventStore};
+//! use serde_json::json;
+//!
+//! # tokio_test::block_on(async {
+//! let store = InMemoryEventStore::new();
+//! let version = store
+//!     .append("cart-1", 0, vec![EventEnvelope::new("cart.item_added", json!({ "sku": "A" }))])
+//!     .await
+//!     .unwrap();
+//! assert_eq!(version, 1);
+//!
+//! // A writer that still believes the stream is empty is rejected.
+//! assert!(store.append("cart-1", 0, vec![EventEnvelope::new("cart.cleared", json!({}))]).await.is_err());
+//!
+//! let events = store.read_stream("cart-1", 1).await.unwrap();
+//! assert_eq!(events[0].sequence_number, 1);
+//! # });
+//! ```
+
+use super::error::HexaError;
+use crate::domain::value_objects::{EventEnvelope, EventStoreError};
+use async_trait::async_trait;
+
+/// Append-only store of per-aggregate event streams.
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// Append `events` to the stream of `aggregate_id`, provided the stream is
+    /// still at `expected_version`. Returns the new stream version.
+    ///
+    /// Appending an empty batch only checks the expected version.
+    ///
+    /// # Errors
+    ///
+    /// - `core.event_store.concurrency_conflict` if the stream version differs
+    /// - `core.event_store.aggregate_mismatch` if an event names another aggregate
+    /// - backend-specific errors on storage failure
+    async fn append(
+        &self,
+        aggregate_id: &str,
+        expected_version: u64,
+        events: Vec<EventEnvelope>,
+    ) -> Result<u64, Box<dyn HexaError>>;
+
+    /// Read the events of `aggregate_id` whose sequence number is at least
+    /// `from_sequence`, in order. Unknown streams are empty.
+    async fn read_stream(
+        &self,
+        aggregate_id: &str,
+        from_sequence: u64,
+    ) -> Result<Vec<EventEnvelope>, Box<dyn HexaError>>;
+
+    /// Current version of the stream of `aggregate_id`; 0 if it has no events.
+    async fn stream_version(&self, aggregate_id: &str) -> Result<u64, Box<dyn HexaError>>;
+}
+
+/// Validate a batch against the stream state and stamp it with sequence numbers.
+///
+/// Shared by the bundled backends so that they enforce identical semantics.
+pub(crate) fn stamp_batch(
+    aggregate_id: &str,
+    expected_version: u64,
+    current_version: u64,
+    events: Vec<EventEnvelope>,
+) -> Result<Vec<EventEnvelope>, EventStoreError> {
+    if expected_version != current_version {
+        return Err(EventStoreError::ConcurrencyConflict {
+            aggregate_id: aggregate_id.to_string(),
+            expected: expected_version,
+            actual: current_version,
+        });
+    }
+    events
+        .into_iter()
+        .zip(current_version + 1..)
+        .map(|(mut event, sequence)| {
+            match event.aggregate_id.as_deref() {
+                Some(id) if id != aggregate_id => {
+                    return Err(EventStoreError::AggregateMismatch {
+                        expected: aggregate_id.to_string(),
+                        actual: id.to_string(),
+                    });
+                }
+                _ => {}
+            }
+            event.aggregate_id = Some(aggregate_id.to_string());
+            event.sequence_number = sequence;
+            Ok(event)
+        })
+        .collect()
+}
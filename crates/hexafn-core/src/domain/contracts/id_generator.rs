@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # IdGenerator Contract (Core Module)
+//!
+//! This module defines the [`IdGenerator`] trait, the pluggable source of
+//! [`EventId`]s. Components that mint ids accept a generator so deployments can
+//! choose time-ordered ids and tests can choose reproducible ones.
+//!
+//! Bundled generators:
+//!
+//! - [`RandomIdGenerator`](crate::RandomIdGenerator): UUIDv4, same as `EventId::new()`
+//! - [`UuidV7IdGenerator`](crate::UuidV7IdGenerator): time-ordered UUIDv7
+//! - [`UlidIdGenerator`](crate::UlidIdGenerator): monotonic ULIDs
+//! - [`SeededIdGenerator`](crate::SeededIdGenerator): deterministic for tests and replays
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventEnvelope, IdGenerator, SeededIdGenerator};
+//! use serde_json::json;
+//!
+//! let ids = SeededIdGenerator::new(42);
+//! let event = EventEnvelope::new("user.created", json!({})).with_id(ids.next_id());
+//!
+//! let replay = SeededIdGenerator::new(42);
+//! assert_eq!(event.id, replay.next_id());
+//! ```
+
+use super::event::EventId;
+use std::fmt::Debug;
+
+/// Source of event ids.
+pub trait IdGenerator: Debug + Send + Sync {
+    /// Produce the next id.
+    fn next_id(&self) -> EventId;
+}
@@ -1,12 +1,29 @@
 // SPDX-FileCopyrightText: 2025 Husamettin ARABACI
 // SPDX-License-Identifier: MIT
 
+mod aggregate;
+mod clock;
 mod domain_event;
 mod error;
 mod event;
+mod event_handler;
+mod event_store;
+mod id_generator;
 mod pipeline;
+mod stage_factory;
+mod stage_schema;
+mod upcaster;
 
+pub use aggregate::Aggregate;
+pub use clock::Clock;
 pub use domain_event::DomainEvent;
 pub use error::{HexaError, HexaErrorKind, HexaErrorSeverity};
 pub use event::{Event, EventId};
-pub use pipeline::{Pipeline, PipelineContext, PipelineStage, PipelineStageType};
+pub use event_handler::EventHandler;
+pub(crate) use event_store::stamp_batch;
+pub use event_store::EventStore;
+pub use id_generator::IdGenerator;
+pub use pipeline::{Pipeline, PipelineContext, PipelineStage, PipelineStageType, StageOutcome};
+pub use stage_factory::StageFactory;
+pub use stage_schema::{ContextKey, StageSchema};
+pub use upcaster::Upcaster;
@@ -20,16 +20,22 @@
 //! use serde_json::json;
 //!
 //! let mut context = PipelineContext::new();
-//! context.set("user_id".to_string(), json!("12345"));
-//! context.set("action".to_string(), json!("login"));
+//! context.set("user_id".to_string(), json!("12345")).unwrap();
+//! context.set("action".to_string(), json!("login")).unwrap();
 //!
 //! assert_eq!(context.get("user_id"), Some(&json!("12345")));
 //! ```
 
-use crate::domain::contracts::HexaError;
+use crate::domain::contracts::{Clock, ContextKey, DomainEvent, Event, HexaError, StageSchema};
+use crate::domain::services::SystemClock;
+use crate::domain::value_objects::{ContextMetadata, PipelineError, RetryPolicy};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Represents the type of pipeline stage in the 6F Lifecycle Flow.
 ///
@@ -67,23 +73,95 @@ use std::collections::HashMap;
 /// let json = serde_json::to_string(&stage).unwrap();
 /// let deserialized: PipelineStageType = serde_json::from_str(&json).unwrap();
 /// assert_eq!(stage, deserialized);
+///
+/// // Lowercase names, as written in pipeline config files, are accepted too.
+/// let parsed: PipelineStageType = serde_json::from_str("\"filter\"").unwrap();
+/// assert_eq!(parsed, PipelineStageType::Filter);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// # Ordering
+///
+/// Variants are declared in 6F execution order, so comparisons follow the lifecycle:
+///
+/// ```rust
+/// use hexafn_core::PipelineStageType;
+///
+/// assert!(PipelineStageType::Feed < PipelineStageType::Filter);
+/// assert!(PipelineStageType::Forward < PipelineStageType::Feedback);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum PipelineStageType {
     /// Feed stage: Ingest data from external sources
+    #[serde(alias = "feed")]
     Feed,
     /// Filter stage: Apply pre-condition checks and gating
+    #[serde(alias = "filter")]
     Filter,
     /// Format stage: Normalize, transform, and validate data
+    #[serde(alias = "format")]
     Format,
     /// Function stage: Execute business logic
+    #[serde(alias = "function")]
     Function,
     /// Forward stage: Route results to destinations
+    #[serde(alias = "forward")]
     Forward,
     /// Feedback stage: Log, trace, and audit execution
+    #[serde(alias = "feedback")]
     Feedback,
 }
 
+/// Control-flow decision returned by [`PipelineStage::run`].
+///
+/// Outcomes let a stage steer the pipeline without raising a [`HexaError`].
+/// A Filter stage that rejects an event returns `Skip` or `Halt` instead of an
+/// error, so dropped events end the run cleanly:
+///
+/// - **Continue**: Proceed to the next stage
+/// - **Skip**: Drop the event; jump straight to the Feedback stage if one exists
+/// - **Halt**: Stop the run immediately, without running any further stage
+/// - **Retry**: Run the same stage again (bounded by the pipeline engine)
+///
+/// # Examples
+///
+/// ```rust
+/// use hexafn_core::{HexaError, PipelineContext, PipelineStage, PipelineStageType, StageOutcome};
+///
+/// struct DropAnonymous;
+///
+/// #[async_trait::async_trait]
+/// impl PipelineStage for DropAnonymous {
+///     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Filter }
+///     fn get_order(&self) -> u32 { 2 }
+///     async fn execute(&self, _context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+///         Ok(())
+///     }
+///     async fn run(&self, context: &mut PipelineContext) -> Result<StageOutcome, Box<dyn HexaError>> {
+///         if context.get("user_id").is_none() {
+///             return Ok(StageOutcome::Skip);
+///         }
+///         Ok(StageOutcome::Continue)
+///     }
+///     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+/// }
+///
+/// # tokio_test::block_on(async {
+/// let outcome = DropAnonymous.run(&mut PipelineContext::new()).await.unwrap();
+/// assert_eq!(outcome, StageOutcome::Skip);
+/// # });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StageOutcome {
+    /// Proceed to the next stage
+    Continue,
+    /// Drop the event and jump to the Feedback stage
+    Skip,
+    /// Stop the pipeline run immediately
+    Halt,
+    /// Execute the same stage again
+    Retry,
+}
+
 /// Core pipeline execution contract for the 6F Lifecycle Flow.
 ///
 /// This trait defines the interface for executing complete data pipelines
@@ -567,7 +645,7 @@ pub trait Pipeline: Send + Sync {
 ///     async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
 ///         // Implement feed logic
 ///         context.set("source".to_string(),
-///                    serde_json::json!(&self.source_name));
+///                    serde_json::json!(&self.source_name))?;
 ///         Ok(())
 ///     }
 ///     
@@ -792,7 +870,7 @@ pub trait PipelineStage: Send + Sync {
     ///     
     ///     // Set result for next stage
     ///     context.set("filter_passed".to_string(),
-    ///                serde_json::json!(true));
+    ///                serde_json::json!(true))?;
     ///     
     ///     Ok(())
     /// }
@@ -800,6 +878,23 @@ pub trait PipelineStage: Send + Sync {
     /// ```
     async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>>;
 
+    /// Execute this stage and report how the pipeline should proceed.
+    ///
+    /// This is the method pipeline engines call. The default implementation runs
+    /// [`PipelineStage::execute`] and returns [`StageOutcome::Continue`], so stages
+    /// that never short-circuit only need to implement `execute`. Override it to
+    /// drop events (`Skip`/`Halt`) or request another attempt (`Retry`) without
+    /// reporting a [`HexaError`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(StageOutcome)` - Stage ran; the outcome tells the engine what to do next
+    /// * `Err(E)` - Stage execution failed
+    async fn run(&self, context: &mut PipelineContext) -> Result<StageOutcome, Box<dyn HexaError>> {
+        self.execute(context).await?;
+        Ok(StageOutcome::Continue)
+    }
+
     /// Validate this stage configuration.
     ///
     /// Checks that the stage is properly configured and ready for execution.
@@ -855,6 +950,110 @@ pub trait PipelineStage: Send + Sync {
     /// # }
     /// ```
     fn validate(&self) -> Result<(), Box<dyn HexaError>>;
+
+    /// Declare the context keys this stage reads and writes.
+    ///
+    /// Pipelines check declared inputs during validation: every input must be
+    /// declared as an output by an earlier stage. The default empty schema opts
+    /// the stage out of these checks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexafn_core::{ContextKey, HexaError, PipelineContext, PipelineStage, PipelineStageType, StageSchema};
+    ///
+    /// struct Normalize;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl PipelineStage for Normalize {
+    ///     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Format }
+    ///     fn get_order(&self) -> u32 { 3 }
+    ///     async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+    ///         let raw: Option<String> =
+    ///             context.get_namespaced(&ContextKey::new(PipelineStageType::Feed, "payload"))?;
+    ///         let normalized = raw.unwrap_or_default().to_lowercase();
+    ///         context.set_namespaced(&ContextKey::new(PipelineStageType::Format, "normalized"), &normalized)
+    ///     }
+    ///     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+    ///     fn schema(&self) -> StageSchema {
+    ///         StageSchema::new()
+    ///             .with_input(ContextKey::new(PipelineStageType::Feed, "payload"))
+    ///             .with_output("normalized")
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Normalize.schema().outputs, vec!["normalized"]);
+    /// ```
+    fn schema(&self) -> StageSchema {
+        StageSchema::default()
+    }
+
+    /// Maximum time a single execution of this stage may take.
+    ///
+    /// Pipelines cancel a stage that runs longer and fail the run with a
+    /// `Timeout`-kind error naming the stage. The default `None` means the stage
+    /// is limited only by the pipeline-wide deadline, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexafn_core::{HexaError, PipelineContext, PipelineStage, PipelineStageType};
+    /// use std::time::Duration;
+    ///
+    /// struct CallWebhook;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl PipelineStage for CallWebhook {
+    ///     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Forward }
+    ///     fn get_order(&self) -> u32 { 5 }
+    ///     async fn execute(&self, _context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+    ///         Ok(())
+    ///     }
+    ///     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+    ///     fn timeout(&self) -> Option<Duration> { Some(Duration::from_secs(2)) }
+    /// }
+    ///
+    /// assert_eq!(CallWebhook.timeout(), Some(Duration::from_secs(2)));
+    /// ```
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Retry policy applied by the pipeline engine when this stage fails.
+    ///
+    /// The engine re-runs the stage for errors whose kind the policy lists, waiting
+    /// between attempts according to its backoff. Stage authors do not implement
+    /// retry loops themselves. The default `None` fails the run on the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexafn_core::{Backoff, HexaError, PipelineContext, PipelineStage, PipelineStageType, RetryPolicy};
+    /// use std::time::Duration;
+    ///
+    /// struct PostToTarget;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl PipelineStage for PostToTarget {
+    ///     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Forward }
+    ///     fn get_order(&self) -> u32 { 5 }
+    ///     async fn execute(&self, _context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+    ///         Ok(())
+    ///     }
+    ///     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+    ///     fn retry_policy(&self) -> Option<RetryPolicy> {
+    ///         Some(RetryPolicy::new(3).with_backoff(Backoff::Jittered {
+    ///             initial: Duration::from_millis(100),
+    ///             max: Duration::from_secs(2),
+    ///         }))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(PostToTarget.retry_policy().unwrap().max_attempts(), 3);
+    /// ```
+    fn retry_policy(&self) -> Option<RetryPolicy> {
+        None
+    }
 }
 
 /// Shared context for pipeline execution.
@@ -892,13 +1091,13 @@ pub trait PipelineStage: Send + Sync {
 /// let mut context = PipelineContext::new();
 ///
 /// // Set various data types
-/// context.set("user_id".to_string(), json!("12345"));
-/// context.set("timestamp".to_string(), json!(1642781234));
-/// context.set("is_valid".to_string(), json!(true));
+/// context.set("user_id".to_string(), json!("12345")).unwrap();
+/// context.set("timestamp".to_string(), json!(1642781234)).unwrap();
+/// context.set("is_valid".to_string(), json!(true)).unwrap();
 /// context.set("metadata".to_string(), json!({
 ///     "source": "webhook",
 ///     "version": "1.0"
-/// }));
+/// })).unwrap();
 ///
 /// // Retrieve and use data
 /// assert_eq!(context.get("user_id"), Some(&json!("12345")));
@@ -917,7 +1116,7 @@ pub trait PipelineStage: Send + Sync {
 ///         "event_type": "user_login",
 ///         "user_id": "user_123",
 ///         "timestamp": "2024-01-01T10:00:00Z"
-///     }));
+///     })).unwrap();
 /// }
 ///
 /// async fn filter_stage_example(context: &mut PipelineContext) {
@@ -927,8 +1126,8 @@ pub trait PipelineStage: Send + Sync {
 ///         if event_type == "user_login" {
 ///             // Clone the value to avoid borrowing conflicts
 ///             let user_id = raw_input["user_id"].clone();
-///             context.set("filter_passed".to_string(), json!(true));
-///             context.set("validated_user_id".to_string(), user_id);
+///             context.set("filter_passed".to_string(), json!(true)).unwrap();
+///             context.set("validated_user_id".to_string(), user_id).unwrap();
 ///         }
 ///     }
 /// }
@@ -953,7 +1152,7 @@ pub trait PipelineStage: Send + Sync {
 ///         }
 ///     },
 ///     "errors": []
-/// }));
+/// })).unwrap();
 ///
 /// // Access nested data
 /// if let Some(result) = context.get("processing_result") {
@@ -965,7 +1164,13 @@ pub trait PipelineStage: Send + Sync {
 #[derive(Debug, Clone)]
 pub struct PipelineContext {
     /// Internal data storage using JSON values for flexibility
-    pub data: HashMap<String, serde_json::Value>,
+    data: HashMap<String, serde_json::Value>,
+    /// Stage currently executing, set by the pipeline engine
+    current_stage: Option<PipelineStageType>,
+    /// Execution metadata and write log
+    metadata: ContextMetadata,
+    /// Time source for deadlines and the write log
+    clock: Arc<dyn Clock>,
 }
 
 impl PipelineContext {
@@ -984,7 +1189,7 @@ impl PipelineContext {
     /// use hexafn_core::PipelineContext;
     ///
     /// let context = PipelineContext::new();
-    /// assert_eq!(context.data.len(), 0);
+    /// assert_eq!(context.data().len(), 0);
     ///
     /// // Context is ready for use
     /// let mut mutable_context = context;
@@ -998,9 +1203,138 @@ impl PipelineContext {
     pub fn new() -> Self {
         Self {
             data: HashMap::new(),
+            current_stage: None,
+            metadata: ContextMetadata::new(),
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// Create an empty context that reads time from `clock`, with `started_at`
+    /// taken from it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexafn_core::{Clock, ManualClock, PipelineContext};
+    /// use std::sync::Arc;
+    ///
+    /// let clock = ManualClock::default();
+    /// let context = PipelineContext::with_clock(Arc::new(clock.clone()));
+    /// assert_eq!(context.metadata().started_at, clock.now());
+    /// ```
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        let mut context = Self::new();
+        context.set_clock(clock);
+        context
+    }
+
+    /// Create an empty context whose metadata records `event` as the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, Utc};
+    /// use hexafn_core::{Event, EventId, PipelineContext};
+    ///
+    /// struct Ping(EventId);
+    ///
+    /// impl Event for Ping {
+    ///     fn event_type(&self) -> &'static str { "ping" }
+    ///     fn event_id(&self) -> &EventId { &self.0 }
+    ///     fn timestamp(&self) -> DateTime<Utc> { Utc::now() }
+    ///     fn payload(&self) -> serde_json::Value { serde_json::json!({}) }
+    /// }
+    ///
+    /// let event = Ping(EventId::new());
+    /// let context = PipelineContext::from_event(&event);
+    /// assert_eq!(context.metadata().event_id.as_ref(), Some(&event.0));
+    /// assert_eq!(context.metadata().event_type.as_deref(), Some("ping"));
+    /// ```
+    pub fn from_event<E: Event + ?Sized>(event: &E) -> Self {
+        Self::started(ContextMetadata::from_event(event))
+    }
+
+    /// Create an empty context whose metadata records `event` as the origin and
+    /// carries over its correlation id.
+    pub fn from_domain_event<E: DomainEvent + ?Sized>(event: &E) -> Self {
+        Self::started(ContextMetadata::from_domain_event(event))
+    }
+
+    /// Create an empty context with the given metadata.
+    pub fn with_metadata(metadata: ContextMetadata) -> Self {
+        Self {
+            metadata,
+            ..Self::new()
         }
     }
 
+    /// Context with `metadata` whose `started_at` is read from its clock.
+    fn started(metadata: ContextMetadata) -> Self {
+        let mut context = Self::with_metadata(metadata);
+        context.metadata.started_at = context.clock.now();
+        context
+    }
+
+    /// Execution metadata: trace ids, origin event, deadline, and write log.
+    pub fn metadata(&self) -> &ContextMetadata {
+        &self.metadata
+    }
+
+    /// Time left before the pipeline deadline, or `None` if the run has no deadline.
+    ///
+    /// Stages use this to size downstream calls (e.g. HTTP timeouts) to the
+    /// remaining budget. Returns [`Duration::ZERO`] once the deadline has passed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::Utc;
+    /// use hexafn_core::PipelineContext;
+    /// use std::time::Duration;
+    ///
+    /// let mut context = PipelineContext::new();
+    /// assert_eq!(context.remaining_budget(), None);
+    ///
+    /// let context = context.with_deadline(Utc::now() + chrono::Duration::seconds(30));
+    /// let remaining = context.remaining_budget().unwrap();
+    /// assert!(remaining > Duration::from_secs(29) && remaining <= Duration::from_secs(30));
+    /// ```
+    pub fn remaining_budget(&self) -> Option<Duration> {
+        self.metadata.remaining(self.clock.now())
+    }
+
+    /// Clock this context reads time from.
+    ///
+    /// Stages should use it instead of the system time so that runs driven by a
+    /// [`ManualClock`](crate::ManualClock) stay deterministic.
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    /// Replace the clock. Pipeline engines install their own clock, if they have
+    /// one, before a run.
+    ///
+    /// `started_at` is reset from the new clock, so that it lies on the same
+    /// timeline as the deadline and the write log.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.metadata.started_at = clock.now();
+        self.clock = clock;
+    }
+
+    /// Set the point in time by which the run should finish.
+    ///
+    /// This takes the context by value so that callers set it up before a run;
+    /// stages, which only borrow the context, cannot move their own deadline.
+    pub fn with_deadline(mut self, deadline: DateTime<Utc>) -> Self {
+        self.metadata.deadline = Some(deadline);
+        self
+    }
+
+    /// Mutable access to execution metadata, for pipeline engines.
+    pub(crate) fn metadata_mut(&mut self) -> &mut ContextMetadata {
+        &mut self.metadata
+    }
+
     /// Get a value from the context.
     ///
     /// Retrieves a value by key, returning a reference to the JSON value.
@@ -1024,7 +1358,7 @@ impl PipelineContext {
     /// use serde_json::json;
     ///
     /// let mut context = PipelineContext::new();
-    /// context.set("name".to_string(), json!("Alice"));
+    /// context.set("name".to_string(), json!("Alice")).unwrap();
     ///
     /// // Get existing value
     /// assert_eq!(context.get("name"), Some(&json!("Alice")));
@@ -1040,8 +1374,8 @@ impl PipelineContext {
     /// use serde_json::json;
     ///
     /// let mut context = PipelineContext::new();
-    /// context.set("age".to_string(), json!(25));
-    /// context.set("active".to_string(), json!(true));
+    /// context.set("age".to_string(), json!(25)).unwrap();
+    /// context.set("active".to_string(), json!(true)).unwrap();
     ///
     /// // Safe type conversion
     /// if let Some(age_value) = context.get("age") {
@@ -1062,7 +1396,7 @@ impl PipelineContext {
     /// use serde_json::json;
     ///
     /// let mut context = PipelineContext::new();
-    /// context.set("status".to_string(), json!("processing"));
+    /// context.set("status".to_string(), json!("processing")).unwrap();
     ///
     /// match context.get("status") {
     ///     Some(value) if value.as_str() == Some("processing") => {
@@ -1083,13 +1417,22 @@ impl PipelineContext {
     /// Set a value in the context.
     ///
     /// Stores or updates a value for the given key. If the key already exists,
-    /// the previous value is replaced.
+    /// the previous value is replaced. The write is recorded in the metadata
+    /// write log together with the currently executing stage.
+    ///
+    /// A namespaced key (such as `feed.payload`) is only writable by its own
+    /// stage while a stage is executing; see
+    /// [`set_namespaced`](Self::set_namespaced).
     ///
     /// # Arguments
     ///
     /// * `key` - The key to store the value under
     /// * `value` - The JSON value to store
     ///
+    /// # Errors
+    ///
+    /// * `core.pipeline.namespace_violation` - `key` is owned by another stage
+    ///
     /// # Examples
     ///
     /// ## Setting Different Types
@@ -1101,23 +1444,23 @@ impl PipelineContext {
     /// let mut context = PipelineContext::new();
     ///
     /// // String value
-    /// context.set("name".to_string(), json!("John Doe"));
+    /// context.set("name".to_string(), json!("John Doe")).unwrap();
     ///
     /// // Numeric value
-    /// context.set("count".to_string(), json!(42));
+    /// context.set("count".to_string(), json!(42)).unwrap();
     ///
     /// // Boolean value
-    /// context.set("enabled".to_string(), json!(true));
+    /// context.set("enabled".to_string(), json!(true)).unwrap();
     ///
     /// // Array value
-    /// context.set("tags".to_string(), json!(["rust", "pipeline", "async"]));
+    /// context.set("tags".to_string(), json!(["rust", "pipeline", "async"])).unwrap();
     ///
     /// // Object value
     /// context.set("config".to_string(), json!({
     ///     "timeout": 30,
     ///     "retries": 3,
     ///     "debug": false
-    /// }));
+    /// })).unwrap();
     /// ```
     ///
     /// ## Updating Existing Values
@@ -1129,11 +1472,11 @@ impl PipelineContext {
     /// let mut context = PipelineContext::new();
     ///
     /// // Initial value
-    /// context.set("counter".to_string(), json!(0));
+    /// context.set("counter".to_string(), json!(0)).unwrap();
     /// assert_eq!(context.get("counter"), Some(&json!(0)));
     ///
     /// // Update value
-    /// context.set("counter".to_string(), json!(1));
+    /// context.set("counter".to_string(), json!(1)).unwrap();
     /// assert_eq!(context.get("counter"), Some(&json!(1)));
     /// ```
     ///
@@ -1146,19 +1489,162 @@ impl PipelineContext {
     /// let mut context = PipelineContext::new();
     ///
     /// // Build up state through stages
-    /// context.set("input".to_string(), json!("raw data"));
-    /// context.set("validated".to_string(), json!(true));
-    /// context.set("processed_at".to_string(), json!("2024-01-01T12:00:00Z"));
+    /// context.set("input".to_string(), json!("raw data")).unwrap();
+    /// context.set("validated".to_string(), json!(true)).unwrap();
+    /// context.set("processed_at".to_string(), json!("2024-01-01T12:00:00Z")).unwrap();
     /// context.set("output".to_string(), json!({
     ///     "result": "processed data",
     ///     "metadata": {
     ///         "version": "1.0",
     ///         "checksum": "abc123"
     ///     }
-    /// }));
+    /// })).unwrap();
     /// ```
-    pub fn set(&mut self, key: String, value: serde_json::Value) {
+    pub fn set(&mut self, key: String, value: serde_json::Value) -> Result<(), Box<dyn HexaError>> {
+        if let Some(context_key) = ContextKey::parse(&key) {
+            self.check_namespace(&context_key)?;
+        }
+        self.metadata
+            .record_write(&key, self.current_stage, self.clock.now());
         self.data.insert(key, value);
+        Ok(())
+    }
+
+    /// All stored values, keyed by name.
+    ///
+    /// Writes go through [`set`](Self::set) and its typed variants, so that
+    /// namespaces are enforced and the write log stays complete.
+    pub fn data(&self) -> &HashMap<String, serde_json::Value> {
+        &self.data
+    }
+
+    /// Get a value deserialized into `T`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(T))` - The key exists and holds a value of type `T`
+    /// * `Ok(None)` - The key does not exist
+    /// * `Err(E)` - The stored value cannot be deserialized into `T`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexafn_core::PipelineContext;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct User { id: u64, name: String }
+    ///
+    /// let mut context = PipelineContext::new();
+    /// let user = User { id: 7, name: "Ada".to_string() };
+    /// context.insert_typed("user", &user).unwrap();
+    ///
+    /// assert_eq!(context.get_as::<User>("user").unwrap(), Some(user));
+    /// assert_eq!(context.get_as::<User>("missing").unwrap(), None);
+    /// assert!(context.get_as::<String>("user").is_err());
+    /// ```
+    pub fn get_as<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, Box<dyn HexaError>> {
+        match self.data.get(key) {
+            None => Ok(None),
+            Some(value) => serde_json::from_value(value.clone())
+                .map(Some)
+                .map_err(|e| {
+                    Box::new(PipelineError::ContextTypeMismatch {
+                        key: key.to_string(),
+                        reason: e.to_string(),
+                    }) as Box<dyn HexaError>
+                }),
+        }
+    }
+
+    /// Serialize `value` and store it under `key`.
+    ///
+    /// Like [`PipelineContext::set`], this refuses to write a namespaced key
+    /// (such as `feed.payload`) that belongs to a different stage than the one
+    /// currently executing.
+    ///
+    /// # Errors
+    ///
+    /// * `core.pipeline.context_type_mismatch` - `value` cannot be serialized to JSON
+    /// * `core.pipeline.namespace_violation` - `key` is owned by another stage
+    pub fn insert_typed<T: Serialize + ?Sized>(
+        &mut self,
+        key: impl Into<String>,
+        value: &T,
+    ) -> Result<(), Box<dyn HexaError>> {
+        let key = key.into();
+        let value = serde_json::to_value(value).map_err(|e| {
+            Box::new(PipelineError::ContextTypeMismatch {
+                key: key.clone(),
+                reason: e.to_string(),
+            }) as Box<dyn HexaError>
+        })?;
+        self.set(key, value)
+    }
+
+    /// Get a namespaced value deserialized into `T`.
+    ///
+    /// Any stage may read any namespace.
+    pub fn get_namespaced<T: DeserializeOwned>(
+        &self,
+        key: &ContextKey,
+    ) -> Result<Option<T>, Box<dyn HexaError>> {
+        self.get_as(&key.to_string())
+    }
+
+    /// Store a value in a stage namespace.
+    ///
+    /// While a stage is executing, only its own namespace is writable. Outside
+    /// of pipeline execution (no current stage) every namespace is writable, so
+    /// callers can seed input before the run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexafn_core::{ContextKey, PipelineContext, PipelineStageType};
+    ///
+    /// let mut context = PipelineContext::new();
+    ///
+    /// // Seeding input before the run: every namespace is writable.
+    /// let payload = ContextKey::new(PipelineStageType::Feed, "payload");
+    /// context.set_namespaced(&payload, "raw").unwrap();
+    /// assert_eq!(context.get("feed.payload"), Some(&serde_json::json!("raw")));
+    /// ```
+    pub fn set_namespaced<T: Serialize + ?Sized>(
+        &mut self,
+        key: &ContextKey,
+        value: &T,
+    ) -> Result<(), Box<dyn HexaError>> {
+        self.insert_typed(key.to_string(), value)
+    }
+
+    /// Mark `stage_type` as the stage currently executing.
+    ///
+    /// Called by pipeline engines before each stage runs.
+    pub(crate) fn enter_stage(&mut self, stage_type: PipelineStageType) {
+        self.current_stage = Some(stage_type);
+    }
+
+    /// Clear the current stage. Called by pipeline engines after a run.
+    pub(crate) fn leave_stage(&mut self) {
+        self.current_stage = None;
+    }
+
+    /// Stage currently executing, if any.
+    pub fn current_stage(&self) -> Option<PipelineStageType> {
+        self.current_stage
+    }
+
+    fn check_namespace(&self, key: &ContextKey) -> Result<(), Box<dyn HexaError>> {
+        match self.current_stage {
+            Some(stage_type) if stage_type != key.namespace => {
+                Err(Box::new(PipelineError::NamespaceViolation {
+                    stage_type,
+                    key: key.to_string(),
+                }))
+            }
+            _ => Ok(()),
+        }
     }
 }
 
@@ -1177,7 +1663,7 @@ impl Default for PipelineContext {
     /// let context2 = PipelineContext::new();
     ///
     /// // Both are equivalent
-    /// assert_eq!(context1.data.len(), context2.data.len());
+    /// assert_eq!(context1.data().len(), context2.data().len());
     /// ```
     ///
     /// ## Usage in Structs
@@ -1192,7 +1678,7 @@ impl Default for PipelineContext {
     /// }
     ///
     /// let executor = PipelineExecutor::default();
-    /// assert_eq!(executor.context.data.len(), 0);
+    /// assert_eq!(executor.context.data().len(), 0);
     /// ```
     fn default() -> Self {
         Self::new()
@@ -1208,16 +1694,16 @@ mod tests {
     fn pipeline_context_new_and_default_are_empty() {
         let ctx1 = PipelineContext::new();
         let ctx2 = PipelineContext::default();
-        assert_eq!(ctx1.data.len(), 0);
-        assert_eq!(ctx2.data.len(), 0);
+        assert_eq!(ctx1.data().len(), 0);
+        assert_eq!(ctx2.data().len(), 0);
     }
 
     #[test]
     fn pipeline_context_set_and_get_basic_types() {
         let mut ctx = PipelineContext::new();
-        ctx.set("str".to_string(), json!("abc"));
-        ctx.set("num".to_string(), json!(42));
-        ctx.set("bool".to_string(), json!(true));
+        ctx.set("str".to_string(), json!("abc")).unwrap();
+        ctx.set("num".to_string(), json!(42)).unwrap();
+        ctx.set("bool".to_string(), json!(true)).unwrap();
         assert_eq!(ctx.get("str"), Some(&json!("abc")));
         assert_eq!(ctx.get("num"), Some(&json!(42)));
         assert_eq!(ctx.get("bool"), Some(&json!(true)));
@@ -1227,9 +1713,9 @@ mod tests {
     #[test]
     fn pipeline_context_set_overwrites_value() {
         let mut ctx = PipelineContext::new();
-        ctx.set("key".to_string(), json!(1));
+        ctx.set("key".to_string(), json!(1)).unwrap();
         assert_eq!(ctx.get("key"), Some(&json!(1)));
-        ctx.set("key".to_string(), json!(2));
+        ctx.set("key".to_string(), json!(2)).unwrap();
         assert_eq!(ctx.get("key"), Some(&json!(2)));
     }
 
@@ -1237,7 +1723,7 @@ mod tests {
     fn pipeline_context_handles_complex_and_nested_data() {
         let mut ctx = PipelineContext::new();
         let obj = json!({"a": 1, "b": [2, 3]});
-        ctx.set("obj".to_string(), obj.clone());
+        ctx.set("obj".to_string(), obj.clone()).unwrap();
         assert_eq!(ctx.get("obj"), Some(&obj));
         if let Some(val) = ctx.get("obj") {
             assert_eq!(val["a"], json!(1));
@@ -1248,8 +1734,8 @@ mod tests {
     #[test]
     fn pipeline_context_type_safe_access() {
         let mut ctx = PipelineContext::new();
-        ctx.set("int".to_string(), json!(10));
-        ctx.set("bool".to_string(), json!(false));
+        ctx.set("int".to_string(), json!(10)).unwrap();
+        ctx.set("bool".to_string(), json!(false)).unwrap();
         assert_eq!(ctx.get("int").unwrap().as_u64(), Some(10));
         assert_eq!(ctx.get("bool").unwrap().as_bool(), Some(false));
         assert_eq!(ctx.get("int").unwrap().as_str(), None);
@@ -1258,13 +1744,84 @@ mod tests {
     #[test]
     fn pipeline_context_clone_is_independent() {
         let mut ctx = PipelineContext::new();
-        ctx.set("x".to_string(), json!(1));
+        ctx.set("x".to_string(), json!(1)).unwrap();
         let mut clone = ctx.clone();
-        clone.set("x".to_string(), json!(2));
+        clone.set("x".to_string(), json!(2)).unwrap();
         assert_eq!(ctx.get("x"), Some(&json!(1)));
         assert_eq!(clone.get("x"), Some(&json!(2)));
     }
 
+    #[test]
+    fn pipeline_context_typed_roundtrip() {
+        let mut ctx = PipelineContext::new();
+        ctx.insert_typed("ids", &vec![1u32, 2, 3]).unwrap();
+        assert_eq!(ctx.get_as::<Vec<u32>>("ids").unwrap(), Some(vec![1, 2, 3]));
+        assert_eq!(ctx.get("ids"), Some(&json!([1, 2, 3])));
+
+        let error = ctx.get_as::<bool>("ids").unwrap_err();
+        assert_eq!(error.error_code(), "core.pipeline.context_type_mismatch");
+    }
+
+    #[test]
+    fn pipeline_context_namespace_ownership() {
+        let mut ctx = PipelineContext::new();
+        let feed_key = ContextKey::new(PipelineStageType::Feed, "payload");
+
+        // No active stage: caller may seed any namespace
+        ctx.set_namespaced(&feed_key, "raw").unwrap();
+
+        ctx.enter_stage(PipelineStageType::Format);
+        assert_eq!(ctx.current_stage(), Some(PipelineStageType::Format));
+        let error = ctx.set_namespaced(&feed_key, "clobbered").unwrap_err();
+        assert_eq!(error.error_code(), "core.pipeline.namespace_violation");
+        let error = ctx.insert_typed("feed.payload", "clobbered").unwrap_err();
+        assert_eq!(error.error_code(), "core.pipeline.namespace_violation");
+        let error = ctx
+            .set("feed.payload".to_string(), json!("clobbered"))
+            .unwrap_err();
+        assert_eq!(error.error_code(), "core.pipeline.namespace_violation");
+        assert_eq!(ctx.metadata().writes().len(), 1);
+        assert_eq!(
+            ctx.get_namespaced::<String>(&feed_key).unwrap(),
+            Some("raw".to_string())
+        );
+
+        // Non-namespaced keys stay writable
+        ctx.insert_typed("shared", &1).unwrap();
+
+        ctx.leave_stage();
+        assert_eq!(ctx.current_stage(), None);
+    }
+
+    #[test]
+    fn pipeline_context_started_at_follows_installed_clock() {
+        let clock = crate::domain::services::ManualClock::default();
+        let mut ctx = PipelineContext::new();
+        ctx.set_clock(Arc::new(clock.clone()));
+        assert_eq!(ctx.metadata().started_at, clock.now());
+
+        clock.advance(Duration::from_secs(5));
+        let ctx = PipelineContext::with_clock(Arc::new(clock.clone()));
+        assert_eq!(ctx.metadata().started_at, clock.now());
+    }
+
+    #[test]
+    fn pipeline_context_records_write_provenance() {
+        let mut ctx = PipelineContext::new();
+        ctx.set("input".to_string(), json!(1)).unwrap();
+        ctx.enter_stage(PipelineStageType::Format);
+        ctx.insert_typed("input", &2).unwrap();
+        ctx.leave_stage();
+
+        let metadata = ctx.metadata();
+        assert_eq!(metadata.history("input").count(), 2);
+        assert_eq!(metadata.writes()[0].stage_type, None);
+        assert_eq!(
+            metadata.written_by("input"),
+            Some(PipelineStageType::Format)
+        );
+    }
+
     #[test]
     fn pipeline_context_stage_to_stage_example() {
         let mut ctx = PipelineContext::new();
@@ -1274,14 +1831,15 @@ mod tests {
                 "event_type": "user_login",
                 "user_id": "user_123"
             }),
-        );
+        )
+        .unwrap();
         // Simulate filter stage (avoid borrow conflict)
         if let Some(raw_input) = ctx.get("raw_input") {
             let event_type = raw_input["event_type"].as_str().unwrap_or("");
             if event_type == "user_login" {
                 let user_id = raw_input["user_id"].clone();
-                ctx.set("filter_passed".to_string(), json!(true));
-                ctx.set("validated_user_id".to_string(), user_id);
+                ctx.set("filter_passed".to_string(), json!(true)).unwrap();
+                ctx.set("validated_user_id".to_string(), user_id).unwrap();
             }
         }
         assert_eq!(ctx.get("filter_passed"), Some(&json!(true)));
@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # StageFactory Contract (Core Module)
+//!
+//! This module defines the [`StageFactory`] trait. A factory turns the parameters
+//! of one stage entry in a pipeline config file into a ready-to-run
+//! [`PipelineStage`]. Factories are registered together with a
+//! [`StageDescriptor`](crate::StageDescriptor) in a
+//! [`PipelineStageRegistry`](crate::PipelineStageRegistry), and config files
+//! refer to them by stage type and name.
+//!
+//! Any `Fn(&Value) -> Result<Box<dyn PipelineStage>, Box<dyn HexaError>>` closure
+//! is a factory.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{HexaError, PipelineContext, PipelineStage, PipelineStageType, StageFactory};
+//! use serde_json::{json, Value};
+//!
+//! struct Constant(Value);
+//!
+//! #[async_trait::async_trait]
+//! impl PipelineStage for Constant {
+//!     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Feed }
+//!     fn get_order(&self) -> u32 { 1 }
+//!     async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+//!         context.set("input".to_string(), self.0.clone())?;
+//!         Ok(())
+//!     }
+//!     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//! }
+//!
+//! let factory = |params: &Value| {
+//!     Ok::<_, Box<dyn HexaError>>(Box::new(Constant(params["value"].clone())) as Box<dyn PipelineStage>)
+//! };
+//! let stage = factory.create(&json!({ "value": 42 })).unwrap();
+//! assert_eq!(stage.stage_type(), PipelineStageType::Feed);
+//! ```
+
+use super::error::HexaError;
+use super::pipeline::PipelineStage;
+use serde_json::Value;
+
+/// Builds a pipeline stage from config parameters.
+pub trait StageFactory: Send + Sync {
+    /// Create a stage from `params`, the stage's `params` table in the config
+    /// file. When called through a registry, `params` already satisfy the
+    /// declared schema and include its defaults; they are `null` if absent and
+    /// no defaults apply.
+    ///
+    /// # Errors
+    ///
+    /// Return an error if the parameters are missing or malformed.
+    fn create(&self, params: &Value) -> Result<Box<dyn PipelineStage>, Box<dyn HexaError>>;
+}
+
+impl<F> StageFactory for F
+where
+    F: Fn(&Value) -> Result<Box<dyn PipelineStage>, Box<dyn HexaError>> + Send + Sync,
+{
+    fn create(&self, params: &Value) -> Result<Box<dyn PipelineStage>, Box<dyn HexaError>> {
+        self(params)
+    }
+}
@@ -0,0 +1,216 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Stage Schema Contract (Core Module)
+//!
+//! This module defines [`ContextKey`] and [`StageSchema`], which let a
+//! [`PipelineStage`](super::PipelineStage) declare the context keys it reads and
+//! writes. Declared keys are checked by `Pipeline::validate` before execution.
+//!
+//! Every stage owns one namespace in the [`PipelineContext`](super::PipelineContext),
+//! named after its [`PipelineStageType`] (`feed`, `filter`, `format`, `function`,
+//! `forward`, `feedback`). Stage outputs always live in the stage's own namespace,
+//! so a Format stage cannot overwrite the keys written by Feed.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{ContextKey, PipelineStageType, StageSchema};
+//!
+//! let schema = StageSchema::new()
+//!     .with_input(ContextKey::new(PipelineStageType::Feed, "payload"))
+//!     .with_output("normalized");
+//!
+//! let outputs: Vec<String> = schema
+//!     .output_keys(PipelineStageType::Format)
+//!     .map(|key| key.to_string())
+//!     .collect();
+//! assert_eq!(outputs, vec!["format.normalized"]);
+//! ```
+
+use super::pipeline::PipelineStageType;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+impl PipelineStageType {
+    /// Context namespace owned by stages of this type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexafn_core::PipelineStageType;
+    ///
+    /// assert_eq!(PipelineStageType::Function.namespace(), "function");
+    /// ```
+    pub fn namespace(&self) -> &'static str {
+        match self {
+            PipelineStageType::Feed => "feed",
+            PipelineStageType::Filter => "filter",
+            PipelineStageType::Format => "format",
+            PipelineStageType::Function => "function",
+            PipelineStageType::Forward => "forward",
+            PipelineStageType::Feedback => "feedback",
+        }
+    }
+
+    /// Look up a stage type by its context namespace.
+    pub fn from_namespace(namespace: &str) -> Option<Self> {
+        [
+            PipelineStageType::Feed,
+            PipelineStageType::Filter,
+            PipelineStageType::Format,
+            PipelineStageType::Function,
+            PipelineStageType::Forward,
+            PipelineStageType::Feedback,
+        ]
+        .into_iter()
+        .find(|stage_type| stage_type.namespace() == namespace)
+    }
+}
+
+/// A context key qualified by the namespace of the stage that owns it.
+///
+/// Rendered as `"<namespace>.<name>"`, e.g. `feed.payload`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ContextKey {
+    /// Stage whose namespace holds the key
+    pub namespace: PipelineStageType,
+    /// Key name within the namespace
+    pub name: String,
+}
+
+impl ContextKey {
+    /// Create a key in the namespace of `namespace`.
+    pub fn new(namespace: PipelineStageType, name: impl Into<String>) -> Self {
+        Self {
+            namespace,
+            name: name.into(),
+        }
+    }
+
+    /// Parse a qualified key such as `"format.normalized"`.
+    ///
+    /// Returns `None` if the key has no namespace prefix, the prefix is not a
+    /// stage namespace, or the name is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hexafn_core::{ContextKey, PipelineStageType};
+    ///
+    /// let key = ContextKey::parse("feed.payload").unwrap();
+    /// assert_eq!(key.namespace, PipelineStageType::Feed);
+    /// assert_eq!(key.name, "payload");
+    /// assert!(ContextKey::parse("payload").is_none());
+    /// ```
+    pub fn parse(qualified: &str) -> Option<Self> {
+        let (namespace, name) = qualified.split_once('.')?;
+        if name.is_empty() {
+            return None;
+        }
+        PipelineStageType::from_namespace(namespace).map(|stage_type| Self::new(stage_type, name))
+    }
+}
+
+impl Display for ContextKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.namespace.namespace(), self.name)
+    }
+}
+
+/// Declared context inputs and outputs of a pipeline stage.
+///
+/// Inputs are fully qualified [`ContextKey`]s that must be produced by an earlier
+/// stage. Outputs are plain names; they are always written into the declaring
+/// stage's own namespace. The default (empty) schema declares nothing and is
+/// never checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageSchema {
+    /// Keys this stage reads
+    pub inputs: Vec<ContextKey>,
+    /// Key names this stage writes in its own namespace
+    pub outputs: Vec<String>,
+}
+
+impl StageSchema {
+    /// Create an empty schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare a key this stage reads.
+    pub fn with_input(mut self, key: ContextKey) -> Self {
+        self.inputs.push(key);
+        self
+    }
+
+    /// Declare a key this stage writes in its own namespace.
+    pub fn with_output(mut self, name: impl Into<String>) -> Self {
+        self.outputs.push(name.into());
+        self
+    }
+
+    /// Returns `true` if the schema declares no inputs and no outputs.
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty() && self.outputs.is_empty()
+    }
+
+    /// Fully qualified output keys for a stage of type `stage_type`.
+    pub fn output_keys(
+        &self,
+        stage_type: PipelineStageType,
+    ) -> impl Iterator<Item = ContextKey> + '_ {
+        self.outputs
+            .iter()
+            .map(move |name| ContextKey::new(stage_type, name.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespace_roundtrip() {
+        for stage_type in [
+            PipelineStageType::Feed,
+            PipelineStageType::Filter,
+            PipelineStageType::Format,
+            PipelineStageType::Function,
+            PipelineStageType::Forward,
+            PipelineStageType::Feedback,
+        ] {
+            assert_eq!(
+                PipelineStageType::from_namespace(stage_type.namespace()),
+                Some(stage_type)
+            );
+        }
+        assert_eq!(PipelineStageType::from_namespace("unknown"), None);
+    }
+
+    #[test]
+    fn test_context_key_parse_and_display() {
+        let key = ContextKey::parse("forward.target.url").unwrap();
+        assert_eq!(key.namespace, PipelineStageType::Forward);
+        assert_eq!(key.name, "target.url");
+        assert_eq!(key.to_string(), "forward.target.url");
+
+        assert!(ContextKey::parse("feed.").is_none());
+        assert!(ContextKey::parse("other.key").is_none());
+    }
+
+    #[test]
+    fn test_schema_builder() {
+        let schema = StageSchema::new()
+            .with_input(ContextKey::new(PipelineStageType::Feed, "payload"))
+            .with_output("result");
+        assert!(!schema.is_empty());
+        assert!(StageSchema::default().is_empty());
+        assert_eq!(
+            schema
+                .output_keys(PipelineStageType::Function)
+                .collect::<Vec<_>>(),
+            vec![ContextKey::new(PipelineStageType::Function, "result")]
+        );
+    }
+}
@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Upcaster Contract (Core Module)
+//!
+//! This module defines the [`Upcaster`] trait. An upcaster converts the payload of
+//! one event type from schema version N to N+1; chaining them in an
+//! [`UpcasterRegistry`](crate::UpcasterRegistry) lets stored and published events
+//! of any older version be read in their latest shape.
+//!
+//! Any `Fn(Value) -> Result<Value, Box<dyn HexaError>>` closure is an upcaster.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{HexaError, Upcaster};
+//! use serde_json::{json, Value};
+//!
+//! // v1 stored a single `name`; v2 splits it.
+//! let split_name = |payload: Value| {
+//!     let name = payload["name"].as_str().unwrap_or_default().to_string();
+//!     let (first, last) = name.split_once(' ').unwrap_or((&name, ""));
+//!     Ok::<_, Box<dyn HexaError>>(json!({ "first": first, "last": last }))
+//! };
+//!
+//! let v2 = split_name.upcast(json!({ "name": "Ada Lovelace" })).unwrap();
+//! assert_eq!(v2, json!({ "first": "Ada", "last": "Lovelace" }));
+//! ```
+
+use super::error::HexaError;
+use serde_json::Value;
+
+/// Converts an event payload from one schema version to the next.
+pub trait Upcaster: Send + Sync {
+    /// Convert `payload` to the next schema version.
+    ///
+    /// # Errors
+    ///
+    /// Return an error if the payload does not have the expected shape.
+    fn upcast(&self, payload: Value) -> Result<Value, Box<dyn HexaError>>;
+}
+
+impl<F> Upcaster for F
+where
+    F: Fn(Value) -> Result<Value, Box<dyn HexaError>> + Send + Sync,
+{
+    fn upcast(&self, payload: Value) -> Result<Value, Box<dyn HexaError>> {
+        self(payload)
+    }
+}
@@ -0,0 +1,201 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # EventSourced (Core Module)
+//!
+//! This module provides [`EventSourced`], a unit of work around an [`Aggregate`].
+//! It rehydrates state from an [`EventStore`], applies newly recorded events
+//! immediately, and appends them on [`EventSourced::commit`] using the version it
+//! was loaded at as the expected version.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{
+//!     Aggregate, EventEnvelope, EventSourced, EventStore, HexaError, InMemoryEventStore,
+//! };
+//! use serde_json::json;
+//!
+//! #[derive(Default)]
+//! struct Balance(i64);
+//!
+//! impl Aggregate for Balance {
+//!     fn apply(&mut self, event: &EventEnvelope) -> Result<(), Box<dyn HexaError>> {
+//!         if event.event_type == "account.deposited" {
+//!             self.0 += event.payload["amount"].as_i64().unwrap_or_default();
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! let store = InMemoryEventStore::new();
+//!
+//! let mut account = EventSourced::<Balance>::load(&store, "acc-1").await.unwrap();
+//! account.record(EventEnvelope::new("account.deposited", json!({ "amount": 40 }))).unwrap();
+//! account.commit(&store).await.unwrap();
+//!
+//! let account = EventSourced::<Balance>::load(&store, "acc-1").await.unwrap();
+//! assert_eq!(account.state().0, 40);
+//! assert_eq!(account.version(), 1);
+//! # });
+//! ```
+
+use crate::domain::contracts::{Aggregate, EventStore, HexaError};
+use crate::domain::value_objects::EventEnvelope;
+
+/// An aggregate together with its stream position and uncommitted events.
+#[derive(Debug)]
+pub struct EventSourced<A: Aggregate> {
+    aggregate_id: String,
+    state: A,
+    version: u64,
+    pending: Vec<EventEnvelope>,
+}
+
+impl<A: Aggregate> EventSourced<A> {
+    /// Start a new, empty stream for `aggregate_id`.
+    pub fn new(aggregate_id: impl Into<String>) -> Self {
+        Self {
+            aggregate_id: aggregate_id.into(),
+            state: A::default(),
+            version: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Rebuild the aggregate from every event stored for `aggregate_id`.
+    ///
+    /// # Errors
+    ///
+    /// Store errors and the first error returned by [`Aggregate::apply`].
+    pub async fn load(
+        store: &dyn EventStore,
+        aggregate_id: impl Into<String>,
+    ) -> Result<Self, Box<dyn HexaError>> {
+        let mut loaded = Self::new(aggregate_id);
+        for event in store.read_stream(&loaded.aggregate_id, 1).await? {
+            loaded.state.apply(&event)?;
+            loaded.version = event.sequence_number;
+        }
+        Ok(loaded)
+    }
+
+    /// Id of the stream backing this aggregate.
+    pub fn aggregate_id(&self) -> &str {
+        &self.aggregate_id
+    }
+
+    /// Current state, including pending events.
+    pub fn state(&self) -> &A {
+        &self.state
+    }
+
+    /// Version of the stream this aggregate was last synchronised with.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Events recorded but not yet committed.
+    pub fn pending(&self) -> &[EventEnvelope] {
+        &self.pending
+    }
+
+    /// Apply `event` to the state and queue it for the next commit.
+    ///
+    /// # Errors
+    ///
+    /// The error returned by [`Aggregate::apply`]; the event is not queued.
+    pub fn record(&mut self, event: EventEnvelope) -> Result<(), Box<dyn HexaError>> {
+        self.state.apply(&event)?;
+        self.pending.push(event);
+        Ok(())
+    }
+
+    /// Append pending events to `store`, expecting the stream to be at
+    /// [`version`](Self::version). Returns the new version.
+    ///
+    /// # Errors
+    ///
+    /// Store errors, notably `core.event_store.concurrency_conflict`. On error the
+    /// pending events are kept so the caller can inspect or discard them.
+    pub async fn commit(&mut self, store: &dyn EventStore) -> Result<u64, Box<dyn HexaError>> {
+        let pending = self.pending.clone();
+        self.version = store
+            .append(&self.aggregate_id, self.version, pending)
+            .await?;
+        self.pending.clear();
+        Ok(self.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::adapters::outbound::InMemoryEventStore;
+    use crate::GenericHexaError;
+    use serde_json::json;
+
+    #[derive(Debug, Default)]
+    struct Tally(Vec<i64>);
+
+    impl Aggregate for Tally {
+        fn apply(&mut self, event: &EventEnvelope) -> Result<(), Box<dyn HexaError>> {
+            match event.payload["n"].as_i64() {
+                Some(n) => {
+                    self.0.push(n);
+                    Ok(())
+                }
+                None => Err(Box::new(GenericHexaError::new("test.tally", "missing n"))),
+            }
+        }
+    }
+
+    fn n(value: i64) -> EventEnvelope {
+        EventEnvelope::new("tally.added", json!({ "n": value }))
+    }
+
+    #[tokio::test]
+    async fn test_commit_and_rehydrate() {
+        let store = InMemoryEventStore::new();
+        let mut tally = EventSourced::<Tally>::new("t");
+        tally.record(n(1)).unwrap();
+        tally.record(n(2)).unwrap();
+        assert_eq!(tally.pending().len(), 2);
+        assert_eq!(tally.commit(&store).await.unwrap(), 2);
+        assert!(tally.pending().is_empty());
+
+        let loaded = EventSourced::<Tally>::load(&store, "t").await.unwrap();
+        assert_eq!(loaded.state().0, vec![1, 2]);
+        assert_eq!(loaded.version(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stale_commit_conflicts_until_reloaded() {
+        let store = InMemoryEventStore::new();
+        let mut first = EventSourced::<Tally>::load(&store, "t").await.unwrap();
+        let mut second = EventSourced::<Tally>::load(&store, "t").await.unwrap();
+
+        first.record(n(1)).unwrap();
+        first.commit(&store).await.unwrap();
+
+        second.record(n(2)).unwrap();
+        let err = second.commit(&store).await.unwrap_err();
+        assert_eq!(err.error_code(), "core.event_store.concurrency_conflict");
+        assert_eq!(second.pending().len(), 1);
+
+        let mut second = EventSourced::<Tally>::load(&store, "t").await.unwrap();
+        second.record(n(2)).unwrap();
+        assert_eq!(second.commit(&store).await.unwrap(), 2);
+        assert_eq!(second.state().0, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_record_rejects_inapplicable_event() {
+        let mut tally = EventSourced::<Tally>::new("t");
+        assert!(tally
+            .record(EventEnvelope::new("tally.added", json!({})))
+            .is_err());
+        assert!(tally.pending().is_empty());
+    }
+}
@@ -0,0 +1,204 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # HotSwapPipeline (Core Module)
+//!
+//! This module provides [`HotSwapPipeline`], a slot holding the live version of a
+//! [`Pipeline`] that can be replaced while executions are running.
+//!
+//! Every execution takes a snapshot of the current version and runs to
+//! completion on it, so a swap never affects in-flight runs; only executions
+//! started after the swap see the new version. A replacement is checked with
+//! [`Pipeline::validate`] first and rejected, leaving the current version live,
+//! if validation fails. Versions are numbered from 1.
+//!
+//! [`PipelineWatcher`](crate::PipelineWatcher) uses it to reload pipelines from
+//! config files.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{
+//!     HexaError, HotSwapPipeline, Pipeline, PipelineContext, PipelineStage, PipelineStageType,
+//!     SixFPipeline,
+//! };
+//! use serde_json::json;
+//!
+//! struct Greeting(&'static str);
+//!
+//! #[async_trait::async_trait]
+//! impl PipelineStage for Greeting {
+//!     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Feed }
+//!     fn get_order(&self) -> u32 { 1 }
+//!     async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+//!         context.set("greeting".to_string(), json!(self.0))?;
+//!         Ok(())
+//!     }
+//!     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//! }
+//!
+//! fn pipeline(greeting: &'static str) -> SixFPipeline {
+//!     let mut pipeline = SixFPipeline::new();
+//!     pipeline.add_stage(Box::new(Greeting(greeting))).unwrap();
+//!     pipeline
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! let live = HotSwapPipeline::new(pipeline("hello")).unwrap();
+//! assert_eq!(live.swap(pipeline("hi")).unwrap(), 2);
+//!
+//! // An empty pipeline fails validation; version 2 stays live.
+//! assert!(live.swap(SixFPipeline::new()).is_err());
+//! let run = live.execute(PipelineContext::new()).await.unwrap();
+//! assert_eq!(run.context.get("greeting"), Some(&json!("hi")));
+//! assert_eq!(live.version(), 2);
+//! # });
+//! ```
+
+use crate::domain::contracts::{HexaError, Pipeline};
+use std::fmt;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// Atomically replaceable pipeline.
+pub struct HotSwapPipeline<P: Pipeline> {
+    current: RwLock<(u64, Arc<P>)>,
+}
+
+impl<P: Pipeline> HotSwapPipeline<P> {
+    /// Make `pipeline` version 1.
+    ///
+    /// # Errors
+    ///
+    /// The error returned by [`Pipeline::validate`].
+    pub fn new(pipeline: P) -> Result<Self, Box<dyn HexaError>> {
+        pipeline.validate()?;
+        Ok(Self {
+            current: RwLock::new((1, Arc::new(pipeline))),
+        })
+    }
+
+    /// Validate `pipeline` and make it the live version. Returns the new
+    /// version number.
+    ///
+    /// # Errors
+    ///
+    /// The error returned by [`Pipeline::validate`]; the current version stays
+    /// live.
+    pub fn swap(&self, pipeline: P) -> Result<u64, Box<dyn HexaError>> {
+        pipeline.validate()?;
+        let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+        let version = current.0 + 1;
+        *current = (version, Arc::new(pipeline));
+        Ok(version)
+    }
+
+    /// The live pipeline. Holding the snapshot keeps that version alive.
+    pub fn current(&self) -> Arc<P> {
+        Arc::clone(&self.read().1)
+    }
+
+    /// Number of the live version.
+    pub fn version(&self) -> u64 {
+        self.read().0
+    }
+
+    /// Run `input` through the version that is live when the call starts.
+    ///
+    /// # Errors
+    ///
+    /// The error returned by the pipeline.
+    pub async fn execute(&self, input: P::Input) -> Result<P::Output, Box<dyn HexaError>>
+    where
+        P::Input: Send,
+    {
+        let snapshot = self.current();
+        snapshot.execute(input).await
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, (u64, Arc<P>)> {
+        // Swaps replace the tuple in one assignment, so a poisoned lock still
+        // holds a consistent version.
+        self.current.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<P: Pipeline> fmt::Debug for HotSwapPipeline<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HotSwapPipeline")
+            .field("version", &self.version())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::{PipelineContext, PipelineStage, PipelineStageType};
+    use crate::domain::entities::SixFPipeline;
+    use serde_json::json;
+    use tokio::sync::Notify;
+
+    /// Feed stage that writes its label, optionally after waiting for a signal.
+    struct Labelled {
+        label: &'static str,
+        gate: Option<Arc<Notify>>,
+    }
+
+    #[async_trait::async_trait]
+    impl PipelineStage for Labelled {
+        fn stage_type(&self) -> PipelineStageType {
+            PipelineStageType::Feed
+        }
+        fn get_order(&self) -> u32 {
+            1
+        }
+        async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            if let Some(gate) = &self.gate {
+                gate.notified().await;
+            }
+            context.set("label".to_string(), json!(self.label))?;
+            Ok(())
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+    }
+
+    fn pipeline(label: &'static str, gate: Option<Arc<Notify>>) -> SixFPipeline {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(Box::new(Labelled { label, gate }))
+            .unwrap();
+        pipeline
+    }
+
+    #[tokio::test]
+    async fn test_in_flight_run_finishes_on_old_version() {
+        let gate = Arc::new(Notify::new());
+        let live = Arc::new(HotSwapPipeline::new(pipeline("v1", Some(Arc::clone(&gate)))).unwrap());
+
+        let in_flight = tokio::spawn({
+            let live = Arc::clone(&live);
+            async move { live.execute(PipelineContext::new()).await.unwrap() }
+        });
+        tokio::task::yield_now().await;
+
+        assert_eq!(live.swap(pipeline("v2", None)).unwrap(), 2);
+        let fresh = live.execute(PipelineContext::new()).await.unwrap();
+        assert_eq!(fresh.context.get("label"), Some(&json!("v2")));
+
+        gate.notify_one();
+        let old = in_flight.await.unwrap();
+        assert_eq!(old.context.get("label"), Some(&json!("v1")));
+    }
+
+    #[test]
+    fn test_invalid_pipelines_are_rejected() {
+        assert!(HotSwapPipeline::new(SixFPipeline::new()).is_err());
+
+        let live = HotSwapPipeline::new(pipeline("v1", None)).unwrap();
+        let err = live.swap(SixFPipeline::new()).unwrap_err();
+        assert_eq!(err.error_code(), "core.pipeline.empty");
+        assert_eq!(live.version(), 1);
+    }
+}
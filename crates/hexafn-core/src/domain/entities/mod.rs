@@ -0,0 +1,15 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod event_sourced;
+mod hot_swap_pipeline;
+mod pipeline_builder;
+mod six_f_pipeline;
+
+pub use event_sourced::EventSourced;
+pub use hot_swap_pipeline::HotSwapPipeline;
+pub use pipeline_builder::{
+    AfterFeed, AfterFeedback, AfterFilter, AfterFormat, AfterForward, AfterFunction, Buildable,
+    BuilderState, NoStages, PipelineBuilder, Precedes,
+};
+pub use six_f_pipeline::SixFPipeline;
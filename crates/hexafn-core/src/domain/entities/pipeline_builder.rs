@@ -0,0 +1,360 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # PipelineBuilder (Core Module)
+//!
+//! This module provides [`PipelineBuilder`], a fluent builder for [`SixFPipeline`]
+//! that uses typestate to enforce the 6F Lifecycle Flow at compile time:
+//! Feed → Filter → Format → Function → Forward → Feedback
+//!
+//! Every builder starts with `.feed(..)`. After that, any later stage may be added,
+//! but never one that belongs earlier in the lifecycle, and never the same stage
+//! twice. Skipping optional stages is allowed.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{HexaError, Pipeline, PipelineBuilder, PipelineContext, PipelineStage, PipelineStageType};
+//! use serde_json::json;
+//!
+//! struct Step(PipelineStageType, u32);
+//!
+//! #[async_trait::async_trait]
+//! impl PipelineStage for Step {
+//!     fn stage_type(&self) -> PipelineStageType { self.0 }
+//!     fn get_order(&self) -> u32 { self.1 }
+//!     async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+//!         context.set(format!("{:?}", self.0), json!(true))?;
+//!         Ok(())
+//!     }
+//!     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//! }
+//!
+//! let pipeline = PipelineBuilder::new()
+//!     .feed(Step(PipelineStageType::Feed, 1))
+//!     .filter(Step(PipelineStageType::Filter, 2))
+//!     .function(Step(PipelineStageType::Function, 4))
+//!     .build()?;
+//!
+//! assert_eq!(pipeline.get_stages().len(), 3);
+//! # Ok::<(), Box<dyn HexaError>>(())
+//! ```
+//!
+//! Adding stages out of order does not compile:
+//!
+//! ```rust,compile_fail
+//! # use hexafn_core::{HexaError, PipelineBuilder, PipelineContext, PipelineStage, PipelineStageType};
+//! # struct Step(PipelineStageType);
+//! # #[async_trait::async_trait]
+//! # impl PipelineStage for Step {
+//! #     fn stage_type(&self) -> PipelineStageType { self.0 }
+//! #     fn get_order(&self) -> u32 { 0 }
+//! #     async fn execute(&self, _: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//! #     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//! # }
+//! let builder = PipelineBuilder::new()
+//!     .feed(Step(PipelineStageType::Feed))
+//!     .format(Step(PipelineStageType::Format))
+//!     .filter(Step(PipelineStageType::Filter)); // Filter must precede Format
+//! ```
+//!
+//! Building without a Feed stage does not compile either:
+//!
+//! ```rust,compile_fail
+//! use hexafn_core::PipelineBuilder;
+//!
+//! let pipeline = PipelineBuilder::new().build();
+//! ```
+
+use std::marker::PhantomData;
+
+use super::SixFPipeline;
+use crate::domain::contracts::{HexaError, Pipeline, PipelineStage, PipelineStageType};
+use crate::domain::value_objects::PipelineError;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Typestate marker implemented by every [`PipelineBuilder`] state.
+pub trait BuilderState: sealed::Sealed {}
+
+/// Marks that a builder in this state may transition to state `Next`.
+///
+/// Implemented only for states that come earlier in the 6F lifecycle than `Next`.
+pub trait Precedes<Next: BuilderState>: BuilderState {}
+
+/// Marks builder states that hold a Feed stage and can therefore be built.
+pub trait Buildable: BuilderState {}
+
+/// Builder state before any stage has been added.
+#[derive(Debug)]
+pub struct NoStages;
+/// Builder state after the Feed stage.
+#[derive(Debug)]
+pub struct AfterFeed;
+/// Builder state after the Filter stage.
+#[derive(Debug)]
+pub struct AfterFilter;
+/// Builder state after the Format stage.
+#[derive(Debug)]
+pub struct AfterFormat;
+/// Builder state after the Function stage.
+#[derive(Debug)]
+pub struct AfterFunction;
+/// Builder state after the Forward stage.
+#[derive(Debug)]
+pub struct AfterForward;
+/// Builder state after the Feedback stage.
+#[derive(Debug)]
+pub struct AfterFeedback;
+
+macro_rules! builder_states {
+    ($($state:ty),+ $(,)?) => {
+        $(
+            impl sealed::Sealed for $state {}
+            impl BuilderState for $state {}
+        )+
+    };
+}
+
+macro_rules! precedes {
+    ($next:ty => $($state:ty),+ $(,)?) => {
+        $(impl Precedes<$next> for $state {})+
+    };
+}
+
+builder_states!(
+    NoStages,
+    AfterFeed,
+    AfterFilter,
+    AfterFormat,
+    AfterFunction,
+    AfterForward,
+    AfterFeedback,
+);
+
+precedes!(AfterFeed => NoStages);
+precedes!(AfterFilter => AfterFeed);
+precedes!(AfterFormat => AfterFeed, AfterFilter);
+precedes!(AfterFunction => AfterFeed, AfterFilter, AfterFormat);
+precedes!(AfterForward => AfterFeed, AfterFilter, AfterFormat, AfterFunction);
+precedes!(AfterFeedback => AfterFeed, AfterFilter, AfterFormat, AfterFunction, AfterForward);
+
+impl Buildable for AfterFeed {}
+impl Buildable for AfterFilter {}
+impl Buildable for AfterFormat {}
+impl Buildable for AfterFunction {}
+impl Buildable for AfterForward {}
+impl Buildable for AfterFeedback {}
+
+/// Fluent, typestate-checked builder for [`SixFPipeline`].
+///
+/// Each method accepts the stage for one 6F slot. The slot is also checked at
+/// runtime during [`PipelineBuilder::build`]: a stage whose `stage_type()` does not
+/// match the slot it was passed to is rejected with
+/// [`PipelineError::StageTypeMismatch`].
+pub struct PipelineBuilder<S: BuilderState = NoStages> {
+    stages: Vec<(PipelineStageType, Box<dyn PipelineStage>)>,
+    _state: PhantomData<S>,
+}
+
+impl PipelineBuilder<NoStages> {
+    /// Create a builder with no stages.
+    pub fn new() -> Self {
+        Self {
+            stages: Vec::new(),
+            _state: PhantomData,
+        }
+    }
+}
+
+impl Default for PipelineBuilder<NoStages> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: BuilderState> PipelineBuilder<S> {
+    fn push<Next: BuilderState>(
+        mut self,
+        slot: PipelineStageType,
+        stage: Box<dyn PipelineStage>,
+    ) -> PipelineBuilder<Next> {
+        self.stages.push((slot, stage));
+        PipelineBuilder {
+            stages: self.stages,
+            _state: PhantomData,
+        }
+    }
+
+    /// Register the Feed stage. Must be the first call on a new builder.
+    pub fn feed(self, stage: impl PipelineStage + 'static) -> PipelineBuilder<AfterFeed>
+    where
+        S: Precedes<AfterFeed>,
+    {
+        self.push(PipelineStageType::Feed, Box::new(stage))
+    }
+
+    /// Register the Filter stage.
+    pub fn filter(self, stage: impl PipelineStage + 'static) -> PipelineBuilder<AfterFilter>
+    where
+        S: Precedes<AfterFilter>,
+    {
+        self.push(PipelineStageType::Filter, Box::new(stage))
+    }
+
+    /// Register the Format stage.
+    pub fn format(self, stage: impl PipelineStage + 'static) -> PipelineBuilder<AfterFormat>
+    where
+        S: Precedes<AfterFormat>,
+    {
+        self.push(PipelineStageType::Format, Box::new(stage))
+    }
+
+    /// Register the Function stage.
+    pub fn function(self, stage: impl PipelineStage + 'static) -> PipelineBuilder<AfterFunction>
+    where
+        S: Precedes<AfterFunction>,
+    {
+        self.push(PipelineStageType::Function, Box::new(stage))
+    }
+
+    /// Register the Forward stage.
+    pub fn forward(self, stage: impl PipelineStage + 'static) -> PipelineBuilder<AfterForward>
+    where
+        S: Precedes<AfterForward>,
+    {
+        self.push(PipelineStageType::Forward, Box::new(stage))
+    }
+
+    /// Register the Feedback stage.
+    pub fn feedback(self, stage: impl PipelineStage + 'static) -> PipelineBuilder<AfterFeedback>
+    where
+        S: Precedes<AfterFeedback>,
+    {
+        self.push(PipelineStageType::Feedback, Box::new(stage))
+    }
+}
+
+impl<S: Buildable> PipelineBuilder<S> {
+    /// Assemble and validate the pipeline.
+    ///
+    /// # Errors
+    ///
+    /// - [`PipelineError::StageTypeMismatch`] if a stage was passed to the wrong slot
+    /// - Any error from [`Pipeline::add_stage`] or [`Pipeline::build`], including
+    ///   stage validation failures and `get_order()` values that contradict the
+    ///   6F lifecycle
+    pub fn build(self) -> Result<SixFPipeline, Box<dyn HexaError>> {
+        let mut pipeline = SixFPipeline::new();
+        for (slot, stage) in self.stages {
+            let actual = stage.stage_type();
+            if actual != slot {
+                return Err(Box::new(PipelineError::StageTypeMismatch {
+                    expected: slot,
+                    actual,
+                }));
+            }
+            pipeline.add_stage(stage)?;
+        }
+        pipeline.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::PipelineContext;
+    use async_trait::async_trait;
+    use serde_json::json;
+
+    struct TestStage {
+        stage_type: PipelineStageType,
+        order: u32,
+    }
+
+    fn stage(stage_type: PipelineStageType, order: u32) -> TestStage {
+        TestStage { stage_type, order }
+    }
+
+    #[async_trait]
+    impl PipelineStage for TestStage {
+        fn stage_type(&self) -> PipelineStageType {
+            self.stage_type
+        }
+        fn get_order(&self) -> u32 {
+            self.order
+        }
+        async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            let count = context.get("count").and_then(|v| v.as_u64()).unwrap_or(0);
+            context.set("count".to_string(), json!(count + 1))?;
+            Ok(())
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_full_pipeline() {
+        let pipeline = PipelineBuilder::new()
+            .feed(stage(PipelineStageType::Feed, 1))
+            .filter(stage(PipelineStageType::Filter, 2))
+            .format(stage(PipelineStageType::Format, 3))
+            .function(stage(PipelineStageType::Function, 4))
+            .forward(stage(PipelineStageType::Forward, 5))
+            .feedback(stage(PipelineStageType::Feedback, 6))
+            .build()
+            .unwrap();
+
+        assert_eq!(pipeline.get_stages().len(), 6);
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+        assert_eq!(run.context.get("count"), Some(&json!(6)));
+    }
+
+    #[test]
+    fn test_build_with_optional_stages_skipped() {
+        let pipeline = PipelineBuilder::new()
+            .feed(stage(PipelineStageType::Feed, 1))
+            .forward(stage(PipelineStageType::Forward, 5))
+            .build()
+            .unwrap();
+
+        let types: Vec<_> = pipeline
+            .get_stages()
+            .iter()
+            .map(|s| s.stage_type())
+            .collect();
+        assert_eq!(
+            types,
+            vec![PipelineStageType::Feed, PipelineStageType::Forward]
+        );
+    }
+
+    #[test]
+    fn test_build_rejects_stage_in_wrong_slot() {
+        let error = PipelineBuilder::new()
+            .feed(stage(PipelineStageType::Feed, 1))
+            .filter(stage(PipelineStageType::Format, 3))
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(error.error_code(), "core.pipeline.stage_type_mismatch");
+        assert_eq!(
+            error.to_string(),
+            "expected a Filter stage but got a Format stage"
+        );
+    }
+
+    #[test]
+    fn test_build_rejects_contradicting_orders() {
+        let error = PipelineBuilder::new()
+            .feed(stage(PipelineStageType::Feed, 10))
+            .filter(stage(PipelineStageType::Filter, 2))
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(error.error_code(), "core.pipeline.stage_order_violation");
+    }
+}
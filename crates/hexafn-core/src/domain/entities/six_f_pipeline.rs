@@ -0,0 +1,1213 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # SixFPipeline (Core Module)
+//!
+//! This module provides [`SixFPipeline`], the reference implementation of the
+//! [`Pipeline`] contract. It owns a set of boxed [`PipelineStage`]s, keeps them
+//! sorted by `get_order()` and [`PipelineStageType`], and threads a single
+//! [`PipelineContext`] through every stage of the 6F Lifecycle Flow:
+//! Feed → Filter → Format → Function → Forward → Feedback
+//!
+//! ## Validation Rules
+//!
+//! - A pipeline must contain at least one stage
+//! - A `Feed` stage is required; all other stage types are optional
+//! - Each stage type may appear at most once
+//! - Sorting stages by `get_order()` must yield the 6F lifecycle sequence
+//! - Every stage must pass its own `validate()`
+//! - Every input declared in a stage's [`StageSchema`](crate::StageSchema) must be declared as an
+//!   output by an earlier stage
+//!
+//! ## Stage Outcomes
+//!
+//! The engine calls [`PipelineStage::run`] and honours the returned
+//! [`StageOutcome`]:
+//!
+//! - `Continue` moves on to the next stage
+//! - `Skip` bypasses the remaining stages but still runs Feedback, if present
+//! - `Halt` ends the run immediately
+//! - `Retry` runs the same stage again, up to [`SixFPipeline::with_max_retries`]
+//!   extra attempts; context changes from earlier attempts are kept
+//!
+//! Runs ended by `Skip` or `Halt` return `Ok` with a [`PipelineRunStatus`] naming
+//! the stage that ended them.
+//!
+//! ## Retry Policies
+//!
+//! When a stage fails, the engine consults [`PipelineStage::retry_policy`]. Errors
+//! whose kind the policy lists are retried after the policy's backoff delay, up to
+//! its attempt limit; any other error, or a delay that would outlast the pipeline
+//! deadline, fails the run with the stage's last error.
+//!
+//! ## Timeouts and Deadlines
+//!
+//! Each stage attempt is limited by [`PipelineStage::timeout`] and by the time left
+//! before the pipeline deadline, whichever is shorter. The deadline comes from
+//! [`SixFPipeline::with_deadline`] or from the context metadata, and is written
+//! back to the context so stages can read [`PipelineContext::remaining_budget`].
+//! A stage that runs over is cancelled and the run fails with a `Timeout`-kind
+//! [`PipelineError::StageTimeout`] or [`PipelineError::DeadlineExceeded`]. Context
+//! writes made before cancellation are seen by a retry of the stage, but a run
+//! that fails returns only the error; the context is dropped with it.
+//!
+//! ## Time
+//!
+//! Deadlines, retry delays and timeouts are measured on the context's
+//! [`Clock`](crate::Clock). A pipeline configured with [`SixFPipeline::with_clock`]
+//! installs its clock into every context it runs, so a
+//! [`ManualClock`](crate::ManualClock) makes runs fully deterministic.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{HexaError, Pipeline, PipelineContext, PipelineStage, PipelineStageType};
+//! use hexafn_core::SixFPipeline;
+//! use serde_json::json;
+//!
+//! struct Feed;
+//!
+//! #[async_trait::async_trait]
+//! impl PipelineStage for Feed {
+//!     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Feed }
+//!     fn get_order(&self) -> u32 { 1 }
+//!     async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+//!         context.set("input".to_string(), json!("hello"))?;
+//!         Ok(())
+//!     }
+//!     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! let mut pipeline = SixFPipeline::new();
+//! pipeline.add_stage(Box::new(Feed))?;
+//! let pipeline = pipeline.build()?;
+//!
+//! let run = pipeline.execute(PipelineContext::new()).await?;
+//! assert!(run.is_completed());
+//! assert_eq!(run.context.get("input"), Some(&json!("hello")));
+//! # Ok::<(), Box<dyn HexaError>>(())
+//! # }).unwrap();
+//! ```
+
+use crate::domain::contracts::{
+    Clock, HexaError, Pipeline, PipelineContext, PipelineStage, PipelineStageType, StageOutcome,
+};
+use crate::domain::value_objects::{PipelineError, PipelineRun, PipelineRunStatus, RetryPolicy};
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Concrete 6F pipeline engine.
+///
+/// Stages are kept sorted by `(get_order(), stage_type())` as they are added, so
+/// [`Pipeline::get_stages`] always returns them in execution order.
+pub struct SixFPipeline {
+    stages: Vec<Box<dyn PipelineStage>>,
+    max_retries: u32,
+    deadline: Option<Duration>,
+    clock: Option<Arc<dyn Clock>>,
+}
+
+impl SixFPipeline {
+    /// Number of extra attempts a stage gets when it returns [`StageOutcome::Retry`].
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+    /// Create an empty pipeline.
+    ///
+    /// Stages are added with [`Pipeline::add_stage`] and the result is checked
+    /// with [`Pipeline::build`] before execution.
+    pub fn new() -> Self {
+        Self {
+            stages: Vec::new(),
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            deadline: None,
+            clock: None,
+        }
+    }
+
+    /// Run every context on `clock` instead of the clock it was created with.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Limit every run to `budget`, measured from the start of
+    /// [`Pipeline::execute`].
+    ///
+    /// If the input context already carries an earlier deadline, that one wins.
+    pub fn with_deadline(mut self, budget: Duration) -> Self {
+        self.deadline = Some(budget);
+        self
+    }
+
+    /// Set how many extra attempts a stage gets when it returns
+    /// [`StageOutcome::Retry`]. Once exhausted, the run fails with
+    /// [`PipelineError::RetryExhausted`].
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Run a single stage, repeating it while it asks for a retry or fails with an
+    /// error its [`RetryPolicy`] allows retrying.
+    async fn run_stage(
+        &self,
+        stage: &dyn PipelineStage,
+        context: &mut PipelineContext,
+    ) -> Result<StageOutcome, Box<dyn HexaError>> {
+        let policy = stage.retry_policy();
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            tracing::debug!(
+                stage_type = ?stage.stage_type(),
+                order = stage.get_order(),
+                attempt = attempts,
+                "executing pipeline stage"
+            );
+            let outcome = match self.run_attempt(stage, context).await {
+                Ok(outcome) => outcome,
+                Err(error) => {
+                    let Some(delay) =
+                        Self::retry_delay(policy.as_ref(), &*error, attempts, context)
+                    else {
+                        return Err(error);
+                    };
+                    tracing::warn!(
+                        stage_type = ?stage.stage_type(),
+                        attempt = attempts,
+                        error_code = error.error_code(),
+                        delay_ms = delay.as_millis() as u64,
+                        "retrying failed pipeline stage"
+                    );
+                    let clock = Arc::clone(context.clock());
+                    clock.sleep(delay).await;
+                    continue;
+                }
+            };
+            match outcome {
+                StageOutcome::Retry if attempts > self.max_retries => {
+                    return Err(Box::new(PipelineError::RetryExhausted {
+                        stage_type: stage.stage_type(),
+                        attempts,
+                    }));
+                }
+                StageOutcome::Retry => continue,
+                outcome => return Ok(outcome),
+            }
+        }
+    }
+
+    /// Delay before retrying a failed attempt, or `None` if the error must be
+    /// returned: no policy, a non-retryable kind, attempts used up, or a delay
+    /// that would outlast the pipeline deadline.
+    fn retry_delay(
+        policy: Option<&RetryPolicy>,
+        error: &dyn HexaError,
+        attempt: u32,
+        context: &PipelineContext,
+    ) -> Option<Duration> {
+        let policy = policy?;
+        if !policy.should_retry(error.error_kind(), attempt) {
+            return None;
+        }
+        let delay = policy.delay_for(attempt);
+        match context.remaining_budget() {
+            Some(remaining) if delay >= remaining => None,
+            _ => Some(delay),
+        }
+    }
+
+    /// Run one stage attempt under its timeout and the remaining pipeline budget.
+    async fn run_attempt(
+        &self,
+        stage: &dyn PipelineStage,
+        context: &mut PipelineContext,
+    ) -> Result<StageOutcome, Box<dyn HexaError>> {
+        let stage_type = stage.stage_type();
+        let stage_timeout = stage.timeout();
+        let remaining = context.remaining_budget();
+        if remaining == Some(Duration::ZERO) {
+            return Err(Box::new(PipelineError::DeadlineExceeded { stage_type }));
+        }
+
+        let limit = match (stage_timeout, remaining) {
+            (Some(timeout), Some(remaining)) => timeout.min(remaining),
+            (Some(limit), None) | (None, Some(limit)) => limit,
+            (None, None) => return stage.run(context).await,
+        };
+        let clock = Arc::clone(context.clock());
+        tokio::select! {
+            biased;
+            result = stage.run(context) => result,
+            () = clock.sleep(limit) => Err(Box::new(if stage_timeout == Some(limit) {
+                PipelineError::StageTimeout {
+                    stage_type,
+                    timeout_ms: limit.as_millis() as u64,
+                }
+            } else {
+                PipelineError::DeadlineExceeded { stage_type }
+            })),
+        }
+    }
+
+    /// Tighten the context deadline to this pipeline's budget, if one is set.
+    fn apply_deadline(&self, context: &mut PipelineContext) {
+        let Some(deadline) = self
+            .deadline
+            .and_then(|budget| chrono::Duration::from_std(budget).ok())
+            .map(|budget| context.clock().now() + budget)
+        else {
+            return;
+        };
+        let metadata = context.metadata_mut();
+        metadata.deadline = Some(match metadata.deadline {
+            Some(existing) => existing.min(deadline),
+            None => deadline,
+        });
+    }
+
+    fn sort_stages(&mut self) {
+        self.stages
+            .sort_by_key(|stage| (stage.get_order(), stage.stage_type()));
+    }
+}
+
+impl Default for SixFPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Pipeline for SixFPipeline {
+    type Input = PipelineContext;
+    type Output = PipelineRun;
+
+    /// Execute every stage in order, threading one context through the run.
+    ///
+    /// The first stage error aborts the run and is returned unchanged. Stages
+    /// that skip or halt end the run cleanly; see the module docs.
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Box<dyn HexaError>> {
+        let mut context = input;
+        if let Some(clock) = &self.clock {
+            context.set_clock(Arc::clone(clock));
+        }
+        self.apply_deadline(&mut context);
+        let mut status = PipelineRunStatus::Completed;
+        let mut index = 0;
+        while index < self.stages.len() {
+            let stage = self.stages[index].as_ref();
+            let stage_type = stage.stage_type();
+            context.enter_stage(stage_type);
+            let outcome = self.run_stage(stage, &mut context).await?;
+            context.leave_stage();
+            match outcome {
+                StageOutcome::Continue | StageOutcome::Retry => index += 1,
+                StageOutcome::Halt => {
+                    return Ok(PipelineRun::new(
+                        context,
+                        PipelineRunStatus::Halted { stage_type },
+                    ));
+                }
+                StageOutcome::Skip => {
+                    tracing::debug!(stage_type = ?stage_type, "pipeline stage skipped event");
+                    status = PipelineRunStatus::Skipped { stage_type };
+                    index = self
+                        .stages
+                        .iter()
+                        .position(|s| s.stage_type() == PipelineStageType::Feedback)
+                        .filter(|&feedback| feedback > index)
+                        .unwrap_or(self.stages.len());
+                }
+            }
+        }
+        Ok(PipelineRun::new(context, status))
+    }
+
+    /// Validate and insert a stage, rejecting a second stage of the same type.
+    fn add_stage(&mut self, stage: Box<dyn PipelineStage>) -> Result<(), Box<dyn HexaError>> {
+        stage.validate()?;
+        let stage_type = stage.stage_type();
+        if self.stages.iter().any(|s| s.stage_type() == stage_type) {
+            return Err(Box::new(PipelineError::DuplicateStage { stage_type }));
+        }
+        self.stages.push(stage);
+        self.sort_stages();
+        Ok(())
+    }
+
+    fn get_stages(&self) -> &Vec<Box<dyn PipelineStage>> {
+        &self.stages
+    }
+
+    fn build(self) -> Result<Self, Box<dyn HexaError>> {
+        self.validate()?;
+        Ok(self)
+    }
+
+    fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+        if self.stages.is_empty() {
+            return Err(Box::new(PipelineError::EmptyPipeline));
+        }
+        if !self
+            .stages
+            .iter()
+            .any(|s| s.stage_type() == PipelineStageType::Feed)
+        {
+            return Err(Box::new(PipelineError::MissingStage {
+                stage_type: PipelineStageType::Feed,
+            }));
+        }
+
+        for stage in &self.stages {
+            stage.validate()?;
+        }
+
+        for pair in self.stages.windows(2) {
+            let (previous, current) = (&pair[0], &pair[1]);
+            if current.stage_type() == previous.stage_type() {
+                return Err(Box::new(PipelineError::DuplicateStage {
+                    stage_type: current.stage_type(),
+                }));
+            }
+            if current.stage_type() < previous.stage_type() {
+                return Err(Box::new(PipelineError::StageOrderViolation {
+                    stage_type: current.stage_type(),
+                    order: current.get_order(),
+                    previous: previous.stage_type(),
+                    previous_order: previous.get_order(),
+                }));
+            }
+        }
+
+        let mut produced = HashSet::new();
+        for stage in &self.stages {
+            let schema = stage.schema();
+            if let Some(missing) = schema.inputs.iter().find(|key| !produced.contains(*key)) {
+                return Err(Box::new(PipelineError::UnsatisfiedInput {
+                    stage_type: stage.stage_type(),
+                    key: missing.to_string(),
+                }));
+            }
+            produced.extend(schema.output_keys(stage.stage_type()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::{ContextKey, HexaErrorKind, HexaErrorSeverity, StageSchema};
+    use crate::domain::services::ManualClock;
+    use crate::domain::value_objects::Backoff;
+    use chrono::Utc;
+    use serde_json::json;
+    use std::fmt::{Display, Formatter};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct StageFailure;
+
+    impl Display for StageFailure {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "stage failed")
+        }
+    }
+
+    impl HexaError for StageFailure {
+        fn error_code(&self) -> &str {
+            "core.test.stage_failed"
+        }
+        fn error_message(&self) -> &str {
+            "Stage failed"
+        }
+        fn error_kind(&self) -> HexaErrorKind {
+            HexaErrorKind::Internal
+        }
+        fn error_severity(&self) -> HexaErrorSeverity {
+            HexaErrorSeverity::Medium
+        }
+    }
+
+    /// Records its stage type into the `trace` array when executed.
+    struct RecordingStage {
+        stage_type: PipelineStageType,
+        order: u32,
+        valid: bool,
+        fail: bool,
+    }
+
+    impl RecordingStage {
+        fn new(stage_type: PipelineStageType, order: u32) -> Box<Self> {
+            Box::new(Self {
+                stage_type,
+                order,
+                valid: true,
+                fail: false,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl PipelineStage for RecordingStage {
+        fn stage_type(&self) -> PipelineStageType {
+            self.stage_type
+        }
+        fn get_order(&self) -> u32 {
+            self.order
+        }
+        async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            if self.fail {
+                return Err(Box::new(StageFailure));
+            }
+            let mut trace = context
+                .get("trace")
+                .and_then(|v| v.as_array().cloned())
+                .unwrap_or_default();
+            trace.push(json!(format!("{:?}", self.stage_type)));
+            context.set("trace".to_string(), json!(trace))?;
+            Ok(())
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            if self.valid {
+                Ok(())
+            } else {
+                Err(Box::new(StageFailure))
+            }
+        }
+    }
+
+    /// Records its stage type like [`RecordingStage`], then returns scripted outcomes.
+    struct OutcomeStage {
+        stage_type: PipelineStageType,
+        order: u32,
+        outcomes: Mutex<Vec<StageOutcome>>,
+    }
+
+    impl OutcomeStage {
+        /// `outcomes` are returned in order; `Continue` once they run out.
+        fn new(stage_type: PipelineStageType, order: u32, outcomes: &[StageOutcome]) -> Box<Self> {
+            let mut outcomes = outcomes.to_vec();
+            outcomes.reverse();
+            Box::new(Self {
+                stage_type,
+                order,
+                outcomes: Mutex::new(outcomes),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl PipelineStage for OutcomeStage {
+        fn stage_type(&self) -> PipelineStageType {
+            self.stage_type
+        }
+        fn get_order(&self) -> u32 {
+            self.order
+        }
+        async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            let mut trace = context
+                .get("trace")
+                .and_then(|v| v.as_array().cloned())
+                .unwrap_or_default();
+            trace.push(json!(format!("{:?}", self.stage_type)));
+            context.set("trace".to_string(), json!(trace))?;
+            Ok(())
+        }
+        async fn run(
+            &self,
+            context: &mut PipelineContext,
+        ) -> Result<StageOutcome, Box<dyn HexaError>> {
+            self.execute(context).await?;
+            Ok(self
+                .outcomes
+                .lock()
+                .unwrap()
+                .pop()
+                .unwrap_or(StageOutcome::Continue))
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+    }
+
+    /// Declares a schema and writes `true` to `write` when executed.
+    struct SchemaStage {
+        stage_type: PipelineStageType,
+        schema: StageSchema,
+        write: Option<ContextKey>,
+    }
+
+    #[async_trait]
+    impl PipelineStage for SchemaStage {
+        fn stage_type(&self) -> PipelineStageType {
+            self.stage_type
+        }
+        fn get_order(&self) -> u32 {
+            self.stage_type as u32 + 1
+        }
+        async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            match &self.write {
+                Some(key) => context.set_namespaced(key, &true),
+                None => Ok(()),
+            }
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+        fn schema(&self) -> StageSchema {
+            self.schema.clone()
+        }
+    }
+
+    /// Sleeps for `delay`, after recording the remaining budget in `budget_ms`.
+    struct SlowStage {
+        stage_type: PipelineStageType,
+        delay: Duration,
+        timeout: Option<Duration>,
+    }
+
+    #[async_trait]
+    impl PipelineStage for SlowStage {
+        fn stage_type(&self) -> PipelineStageType {
+            self.stage_type
+        }
+        fn get_order(&self) -> u32 {
+            self.stage_type as u32 + 1
+        }
+        async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            if let Some(budget) = context.remaining_budget() {
+                context.set("budget_ms".to_string(), json!(budget.as_millis() as u64))?;
+            }
+            tokio::time::sleep(self.delay).await;
+            Ok(())
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+        fn timeout(&self) -> Option<Duration> {
+            self.timeout
+        }
+    }
+
+    fn slow_pipeline(delay_ms: u64, timeout_ms: Option<u64>) -> SixFPipeline {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 1))
+            .unwrap();
+        pipeline
+            .add_stage(Box::new(SlowStage {
+                stage_type: PipelineStageType::Function,
+                delay: Duration::from_millis(delay_ms),
+                timeout: timeout_ms.map(Duration::from_millis),
+            }))
+            .unwrap();
+        pipeline
+    }
+
+    #[derive(Debug)]
+    struct KindFailure(HexaErrorKind);
+
+    impl Display for KindFailure {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?} failure", self.0)
+        }
+    }
+
+    impl HexaError for KindFailure {
+        fn error_code(&self) -> &str {
+            "core.test.kind_failure"
+        }
+        fn error_message(&self) -> &str {
+            "Stage failed with a chosen kind"
+        }
+        fn error_kind(&self) -> HexaErrorKind {
+            self.0
+        }
+        fn error_severity(&self) -> HexaErrorSeverity {
+            HexaErrorSeverity::Medium
+        }
+    }
+
+    /// Fails with `kind` for the first `failures` executions, then succeeds.
+    struct FlakyStage {
+        kind: HexaErrorKind,
+        failures: u32,
+        calls: AtomicU32,
+        policy: Option<RetryPolicy>,
+    }
+
+    impl FlakyStage {
+        fn new(kind: HexaErrorKind, failures: u32, policy: Option<RetryPolicy>) -> Arc<Self> {
+            Arc::new(Self {
+                kind,
+                failures,
+                calls: AtomicU32::new(0),
+                policy,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl PipelineStage for Arc<FlakyStage> {
+        fn stage_type(&self) -> PipelineStageType {
+            PipelineStageType::Forward
+        }
+        fn get_order(&self) -> u32 {
+            5
+        }
+        async fn execute(&self, _context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(Box::new(KindFailure(self.kind)));
+            }
+            Ok(())
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+        fn retry_policy(&self) -> Option<RetryPolicy> {
+            self.policy.clone()
+        }
+    }
+
+    fn flaky_pipeline(stage: &Arc<FlakyStage>) -> SixFPipeline {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 1))
+            .unwrap();
+        pipeline.add_stage(Box::new(stage.clone())).unwrap();
+        pipeline
+    }
+
+    fn feed_payload() -> ContextKey {
+        ContextKey::new(PipelineStageType::Feed, "payload")
+    }
+
+    fn outcome_pipeline(filter_outcomes: &[StageOutcome]) -> SixFPipeline {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 1))
+            .unwrap();
+        pipeline
+            .add_stage(OutcomeStage::new(
+                PipelineStageType::Filter,
+                2,
+                filter_outcomes,
+            ))
+            .unwrap();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Function, 4))
+            .unwrap();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feedback, 6))
+            .unwrap();
+        pipeline
+    }
+
+    fn full_pipeline() -> SixFPipeline {
+        let mut pipeline = SixFPipeline::new();
+        // Added out of order on purpose
+        for (stage_type, order) in [
+            (PipelineStageType::Feedback, 6),
+            (PipelineStageType::Format, 3),
+            (PipelineStageType::Feed, 1),
+            (PipelineStageType::Forward, 5),
+            (PipelineStageType::Filter, 2),
+            (PipelineStageType::Function, 4),
+        ] {
+            pipeline
+                .add_stage(RecordingStage::new(stage_type, order))
+                .unwrap();
+        }
+        pipeline
+    }
+
+    #[test]
+    fn test_stages_are_sorted_on_add() {
+        let pipeline = full_pipeline();
+        let types: Vec<_> = pipeline
+            .get_stages()
+            .iter()
+            .map(|s| s.stage_type())
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                PipelineStageType::Feed,
+                PipelineStageType::Filter,
+                PipelineStageType::Format,
+                PipelineStageType::Function,
+                PipelineStageType::Forward,
+                PipelineStageType::Feedback,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_threads_context_in_6f_order() {
+        let pipeline = full_pipeline().build().unwrap();
+        let mut input = PipelineContext::new();
+        input.set("request".to_string(), json!("abc")).unwrap();
+
+        let run = pipeline.execute(input).await.unwrap();
+        assert!(run.is_completed());
+        let output = run.context;
+        assert_eq!(output.get("request"), Some(&json!("abc")));
+        assert_eq!(
+            output.get("trace"),
+            Some(&json!([
+                "Feed", "Filter", "Format", "Function", "Forward", "Feedback"
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_records_stage_provenance() {
+        let pipeline = full_pipeline().build().unwrap();
+        let mut input = PipelineContext::new();
+        input.set("request".to_string(), json!("abc")).unwrap();
+
+        let run = pipeline.execute(input).await.unwrap();
+        let metadata = run.context.metadata();
+        assert_eq!(metadata.written_by("request"), None);
+        assert_eq!(
+            metadata.written_by("trace"),
+            Some(PipelineStageType::Feedback)
+        );
+        let writers: Vec<_> = metadata
+            .history("trace")
+            .filter_map(|record| record.stage_type)
+            .collect();
+        assert_eq!(writers.len(), 6);
+        assert_eq!(writers[0], PipelineStageType::Feed);
+    }
+
+    #[tokio::test]
+    async fn test_execute_stops_on_first_stage_error() {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 1))
+            .unwrap();
+        pipeline
+            .add_stage(Box::new(RecordingStage {
+                stage_type: PipelineStageType::Function,
+                order: 4,
+                valid: true,
+                fail: true,
+            }))
+            .unwrap();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feedback, 6))
+            .unwrap();
+
+        let error = pipeline.execute(PipelineContext::new()).await.unwrap_err();
+        assert_eq!(error.error_code(), "core.test.stage_failed");
+    }
+
+    #[tokio::test]
+    async fn test_skip_jumps_to_feedback() {
+        let pipeline = outcome_pipeline(&[StageOutcome::Skip]).build().unwrap();
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+
+        assert_eq!(
+            run.status,
+            PipelineRunStatus::Skipped {
+                stage_type: PipelineStageType::Filter
+            }
+        );
+        assert_eq!(
+            run.context.get("trace"),
+            Some(&json!(["Feed", "Filter", "Feedback"]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_skip_without_feedback_ends_run() {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(OutcomeStage::new(
+                PipelineStageType::Feed,
+                1,
+                &[StageOutcome::Skip],
+            ))
+            .unwrap();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Function, 4))
+            .unwrap();
+
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+        assert!(!run.is_completed());
+        assert_eq!(run.context.get("trace"), Some(&json!(["Feed"])));
+    }
+
+    #[tokio::test]
+    async fn test_halt_stops_without_feedback() {
+        let pipeline = outcome_pipeline(&[StageOutcome::Halt]).build().unwrap();
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+
+        assert_eq!(
+            run.status,
+            PipelineRunStatus::Halted {
+                stage_type: PipelineStageType::Filter
+            }
+        );
+        assert_eq!(run.context.get("trace"), Some(&json!(["Feed", "Filter"])));
+    }
+
+    #[tokio::test]
+    async fn test_retry_reruns_stage() {
+        let pipeline = outcome_pipeline(&[StageOutcome::Retry, StageOutcome::Retry])
+            .build()
+            .unwrap();
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+
+        assert!(run.is_completed());
+        assert_eq!(
+            run.context.get("trace"),
+            Some(&json!([
+                "Feed", "Filter", "Filter", "Filter", "Function", "Feedback"
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_limit_is_enforced() {
+        let pipeline = outcome_pipeline(&[StageOutcome::Retry; 3])
+            .with_max_retries(2)
+            .build()
+            .unwrap();
+        let error = pipeline.execute(PipelineContext::new()).await.unwrap_err();
+
+        assert_eq!(error.error_code(), "core.pipeline.retry_exhausted");
+        assert_eq!(
+            error.to_string(),
+            "Filter stage requested a retry after 3 attempts"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stage_timeout_cancels_stage() {
+        let pipeline = slow_pipeline(500, Some(20)).build().unwrap();
+        let error = pipeline.execute(PipelineContext::new()).await.unwrap_err();
+
+        assert_eq!(error.error_code(), "core.pipeline.stage_timeout");
+        assert_eq!(error.error_kind(), HexaErrorKind::Timeout);
+        assert_eq!(
+            error.to_string(),
+            "Function stage exceeded its 20ms timeout"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fast_stage_within_timeout_completes() {
+        let pipeline = slow_pipeline(1, Some(1_000)).build().unwrap();
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+        assert!(run.is_completed());
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_deadline_cancels_stage() {
+        let pipeline = slow_pipeline(500, Some(10_000))
+            .with_deadline(Duration::from_millis(30))
+            .build()
+            .unwrap();
+        let error = pipeline.execute(PipelineContext::new()).await.unwrap_err();
+
+        assert_eq!(error.error_code(), "core.pipeline.deadline_exceeded");
+        assert_eq!(error.error_kind(), HexaErrorKind::Timeout);
+        assert!(error.to_string().contains("Function"));
+    }
+
+    #[tokio::test]
+    async fn test_deadline_is_propagated_to_context() {
+        let pipeline = slow_pipeline(0, None)
+            .with_deadline(Duration::from_secs(60))
+            .build()
+            .unwrap();
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+
+        assert!(run.context.metadata().deadline.is_some());
+        let budget = run
+            .context
+            .get("budget_ms")
+            .and_then(|v| v.as_u64())
+            .unwrap();
+        assert!(budget > 50_000 && budget <= 60_000);
+    }
+
+    #[tokio::test]
+    async fn test_expired_context_deadline_fails_before_stage_runs() {
+        let pipeline = slow_pipeline(0, None).build().unwrap();
+        let mut input = PipelineContext::new();
+        input.metadata_mut().deadline = Some(Utc::now() - chrono::Duration::seconds(1));
+
+        let error = pipeline.execute(input).await.unwrap_err();
+        assert_eq!(error.error_code(), "core.pipeline.deadline_exceeded");
+        assert!(error.to_string().contains("Feed"));
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_recovers_transient_failure() {
+        let policy = RetryPolicy::new(3).with_backoff(Backoff::Fixed(Duration::from_millis(1)));
+        let stage = FlakyStage::new(HexaErrorKind::External, 2, Some(policy));
+        let pipeline = flaky_pipeline(&stage).build().unwrap();
+
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+        assert!(run.is_completed());
+        assert_eq!(stage.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_gives_up_after_max_attempts() {
+        let stage = FlakyStage::new(HexaErrorKind::Timeout, 10, Some(RetryPolicy::new(3)));
+        let pipeline = flaky_pipeline(&stage).build().unwrap();
+
+        let error = pipeline.execute(PipelineContext::new()).await.unwrap_err();
+        assert_eq!(error.error_code(), "core.test.kind_failure");
+        assert_eq!(stage.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_skips_unlisted_kinds() {
+        for kind in [HexaErrorKind::Validation, HexaErrorKind::Internal] {
+            let stage = FlakyStage::new(kind, 1, Some(RetryPolicy::new(5)));
+            let pipeline = flaky_pipeline(&stage).build().unwrap();
+
+            assert!(pipeline.execute(PipelineContext::new()).await.is_err());
+            assert_eq!(stage.calls.load(Ordering::SeqCst), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_policy_fails_immediately() {
+        let stage = FlakyStage::new(HexaErrorKind::External, 1, None);
+        let pipeline = flaky_pipeline(&stage).build().unwrap();
+
+        assert!(pipeline.execute(PipelineContext::new()).await.is_err());
+        assert_eq!(stage.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_backoff_respects_deadline() {
+        let policy = RetryPolicy::new(5).with_backoff(Backoff::Fixed(Duration::from_secs(30)));
+        let stage = FlakyStage::new(HexaErrorKind::External, 1, Some(policy));
+        let pipeline = flaky_pipeline(&stage)
+            .with_deadline(Duration::from_secs(1))
+            .build()
+            .unwrap();
+
+        let error = pipeline.execute(PipelineContext::new()).await.unwrap_err();
+        assert_eq!(error.error_kind(), HexaErrorKind::External);
+        assert_eq!(stage.calls.load(Ordering::SeqCst), 1);
+    }
+
+    async fn parked(clock: &ManualClock) {
+        while clock.sleeper_count() == 0 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_manual_clock_drives_deadline_and_write_log() {
+        let clock = ManualClock::default();
+        let pipeline = slow_pipeline(0, None)
+            .with_deadline(Duration::from_secs(60))
+            .with_clock(Arc::new(clock.clone()))
+            .build()
+            .unwrap();
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+
+        let metadata = run.context.metadata();
+        assert_eq!(metadata.started_at, clock.now());
+        assert_eq!(
+            metadata.deadline,
+            Some(clock.now() + chrono::Duration::seconds(60))
+        );
+        assert_eq!(run.context.get("budget_ms"), Some(&json!(60_000)));
+        assert!(metadata
+            .writes()
+            .iter()
+            .all(|record| record.written_at == clock.now()));
+    }
+
+    #[tokio::test]
+    async fn test_manual_clock_drives_retry_backoff() {
+        let clock = ManualClock::default();
+        let policy = RetryPolicy::new(3).with_backoff(Backoff::Fixed(Duration::from_secs(30)));
+        let stage = FlakyStage::new(HexaErrorKind::External, 1, Some(policy));
+        let pipeline = flaky_pipeline(&stage)
+            .with_clock(Arc::new(clock.clone()))
+            .build()
+            .unwrap();
+        let run = tokio::spawn(async move { pipeline.execute(PipelineContext::new()).await });
+
+        parked(&clock).await;
+        assert_eq!(stage.calls.load(Ordering::SeqCst), 1);
+        clock.advance(Duration::from_secs(30));
+
+        assert!(run.await.unwrap().unwrap().is_completed());
+        assert_eq!(stage.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_manual_clock_drives_stage_timeout() {
+        let clock = ManualClock::default();
+        let pipeline = slow_pipeline(3_600_000, Some(5_000))
+            .with_clock(Arc::new(clock.clone()))
+            .build()
+            .unwrap();
+        let run = tokio::spawn(async move { pipeline.execute(PipelineContext::new()).await });
+
+        parked(&clock).await;
+        clock.advance(Duration::from_secs(5));
+
+        let error = run.await.unwrap().unwrap_err();
+        assert_eq!(error.error_code(), "core.pipeline.stage_timeout");
+    }
+
+    #[test]
+    fn test_add_stage_rejects_duplicate_stage_type() {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 1))
+            .unwrap();
+        let error = pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 2))
+            .unwrap_err();
+        assert_eq!(error.error_code(), "core.pipeline.duplicate_stage");
+        assert_eq!(pipeline.get_stages().len(), 1);
+    }
+
+    #[test]
+    fn test_add_stage_rejects_invalid_stage() {
+        let mut pipeline = SixFPipeline::new();
+        let error = pipeline
+            .add_stage(Box::new(RecordingStage {
+                stage_type: PipelineStageType::Feed,
+                order: 1,
+                valid: false,
+                fail: false,
+            }))
+            .unwrap_err();
+        assert_eq!(error.error_code(), "core.test.stage_failed");
+        assert!(pipeline.get_stages().is_empty());
+    }
+
+    #[test]
+    fn test_build_rejects_empty_pipeline() {
+        let error = SixFPipeline::new().build().err().unwrap();
+        assert_eq!(error.error_code(), "core.pipeline.empty");
+        assert_eq!(error.error_kind(), HexaErrorKind::Validation);
+    }
+
+    #[test]
+    fn test_build_requires_feed_stage() {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Function, 4))
+            .unwrap();
+        let error = pipeline.build().err().unwrap();
+        assert_eq!(error.error_code(), "core.pipeline.missing_stage");
+    }
+
+    #[test]
+    fn test_build_rejects_order_contradicting_lifecycle() {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 1))
+            .unwrap();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Function, 2))
+            .unwrap();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Filter, 3))
+            .unwrap();
+
+        let error = pipeline.build().err().unwrap();
+        assert_eq!(error.error_code(), "core.pipeline.stage_order_violation");
+        assert!(error.to_string().contains("Filter stage (order 3)"));
+    }
+
+    #[test]
+    fn test_build_rejects_unsatisfied_schema_input() {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 1))
+            .unwrap();
+        pipeline
+            .add_stage(Box::new(SchemaStage {
+                stage_type: PipelineStageType::Format,
+                schema: StageSchema::new().with_input(feed_payload()),
+                write: None,
+            }))
+            .unwrap();
+
+        let error = pipeline.build().err().unwrap();
+        assert_eq!(error.error_code(), "core.pipeline.unsatisfied_input");
+        assert!(error.to_string().contains("'feed.payload'"));
+    }
+
+    #[tokio::test]
+    async fn test_declared_outputs_satisfy_later_inputs() {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(Box::new(SchemaStage {
+                stage_type: PipelineStageType::Feed,
+                schema: StageSchema::new().with_output("payload"),
+                write: Some(feed_payload()),
+            }))
+            .unwrap();
+        pipeline
+            .add_stage(Box::new(SchemaStage {
+                stage_type: PipelineStageType::Format,
+                schema: StageSchema::new().with_input(feed_payload()),
+                write: None,
+            }))
+            .unwrap();
+
+        let pipeline = pipeline.build().unwrap();
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+        assert_eq!(run.context.get("feed.payload"), Some(&json!(true)));
+        assert_eq!(run.context.current_stage(), None);
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_write_to_foreign_namespace() {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 1))
+            .unwrap();
+        pipeline
+            .add_stage(Box::new(SchemaStage {
+                stage_type: PipelineStageType::Format,
+                schema: StageSchema::default(),
+                write: Some(feed_payload()),
+            }))
+            .unwrap();
+
+        let error = pipeline.execute(PipelineContext::new()).await.unwrap_err();
+        assert_eq!(error.error_code(), "core.pipeline.namespace_violation");
+    }
+
+    #[test]
+    fn test_equal_orders_fall_back_to_stage_type() {
+        let mut pipeline = SixFPipeline::new();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Format, 0))
+            .unwrap();
+        pipeline
+            .add_stage(RecordingStage::new(PipelineStageType::Feed, 0))
+            .unwrap();
+
+        let pipeline = pipeline.build().unwrap();
+        assert_eq!(
+            pipeline.get_stages()[0].stage_type(),
+            PipelineStageType::Feed
+        );
+    }
+}
@@ -1,3 +1,6 @@
 // SPDX-FileCopyrightText: 2025 Husamettin ARABACI
 // SPDX-License-Identifier: MIT
 pub mod contracts;
+pub mod entities;
+pub mod services;
+pub mod value_objects;
@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Clocks (Core Module)
+//!
+//! This module provides the bundled [`Clock`] implementations.
+//!
+//! - [`SystemClock`] reads the system time and sleeps on the Tokio timer. It is
+//!   the default everywhere a clock can be injected.
+//! - [`ManualClock`] only moves when told to. Sleepers wake when
+//!   [`ManualClock::advance`] or [`ManualClock::set`] reaches their deadline, so
+//!   retries, timeouts and TTLs can be tested without real waiting.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use chrono::{DateTime, Utc};
+//! use hexafn_core::{Clock, ManualClock};
+//! use std::time::Duration;
+//!
+//! let clock = ManualClock::default();
+//! assert_eq!(clock.now(), DateTime::<Utc>::UNIX_EPOCH);
+//! clock.advance(Duration::from_millis(1500));
+//! assert_eq!(clock.now().timestamp_millis(), 1500);
+//! ```
+
+use crate::domain::contracts::Clock;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+static SYSTEM_CLOCK: Lazy<Arc<dyn Clock>> = Lazy::new(|| Arc::new(SystemClock));
+
+/// Clock backed by the system time and the Tokio timer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// A shared handle to the system clock, used as the default clock.
+    pub fn shared() -> Arc<dyn Clock> {
+        Arc::clone(&SYSTEM_CLOCK)
+    }
+}
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+#[derive(Debug)]
+struct ManualState {
+    now: DateTime<Utc>,
+    sleepers: Vec<(DateTime<Utc>, oneshot::Sender<()>)>,
+}
+
+/// Clock that only advances when told to.
+///
+/// Clones share the same time and sleepers.
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    state: Arc<Mutex<ManualState>>,
+}
+
+impl ManualClock {
+    /// Create a clock reading `start`.
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ManualState {
+                now: start,
+                sleepers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Move the clock forward by `duration`, waking every sleeper whose deadline
+    /// has been reached.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.lock();
+        let now = add(state.now, duration);
+        Self::move_to(&mut state, now);
+    }
+
+    /// Set the clock to `now`, waking every sleeper whose deadline has been
+    /// reached. Setting an earlier time wakes nobody.
+    pub fn set(&self, now: DateTime<Utc>) {
+        Self::move_to(&mut self.lock(), now);
+    }
+
+    /// Number of tasks currently waiting in [`Clock::sleep`].
+    ///
+    /// Tests use this to wait until the code under test is parked before
+    /// advancing time.
+    pub fn sleeper_count(&self) -> usize {
+        let mut state = self.lock();
+        state.sleepers.retain(|(_, waker)| !waker.is_closed());
+        state.sleepers.len()
+    }
+
+    fn move_to(state: &mut ManualState, now: DateTime<Utc>) {
+        state.now = now;
+        let (due, waiting) = std::mem::take(&mut state.sleepers)
+            .into_iter()
+            .partition(|(wake_at, _)| *wake_at <= now);
+        state.sleepers = waiting;
+        for (_, waker) in due {
+            let _ = waker.send(());
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ManualState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for ManualClock {
+    /// A clock reading the Unix epoch.
+    fn default() -> Self {
+        Self::new(DateTime::<Utc>::UNIX_EPOCH)
+    }
+}
+
+#[async_trait]
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.lock().now
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        let woken = {
+            let mut state = self.lock();
+            let wake_at = add(state.now, duration);
+            if wake_at <= state.now {
+                return;
+            }
+            let (waker, woken) = oneshot::channel();
+            state.sleepers.push((wake_at, waker));
+            woken
+        };
+        let _ = woken.await;
+    }
+}
+
+/// `time + duration`, saturating at the latest representable time.
+fn add(time: DateTime<Utc>, duration: Duration) -> DateTime<Utc> {
+    chrono::Duration::from_std(duration)
+        .ok()
+        .and_then(|duration| time.checked_add_signed(duration))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn parked(clock: &ManualClock, count: usize) {
+        while clock.sleeper_count() < count {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sleepers_wake_in_deadline_order() {
+        let clock = ManualClock::default();
+        let short = tokio::spawn({
+            let clock = clock.clone();
+            async move { clock.sleep(Duration::from_secs(1)).await }
+        });
+        let long = tokio::spawn({
+            let clock = clock.clone();
+            async move { clock.sleep(Duration::from_secs(5)).await }
+        });
+        parked(&clock, 2).await;
+
+        clock.advance(Duration::from_secs(2));
+        short.await.unwrap();
+        assert_eq!(clock.sleeper_count(), 1);
+        assert!(!long.is_finished());
+
+        clock.advance(Duration::from_secs(3));
+        long.await.unwrap();
+        assert_eq!(clock.sleeper_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_zero_sleep_and_set() {
+        let clock = ManualClock::default();
+        clock.sleep(Duration::ZERO).await;
+
+        let target = DateTime::<Utc>::UNIX_EPOCH + chrono::Duration::hours(1);
+        let sleeper = tokio::spawn({
+            let clock = clock.clone();
+            async move { clock.sleep(Duration::from_secs(3600)).await }
+        });
+        parked(&clock, 1).await;
+        clock.set(target);
+        sleeper.await.unwrap();
+        assert_eq!(clock.now(), target);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_sleepers_are_not_counted() {
+        let clock = ManualClock::default();
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(10),
+            clock.sleep(Duration::from_secs(1)),
+        )
+        .await;
+        assert!(cancelled.is_err());
+        assert_eq!(clock.sleeper_count(), 0);
+    }
+
+    #[test]
+    fn test_system_clock_tracks_utc() {
+        let before = Utc::now();
+        let now = SystemClock::shared().now();
+        assert!(now >= before && now <= Utc::now());
+    }
+}
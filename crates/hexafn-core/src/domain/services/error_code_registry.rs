@@ -0,0 +1,300 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # ErrorCodeRegistry (Core Module)
+//!
+//! This module provides [`ErrorCodeRegistry`], a catalogue of the error codes a
+//! component may raise. Each entry records the code's expected kind, severity and
+//! a short description, so codes can be documented, validated at startup, and
+//! checked against the errors actually produced.
+//!
+//! Codes must follow the `<domain>.<module>.<reason>` convention (see
+//! [`ErrorCode`]) and their domain must be one of the hexaFn modules, unless
+//! another domain has been allowed explicitly.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{ErrorCodeRegistry, HexaErrorKind, HexaErrorSeverity, PipelineError, PipelineStageType};
+//!
+//! let mut registry = ErrorCodeRegistry::new();
+//! registry
+//!     .register(
+//!         "core.pipeline.empty",
+//!         HexaErrorKind::Validation,
+//!         HexaErrorSeverity::High,
+//!         "Pipeline has no stages",
+//!     )
+//!     .unwrap();
+//!
+//! assert!(registry.validate_error(&PipelineError::EmptyPipeline).is_ok());
+//! let missing = PipelineError::MissingStage { stage_type: PipelineStageType::Feed };
+//! assert!(registry.validate_error(&missing).is_err());
+//! assert!(registry
+//!     .register("billing.invoice.late", HexaErrorKind::Validation, HexaErrorSeverity::Low, "")
+//!     .is_err());
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use crate::domain::value_objects::{ErrorCode, ErrorCodeError};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Registered metadata for a single error code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorCodeSpec {
+    /// The validated code
+    pub code: ErrorCode,
+    /// Kind errors with this code are expected to report
+    pub kind: HexaErrorKind,
+    /// Default severity for this code
+    pub severity: HexaErrorSeverity,
+    /// Short human-readable description
+    pub description: String,
+}
+
+/// Catalogue of known error codes with format and domain validation.
+#[derive(Debug, Clone)]
+pub struct ErrorCodeRegistry {
+    domains: BTreeSet<String>,
+    codes: BTreeMap<String, ErrorCodeSpec>,
+}
+
+impl ErrorCodeRegistry {
+    /// Domains accepted by a new registry: the hexaFn modules.
+    pub const DEFAULT_DOMAINS: [&'static str; 7] = [
+        "core", "trigger", "function", "store", "cast", "watch", "bridge",
+    ];
+
+    /// Create an empty registry accepting [`ErrorCodeRegistry::DEFAULT_DOMAINS`].
+    pub fn new() -> Self {
+        Self {
+            domains: Self::DEFAULT_DOMAINS
+                .iter()
+                .map(|domain| domain.to_string())
+                .collect(),
+            codes: BTreeMap::new(),
+        }
+    }
+
+    /// Accept codes from an additional domain, e.g. a plugin's namespace.
+    pub fn allow_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domains.insert(domain.into());
+        self
+    }
+
+    /// Register a code.
+    ///
+    /// # Errors
+    ///
+    /// - [`ErrorCodeError::InvalidFormat`] if the code is malformed
+    /// - [`ErrorCodeError::UnknownDomain`] if its domain is not accepted
+    /// - [`ErrorCodeError::Duplicate`] if it is already registered
+    pub fn register(
+        &mut self,
+        code: &str,
+        kind: HexaErrorKind,
+        severity: HexaErrorSeverity,
+        description: impl Into<String>,
+    ) -> Result<&ErrorCodeSpec, ErrorCodeError> {
+        let parsed = ErrorCode::parse(code)?;
+        if !self.domains.contains(parsed.domain()) {
+            return Err(ErrorCodeError::UnknownDomain {
+                code: code.to_string(),
+                domain: parsed.domain().to_string(),
+            });
+        }
+        if self.codes.contains_key(code) {
+            return Err(ErrorCodeError::Duplicate {
+                code: code.to_string(),
+            });
+        }
+        let spec = ErrorCodeSpec {
+            code: parsed,
+            kind,
+            severity,
+            description: description.into(),
+        };
+        Ok(self.codes.entry(code.to_string()).or_insert(spec))
+    }
+
+    /// Look up a registered code.
+    pub fn get(&self, code: &str) -> Option<&ErrorCodeSpec> {
+        self.codes.get(code)
+    }
+
+    /// Whether `code` is registered.
+    pub fn contains(&self, code: &str) -> bool {
+        self.codes.contains_key(code)
+    }
+
+    /// Iterate over registered codes in lexical order.
+    pub fn iter(&self) -> impl Iterator<Item = &ErrorCodeSpec> {
+        self.codes.values()
+    }
+
+    /// Number of registered codes.
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    /// Whether no codes are registered.
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// Check that an error's code is registered and its kind matches.
+    ///
+    /// # Errors
+    ///
+    /// - [`ErrorCodeError::Unregistered`] if the code is unknown
+    /// - [`ErrorCodeError::KindMismatch`] if the error reports a different kind
+    pub fn validate_error(&self, error: &dyn HexaError) -> Result<(), ErrorCodeError> {
+        let code = error.error_code();
+        let spec = self
+            .codes
+            .get(code)
+            .ok_or_else(|| ErrorCodeError::Unregistered {
+                code: code.to_string(),
+            })?;
+        if spec.kind != error.error_kind() {
+            return Err(ErrorCodeError::KindMismatch {
+                code: code.to_string(),
+                expected: spec.kind,
+                actual: error.error_kind(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for ErrorCodeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::PipelineStageType;
+    use crate::domain::value_objects::{GenericHexaError, PipelineError};
+
+    #[test]
+    fn test_register_and_lookup() {
+        let mut registry = ErrorCodeRegistry::new();
+        let spec = registry
+            .register(
+                "store.kv.write_failed",
+                HexaErrorKind::External,
+                HexaErrorSeverity::High,
+                "Backend write failed",
+            )
+            .unwrap();
+        assert_eq!(spec.code.module(), "kv");
+
+        let spec = registry.get("store.kv.write_failed").unwrap();
+        assert_eq!(spec.kind, HexaErrorKind::External);
+        assert_eq!(spec.description, "Backend write failed");
+        assert_eq!(registry.len(), 1);
+        assert!(registry.get("store.kv.read_failed").is_none());
+    }
+
+    #[test]
+    fn test_rejects_duplicates_and_unknown_domains() {
+        let mut registry = ErrorCodeRegistry::new();
+        registry
+            .register(
+                "core.pipeline.empty",
+                HexaErrorKind::Validation,
+                HexaErrorSeverity::High,
+                "",
+            )
+            .unwrap();
+
+        let duplicate = registry
+            .register(
+                "core.pipeline.empty",
+                HexaErrorKind::Validation,
+                HexaErrorSeverity::High,
+                "",
+            )
+            .unwrap_err();
+        assert_eq!(duplicate.error_code(), "core.error_code.duplicate");
+
+        let unknown = registry
+            .register(
+                "billing.invoice.late",
+                HexaErrorKind::Validation,
+                HexaErrorSeverity::Low,
+                "",
+            )
+            .unwrap_err();
+        assert_eq!(unknown.error_code(), "core.error_code.unknown_domain");
+
+        let malformed = registry
+            .register(
+                "TEST_ERROR",
+                HexaErrorKind::Unknown,
+                HexaErrorSeverity::Low,
+                "",
+            )
+            .unwrap_err();
+        assert_eq!(malformed.error_code(), "core.error_code.invalid_format");
+    }
+
+    #[test]
+    fn test_allow_domain() {
+        let mut registry = ErrorCodeRegistry::new().allow_domain("billing");
+        assert!(registry
+            .register(
+                "billing.invoice.late",
+                HexaErrorKind::Validation,
+                HexaErrorSeverity::Low,
+                "",
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_error() {
+        let mut registry = ErrorCodeRegistry::new();
+        registry
+            .register(
+                "core.pipeline.empty",
+                HexaErrorKind::Validation,
+                HexaErrorSeverity::High,
+                "",
+            )
+            .unwrap();
+        registry
+            .register(
+                "core.io.failure",
+                HexaErrorKind::External,
+                HexaErrorSeverity::Medium,
+                "",
+            )
+            .unwrap();
+
+        assert!(registry
+            .validate_error(&PipelineError::EmptyPipeline)
+            .is_ok());
+        assert!(matches!(
+            registry.validate_error(&PipelineError::MissingStage {
+                stage_type: PipelineStageType::Feed
+            }),
+            Err(ErrorCodeError::Unregistered { .. })
+        ));
+
+        let wrong_kind = GenericHexaError::builder("core.io.failure", "failure")
+            .kind(HexaErrorKind::Internal)
+            .build();
+        assert!(matches!(
+            registry.validate_error(&wrong_kind),
+            Err(ErrorCodeError::KindMismatch {
+                expected: HexaErrorKind::External,
+                actual: HexaErrorKind::Internal,
+                ..
+            })
+        ));
+    }
+}
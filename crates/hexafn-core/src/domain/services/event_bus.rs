@@ -0,0 +1,817 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # EventBus (Core Module)
+//!
+//! This module provides [`EventBus`], the in-process async bus through which hexaFn
+//! modules (Bridge, Trigger, Run, Cast, Watch) exchange [`Event`]s and
+//! [`DomainEvent`]s without depending on each other directly.
+//!
+//! ## Delivery Model
+//!
+//! - Handlers subscribe with an [`EventPattern`] matched against `event_type()`,
+//!   either receiving `Arc<dyn Event>` or, for typed subscriptions, `Arc<E>`.
+//! - The bus is split into shards, each with a bounded queue and one worker task.
+//!   Domain events are routed by `aggregate_id()`, so every event of an aggregate
+//!   is handled in publish order. Other events are spread by `event_id()`.
+//! - [`EventBus::publish`] waits while the target queue is full (backpressure);
+//!   [`EventBus::try_publish`] fails fast with [`EventBusError::QueueFull`].
+//! - A handler error or panic is logged and does not stop delivery to the other
+//!   handlers.
+//! - A bus built with [`EventBus::with_upcasters`] upcasts events older than the
+//!   latest schema version of their type before delivery. Such events reach
+//!   handlers as an [`EventEnvelope`]. A typed subscription for any other type
+//!   could not receive them, so subscribing one with a pattern that matches an
+//!   event type with upcasters fails with
+//!   [`EventBusError::TypedUpcastSubscription`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{Event, EventBus, EventBusConfig, EventId, HexaError};
+//! use chrono::{DateTime, Utc};
+//! use serde_json::json;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::sync::Arc;
+//!
+//! struct UserCreated {
+//!     id: EventId,
+//! }
+//!
+//! impl Event for UserCreated {
+//!     fn event_type(&self) -> &'static str { "user.created" }
+//!     fn event_id(&self) -> &EventId { &self.id }
+//!     fn timestamp(&self) -> DateTime<Utc> { Utc::now() }
+//!     fn payload(&self) -> serde_json::Value { json!({}) }
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! let bus = EventBus::new(EventBusConfig::default());
+//! let seen = Arc::new(AtomicUsize::new(0));
+//! let counter = Arc::clone(&seen);
+//! bus.subscribe("user.*", move |_event: Arc<dyn Event>| {
+//!     let counter = Arc::clone(&counter);
+//!     async move {
+//!         counter.fetch_add(1, Ordering::SeqCst);
+//!         Ok::<_, Box<dyn HexaError>>(())
+//!     }
+//! })
+//! .unwrap();
+//!
+//! bus.publish(UserCreated { id: EventId::new() }).await.unwrap();
+//! bus.shutdown().await;
+//! assert_eq!(seen.load(Ordering::SeqCst), 1);
+//! # });
+//! ```
+
+use crate::domain::contracts::{DomainEvent, Event, EventHandler, HexaError};
+use crate::domain::services::UpcasterRegistry;
+use crate::domain::value_objects::{EventBusError, EventEnvelope, EventPattern};
+use std::any::{Any, TypeId};
+use std::collections::hash_map::DefaultHasher;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+type HandlerFuture = Pin<Box<dyn Future<Output = Result<(), Box<dyn HexaError>>> + Send>>;
+type ErasedHandler = Arc<dyn Fn(&Delivery) -> Option<HandlerFuture> + Send + Sync>;
+type Subscriptions = Arc<RwLock<Vec<Subscription>>>;
+
+/// Sizing of an [`EventBus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventBusConfig {
+    shards: usize,
+    queue_capacity: usize,
+}
+
+impl EventBusConfig {
+    /// Default number of shards.
+    pub const DEFAULT_SHARDS: usize = 4;
+    /// Default queue capacity per shard.
+    pub const DEFAULT_QUEUE_CAPACITY: usize = 1024;
+
+    /// Set the number of shards (at least one).
+    pub fn with_shards(mut self, shards: usize) -> Self {
+        self.shards = shards.max(1);
+        self
+    }
+
+    /// Set the queue capacity of each shard (at least one).
+    pub fn with_queue_capacity(mut self, capacity: usize) -> Self {
+        self.queue_capacity = capacity.max(1);
+        self
+    }
+
+    /// Number of shards.
+    pub fn shards(&self) -> usize {
+        self.shards
+    }
+
+    /// Queue capacity of each shard.
+    pub fn queue_capacity(&self) -> usize {
+        self.queue_capacity
+    }
+}
+
+impl Default for EventBusConfig {
+    fn default() -> Self {
+        Self {
+            shards: Self::DEFAULT_SHARDS,
+            queue_capacity: Self::DEFAULT_QUEUE_CAPACITY,
+        }
+    }
+}
+
+/// Identifier returned by [`EventBus::subscribe`], used to unsubscribe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubscriptionId(u64);
+
+/// A published event, viewed both as a trait object and as `Any` for typed handlers.
+#[derive(Clone)]
+struct Delivery {
+    event: Arc<dyn Event>,
+    any: Arc<dyn Any + Send + Sync>,
+}
+
+impl Delivery {
+    fn new<E: Event + 'static>(event: E) -> Self {
+        let event = Arc::new(event);
+        Self {
+            any: Arc::clone(&event) as Arc<dyn Any + Send + Sync>,
+            event,
+        }
+    }
+}
+
+struct Subscription {
+    id: SubscriptionId,
+    pattern: EventPattern,
+    handler: ErasedHandler,
+}
+
+struct Inner {
+    subscriptions: Subscriptions,
+    senders: RwLock<Vec<mpsc::Sender<Delivery>>>,
+    workers: Mutex<Vec<JoinHandle<()>>>,
+    next_id: AtomicU64,
+    upcasters: Arc<UpcasterRegistry>,
+}
+
+/// In-process async event bus with per-aggregate ordering.
+///
+/// Cloning is cheap; clones share subscriptions and queues.
+#[derive(Clone)]
+pub struct EventBus {
+    inner: Arc<Inner>,
+}
+
+impl EventBus {
+    /// Create a bus and spawn one worker task per shard.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a Tokio runtime.
+    pub fn new(config: EventBusConfig) -> Self {
+        Self::with_upcasters(config, Arc::default())
+    }
+
+    /// Create a bus that upcasts published events with `upcasters`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a Tokio runtime.
+    pub fn with_upcasters(config: EventBusConfig, upcasters: Arc<UpcasterRegistry>) -> Self {
+        let subscriptions: Subscriptions = Arc::default();
+        let (senders, workers) = (0..config.shards)
+            .map(|_| {
+                let (sender, receiver) = mpsc::channel(config.queue_capacity);
+                let worker = tokio::spawn(Self::run_shard(receiver, Arc::clone(&subscriptions)));
+                (sender, worker)
+            })
+            .unzip();
+        Self {
+            inner: Arc::new(Inner {
+                subscriptions,
+                senders: RwLock::new(senders),
+                workers: Mutex::new(workers),
+                next_id: AtomicU64::new(1),
+                upcasters,
+            }),
+        }
+    }
+
+    /// Subscribe a handler to every event whose type matches `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// [`EventBusError::InvalidPattern`] if `pattern` cannot be parsed.
+    pub fn subscribe<H>(&self, pattern: &str, handler: H) -> Result<SubscriptionId, EventBusError>
+    where
+        H: EventHandler + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.add_subscription(
+            EventPattern::parse(pattern)?,
+            Arc::new(move |delivery: &Delivery| {
+                let handler = Arc::clone(&handler);
+                let event = Arc::clone(&delivery.event);
+                Some(Box::pin(async move { handler.handle(event).await }) as HandlerFuture)
+            }),
+        )
+    }
+
+    /// Subscribe a handler to events of concrete type `E` whose type matches
+    /// `pattern`. Events of other types are not delivered to it.
+    ///
+    /// Upcast events are delivered as [`EventEnvelope`]s, so only
+    /// `E = EventEnvelope` may subscribe to event types that have upcasters.
+    ///
+    /// # Errors
+    ///
+    /// - [`EventBusError::InvalidPattern`] if `pattern` cannot be parsed
+    /// - [`EventBusError::TypedUpcastSubscription`] if `pattern` matches an
+    ///   event type that has upcasters and `E` is not [`EventEnvelope`]
+    pub fn subscribe_typed<E, H>(
+        &self,
+        pattern: &str,
+        handler: H,
+    ) -> Result<SubscriptionId, EventBusError>
+    where
+        E: Event + 'static,
+        H: EventHandler<E> + 'static,
+    {
+        let pattern = EventPattern::parse(pattern)?;
+        if TypeId::of::<E>() != TypeId::of::<EventEnvelope>() {
+            let upcast = self
+                .inner
+                .upcasters
+                .event_types()
+                .find(|t| pattern.matches(t));
+            if let Some(event_type) = upcast {
+                return Err(EventBusError::TypedUpcastSubscription {
+                    event_type: event_type.to_string(),
+                });
+            }
+        }
+        let handler = Arc::new(handler);
+        self.add_subscription(
+            pattern,
+            Arc::new(move |delivery: &Delivery| {
+                let event = Arc::clone(&delivery.any).downcast::<E>().ok()?;
+                let handler = Arc::clone(&handler);
+                Some(Box::pin(async move { handler.handle(event).await }) as HandlerFuture)
+            }),
+        )
+    }
+
+    fn add_subscription(
+        &self,
+        pattern: EventPattern,
+        handler: ErasedHandler,
+    ) -> Result<SubscriptionId, EventBusError> {
+        let id = SubscriptionId(self.inner.next_id.fetch_add(1, Ordering::Relaxed));
+        self.inner
+            .subscriptions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Subscription {
+                id,
+                pattern,
+                handler,
+            });
+        Ok(id)
+    }
+
+    /// Remove a subscription. Returns `false` if it was not found.
+    pub fn unsubscribe(&self, id: SubscriptionId) -> bool {
+        let mut subscriptions = self
+            .inner
+            .subscriptions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let before = subscriptions.len();
+        subscriptions.retain(|subscription| subscription.id != id);
+        subscriptions.len() != before
+    }
+
+    /// Number of active subscriptions.
+    pub fn subscription_count(&self) -> usize {
+        self.inner
+            .subscriptions
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Publish an event, waiting while the target queue is full.
+    ///
+    /// # Errors
+    ///
+    /// - [`EventBusError::UpcastFailed`] if the event cannot be upcast
+    /// - [`EventBusError::Closed`] if the bus has been shut down
+    pub async fn publish<E: Event + 'static>(&self, event: E) -> Result<(), EventBusError> {
+        let key = event.event_id().to_string();
+        let delivery = self.delivery(event, EventEnvelope::from_event)?;
+        self.send(&key, delivery).await
+    }
+
+    /// Publish a domain event, preserving order with other events of the same
+    /// aggregate. Waits while the target queue is full.
+    ///
+    /// # Errors
+    ///
+    /// - [`EventBusError::UpcastFailed`] if the event cannot be upcast
+    /// - [`EventBusError::Closed`] if the bus has been shut down
+    pub async fn publish_domain<E: DomainEvent + 'static>(
+        &self,
+        event: E,
+    ) -> Result<(), EventBusError> {
+        let key = event.aggregate_id().to_string();
+        let delivery = self.delivery(event, EventEnvelope::from_domain_event)?;
+        self.send(&key, delivery).await
+    }
+
+    /// Publish an event without waiting.
+    ///
+    /// # Errors
+    ///
+    /// - [`EventBusError::UpcastFailed`] if the event cannot be upcast
+    /// - [`EventBusError::QueueFull`] if the target queue is full
+    /// - [`EventBusError::Closed`] if the bus has been shut down
+    pub fn try_publish<E: Event + 'static>(&self, event: E) -> Result<(), EventBusError> {
+        let key = event.event_id().to_string();
+        let delivery = self.delivery(event, EventEnvelope::from_event)?;
+        self.try_send(&key, delivery)
+    }
+
+    /// Publish a domain event without waiting, preserving per-aggregate order.
+    ///
+    /// # Errors
+    ///
+    /// - [`EventBusError::UpcastFailed`] if the event cannot be upcast
+    /// - [`EventBusError::QueueFull`] if the target queue is full
+    /// - [`EventBusError::Closed`] if the bus has been shut down
+    pub fn try_publish_domain<E: DomainEvent + 'static>(
+        &self,
+        event: E,
+    ) -> Result<(), EventBusError> {
+        let key = event.aggregate_id().to_string();
+        let delivery = self.delivery(event, EventEnvelope::from_domain_event)?;
+        self.try_send(&key, delivery)
+    }
+
+    /// Stop accepting events, then wait until every queued event is handled.
+    pub async fn shutdown(&self) {
+        self.inner
+            .senders
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        let workers = std::mem::take(
+            &mut *self
+                .inner
+                .workers
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        for worker in workers {
+            let _ = worker.await;
+        }
+    }
+
+    /// Wrap `event` for delivery, upcasting it first if it is outdated. Outdated
+    /// events other than envelopes are captured with `capture`.
+    fn delivery<E: Event + 'static>(
+        &self,
+        event: E,
+        capture: fn(&E) -> EventEnvelope,
+    ) -> Result<Delivery, EventBusError> {
+        if !self.inner.upcasters.needs_upcast(&event) {
+            return Ok(Delivery::new(event));
+        }
+        let envelope = match (&event as &dyn Any).downcast_ref::<EventEnvelope>() {
+            Some(envelope) => envelope.clone(),
+            None => capture(&event),
+        };
+        self.inner
+            .upcasters
+            .upcast(envelope)
+            .map(Delivery::new)
+            .map_err(|error| EventBusError::UpcastFailed {
+                event_type: event.event_type().to_string(),
+                reason: error.to_string(),
+            })
+    }
+
+    fn sender_for(&self, key: &str) -> Result<(usize, mpsc::Sender<Delivery>), EventBusError> {
+        let senders = self
+            .inner
+            .senders
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        if senders.is_empty() {
+            return Err(EventBusError::Closed);
+        }
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let shard = (hasher.finish() % senders.len() as u64) as usize;
+        Ok((shard, senders[shard].clone()))
+    }
+
+    async fn send(&self, key: &str, delivery: Delivery) -> Result<(), EventBusError> {
+        let (_, sender) = self.sender_for(key)?;
+        sender
+            .send(delivery)
+            .await
+            .map_err(|_| EventBusError::Closed)
+    }
+
+    fn try_send(&self, key: &str, delivery: Delivery) -> Result<(), EventBusError> {
+        let (shard, sender) = self.sender_for(key)?;
+        sender.try_send(delivery).map_err(|error| match error {
+            mpsc::error::TrySendError::Full(_) => EventBusError::QueueFull { shard },
+            mpsc::error::TrySendError::Closed(_) => EventBusError::Closed,
+        })
+    }
+
+    async fn run_shard(mut receiver: mpsc::Receiver<Delivery>, subscriptions: Subscriptions) {
+        while let Some(delivery) = receiver.recv().await {
+            let event_type = delivery.event.event_type();
+            let handlers: Vec<(SubscriptionId, ErasedHandler)> = subscriptions
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .filter(|subscription| subscription.pattern.matches(event_type))
+                .map(|subscription| (subscription.id, Arc::clone(&subscription.handler)))
+                .collect();
+
+            for (id, handler) in handlers {
+                let Some(future) = handler(&delivery) else {
+                    continue;
+                };
+                match tokio::spawn(future).await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(error)) => tracing::warn!(
+                        event_type,
+                        event_id = %delivery.event.event_id(),
+                        subscription = id.0,
+                        error_code = error.error_code(),
+                        "event handler failed: {}",
+                        error
+                    ),
+                    Err(join_error) => tracing::error!(
+                        event_type,
+                        event_id = %delivery.event.event_id(),
+                        subscription = id.0,
+                        "event handler panicked: {}",
+                        join_error
+                    ),
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus")
+            .field("subscriptions", &self.subscription_count())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::EventId;
+    use crate::domain::value_objects::GenericHexaError;
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+    use std::time::Duration;
+    use tokio::sync::{oneshot, Notify};
+
+    struct Ping {
+        id: EventId,
+        event_type: &'static str,
+    }
+
+    impl Ping {
+        fn new(event_type: &'static str) -> Self {
+            Self {
+                id: EventId::new(),
+                event_type,
+            }
+        }
+    }
+
+    impl Event for Ping {
+        fn event_type(&self) -> &'static str {
+            self.event_type
+        }
+        fn event_id(&self) -> &EventId {
+            &self.id
+        }
+        fn timestamp(&self) -> DateTime<Utc> {
+            Utc::now()
+        }
+        fn payload(&self) -> serde_json::Value {
+            json!({})
+        }
+    }
+
+    struct Moved {
+        id: EventId,
+        aggregate_id: String,
+        seq: u64,
+    }
+
+    impl Event for Moved {
+        fn event_type(&self) -> &'static str {
+            "item.moved"
+        }
+        fn event_id(&self) -> &EventId {
+            &self.id
+        }
+        fn timestamp(&self) -> DateTime<Utc> {
+            Utc::now()
+        }
+        fn payload(&self) -> serde_json::Value {
+            json!({ "seq": self.seq })
+        }
+    }
+
+    impl DomainEvent for Moved {
+        fn aggregate_id(&self) -> &str {
+            &self.aggregate_id
+        }
+        fn sequence_number(&self) -> u64 {
+            self.seq
+        }
+        fn occurred_at(&self) -> DateTime<Utc> {
+            Utc::now()
+        }
+        fn correlation_id(&self) -> &str {
+            "corr"
+        }
+    }
+
+    type Seen = Arc<Mutex<Vec<String>>>;
+
+    fn recorder() -> (Seen, impl Fn(Arc<dyn Event>) -> HandlerFuture + Send + Sync) {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let handler = move |event: Arc<dyn Event>| -> HandlerFuture {
+            let sink = Arc::clone(&sink);
+            Box::pin(async move {
+                sink.lock().unwrap().push(event.event_type().to_string());
+                Ok(())
+            })
+        };
+        (seen, handler)
+    }
+
+    #[tokio::test]
+    async fn test_pattern_subscriptions() {
+        let bus = EventBus::new(EventBusConfig::default());
+        let (users, user_handler) = recorder();
+        let (all, all_handler) = recorder();
+        bus.subscribe("user.*", user_handler).unwrap();
+        bus.subscribe("**", all_handler).unwrap();
+
+        for event_type in ["user.created", "order.placed", "user.profile.updated"] {
+            bus.publish(Ping::new(event_type)).await.unwrap();
+        }
+        bus.shutdown().await;
+
+        assert_eq!(*users.lock().unwrap(), vec!["user.created"]);
+        let mut all = all.lock().unwrap().clone();
+        all.sort();
+        assert_eq!(
+            all,
+            vec!["order.placed", "user.created", "user.profile.updated"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_typed_subscription_only_receives_its_type() {
+        let bus = EventBus::new(EventBusConfig::default());
+        let seqs = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seqs);
+        bus.subscribe_typed::<Moved, _>("**", move |event: Arc<Moved>| {
+            let sink = Arc::clone(&sink);
+            async move {
+                sink.lock().unwrap().push(event.seq);
+                Ok::<_, Box<dyn HexaError>>(())
+            }
+        })
+        .unwrap();
+
+        bus.publish(Ping::new("item.moved")).await.unwrap();
+        bus.publish_domain(Moved {
+            id: EventId::new(),
+            aggregate_id: "a".to_string(),
+            seq: 7,
+        })
+        .await
+        .unwrap();
+        bus.shutdown().await;
+
+        assert_eq!(*seqs.lock().unwrap(), vec![7]);
+    }
+
+    #[tokio::test]
+    async fn test_domain_events_are_ordered_per_aggregate() {
+        let bus = EventBus::new(EventBusConfig::default().with_shards(4));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        bus.subscribe_typed::<Moved, _>("item.moved", move |event: Arc<Moved>| {
+            let sink = Arc::clone(&sink);
+            async move {
+                // Uneven handler latency must not reorder an aggregate's events.
+                tokio::time::sleep(Duration::from_micros(event.seq % 3 * 200)).await;
+                sink.lock()
+                    .unwrap()
+                    .push((event.aggregate_id.clone(), event.seq));
+                Ok::<_, Box<dyn HexaError>>(())
+            }
+        })
+        .unwrap();
+
+        for seq in 0..30 {
+            for aggregate in ["a", "b", "c"] {
+                bus.publish_domain(Moved {
+                    id: EventId::new(),
+                    aggregate_id: aggregate.to_string(),
+                    seq,
+                })
+                .await
+                .unwrap();
+            }
+        }
+        bus.shutdown().await;
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 90);
+        for aggregate in ["a", "b", "c"] {
+            let seqs: Vec<u64> = seen
+                .iter()
+                .filter(|(id, _)| id == aggregate)
+                .map(|(_, seq)| *seq)
+                .collect();
+            assert_eq!(seqs, (0..30).collect::<Vec<_>>());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_try_publish_reports_full_queue() {
+        let bus = EventBus::new(
+            EventBusConfig::default()
+                .with_shards(1)
+                .with_queue_capacity(1),
+        );
+        let release = Arc::new(Notify::new());
+        let (started_tx, started_rx) = oneshot::channel();
+        let started = Arc::new(Mutex::new(Some(started_tx)));
+        let gate = Arc::clone(&release);
+        bus.subscribe("**", move |_event: Arc<dyn Event>| {
+            let gate = Arc::clone(&gate);
+            let started = started.lock().unwrap().take();
+            async move {
+                if let Some(started) = started {
+                    let _ = started.send(());
+                    gate.notified().await;
+                }
+                Ok::<_, Box<dyn HexaError>>(())
+            }
+        })
+        .unwrap();
+
+        bus.publish(Ping::new("a")).await.unwrap();
+        started_rx.await.unwrap();
+        bus.try_publish(Ping::new("b")).unwrap();
+        assert_eq!(
+            bus.try_publish(Ping::new("c")).unwrap_err(),
+            EventBusError::QueueFull { shard: 0 }
+        );
+
+        release.notify_one();
+        bus.shutdown().await;
+        assert_eq!(
+            bus.publish(Ping::new("d")).await.unwrap_err(),
+            EventBusError::Closed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failing_handler_does_not_block_others() {
+        let bus = EventBus::new(EventBusConfig::default());
+        bus.subscribe("**", |_event: Arc<dyn Event>| async {
+            Err::<(), _>(
+                Box::new(GenericHexaError::new("core.test.failed", "failed")) as Box<dyn HexaError>,
+            )
+        })
+        .unwrap();
+        bus.subscribe("**", |_event: Arc<dyn Event>| async {
+            panic!("handler panic");
+            #[allow(unreachable_code)]
+            Ok::<_, Box<dyn HexaError>>(())
+        })
+        .unwrap();
+        let (seen, handler) = recorder();
+        bus.subscribe("**", handler).unwrap();
+
+        bus.publish(Ping::new("x")).await.unwrap();
+        bus.publish(Ping::new("y")).await.unwrap();
+        bus.shutdown().await;
+
+        assert_eq!(seen.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_and_invalid_pattern() {
+        let bus = EventBus::new(EventBusConfig::default());
+        let (seen, handler) = recorder();
+        let id = bus.subscribe("x", handler).unwrap();
+        assert_eq!(bus.subscription_count(), 1);
+        assert!(bus.unsubscribe(id));
+        assert!(!bus.unsubscribe(id));
+
+        bus.publish(Ping::new("x")).await.unwrap();
+        bus.shutdown().await;
+        assert!(seen.lock().unwrap().is_empty());
+
+        let (_, handler) = recorder();
+        assert!(matches!(
+            bus.subscribe("a..b", handler),
+            Err(EventBusError::InvalidPattern { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_outdated_events_are_upcast_before_delivery() {
+        let mut upcasters = UpcasterRegistry::new();
+        upcasters
+            .register("item.moved", 1, |payload: serde_json::Value| {
+                Ok::<_, Box<dyn HexaError>>(json!({ "position": payload["seq"] }))
+            })
+            .unwrap();
+        upcasters
+            .register("item.broken", 1, |_payload: serde_json::Value| {
+                Err::<serde_json::Value, Box<dyn HexaError>>(Box::new(GenericHexaError::new(
+                    "test.upcast",
+                    "unreadable",
+                )))
+            })
+            .unwrap();
+        let bus = EventBus::with_upcasters(EventBusConfig::default(), Arc::new(upcasters));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        bus.subscribe_typed::<EventEnvelope, _>("item.*", move |event: Arc<EventEnvelope>| {
+            let sink = Arc::clone(&sink);
+            async move {
+                sink.lock().unwrap().push((
+                    event.aggregate_id().to_string(),
+                    event.schema_version,
+                    event.payload.clone(),
+                ));
+                Ok::<_, Box<dyn HexaError>>(())
+            }
+        })
+        .unwrap();
+        for pattern in ["item.moved", "item.*", "**"] {
+            let err = bus
+                .subscribe_typed::<Moved, _>(pattern, |_event: Arc<Moved>| async {
+                    Ok::<_, Box<dyn HexaError>>(())
+                })
+                .unwrap_err();
+            assert_eq!(err.error_code(), "core.event_bus.typed_upcast_subscription");
+        }
+        bus.subscribe_typed::<Moved, _>("order.*", |_event: Arc<Moved>| async {
+            Ok::<_, Box<dyn HexaError>>(())
+        })
+        .unwrap();
+
+        bus.publish_domain(Moved {
+            id: EventId::new(),
+            aggregate_id: "a".to_string(),
+            seq: 3,
+        })
+        .await
+        .unwrap();
+        let err = bus
+            .publish(EventEnvelope::new("item.broken", json!({})))
+            .await
+            .unwrap_err();
+        assert_eq!(err.error_code(), "core.event_bus.upcast_failed");
+        bus.shutdown().await;
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![("a".to_string(), 2, json!({ "position": 3 }))]
+        );
+    }
+}
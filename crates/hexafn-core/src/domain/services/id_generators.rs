@@ -0,0 +1,204 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Id Generators (Core Module)
+//!
+//! This module provides the bundled [`IdGenerator`] implementations.
+//!
+//! - [`RandomIdGenerator`] creates random UUIDv4 ids.
+//! - [`UuidV7IdGenerator`] creates UUIDv7 ids, ordered by creation time within
+//!   the process.
+//! - [`UlidIdGenerator`] creates ULIDs: a 48-bit millisecond timestamp followed by
+//!   80 random bits. Ids created in the same millisecond, or after the clock steps
+//!   back, are made monotonic by incrementing the previous id. The timestamp comes
+//!   from an injectable [`Clock`].
+//! - [`SeededIdGenerator`] creates UUIDv4-shaped ids from a SplitMix64 sequence,
+//!   so the same seed always yields the same ids, on every platform and version.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{IdGenerator, UlidIdGenerator};
+//!
+//! let ids = UlidIdGenerator::new();
+//! let first = ids.next_id();
+//! let second = ids.next_id();
+//! assert!(first.to_ulid() < second.to_ulid());
+//! ```
+
+use crate::domain::contracts::{Clock, EventId, IdGenerator};
+use crate::domain::services::SystemClock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use uuid::{Builder, Uuid};
+
+/// Generates random UUIDv4 ids.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomIdGenerator;
+
+impl IdGenerator for RandomIdGenerator {
+    fn next_id(&self) -> EventId {
+        EventId::new()
+    }
+}
+
+/// Generates time-ordered UUIDv7 ids.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidV7IdGenerator;
+
+impl IdGenerator for UuidV7IdGenerator {
+    fn next_id(&self) -> EventId {
+        EventId::new_v7()
+    }
+}
+
+/// Generates monotonic ULIDs.
+#[derive(Debug)]
+pub struct UlidIdGenerator {
+    clock: Arc<dyn Clock>,
+    last: Mutex<u128>,
+}
+
+impl UlidIdGenerator {
+    /// Number of random bits below the timestamp.
+    const RANDOM_BITS: u32 = 80;
+
+    /// Create a generator timestamping ids with the system clock.
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock::shared())
+    }
+
+    /// Create a generator timestamping ids with `clock`.
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            last: Mutex::new(0),
+        }
+    }
+}
+
+impl Default for UlidIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdGenerator for UlidIdGenerator {
+    fn next_id(&self) -> EventId {
+        let millis = u128::from(self.clock.now().timestamp_millis().max(0) as u64);
+        let random = rand::random::<u128>() >> (128 - Self::RANDOM_BITS);
+        let fresh = (millis << Self::RANDOM_BITS) | random;
+
+        let mut last = self.last.lock().unwrap_or_else(PoisonError::into_inner);
+        *last = if fresh > *last {
+            fresh
+        } else {
+            last.wrapping_add(1)
+        };
+        EventId(Uuid::from_u128(*last))
+    }
+}
+
+/// Generates a reproducible sequence of ids from a seed.
+///
+/// Concurrent callers each receive distinct ids, but which caller receives which
+/// id depends on scheduling; use one generator per deterministic sequence.
+#[derive(Debug)]
+pub struct SeededIdGenerator {
+    state: AtomicU64,
+}
+
+impl SeededIdGenerator {
+    /// SplitMix64 increment.
+    const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+    /// Create a generator whose sequence is determined by `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    fn mix(mut z: u64) -> u64 {
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl IdGenerator for SeededIdGenerator {
+    fn next_id(&self) -> EventId {
+        let state = self
+            .state
+            .fetch_add(Self::GAMMA.wrapping_mul(2), Ordering::Relaxed);
+        let high = Self::mix(state.wrapping_add(Self::GAMMA));
+        let low = Self::mix(state.wrapping_add(Self::GAMMA.wrapping_mul(2)));
+        let bytes = ((u128::from(high) << 64) | u128::from(low)).to_be_bytes();
+        EventId(Builder::from_random_bytes(bytes).into_uuid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::services::ManualClock;
+    use chrono::Utc;
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    #[test]
+    fn test_seeded_sequence_is_reproducible() {
+        let a = SeededIdGenerator::new(7);
+        let b = SeededIdGenerator::new(7);
+        let other = SeededIdGenerator::new(8);
+        let first: Vec<EventId> = (0..5).map(|_| a.next_id()).collect();
+        let second: Vec<EventId> = (0..5).map(|_| b.next_id()).collect();
+        assert_eq!(first, second);
+        assert_ne!(first[0], other.next_id());
+        assert_eq!(first.iter().collect::<HashSet<_>>().len(), 5);
+        assert_eq!(first[0].0.get_version_num(), 4);
+    }
+
+    #[test]
+    fn test_seeded_sequence_is_stable() {
+        // Pinned so that changes to the algorithm are caught: replays depend on it.
+        let ids = SeededIdGenerator::new(0);
+        assert_eq!(
+            ids.next_id().to_string(),
+            "e220a839-7b1d-4daf-ae78-9e6aa1b965f4"
+        );
+    }
+
+    #[test]
+    fn test_ulids_are_monotonic_and_timestamped() {
+        let ids = UlidIdGenerator::new();
+        let before = Utc::now().timestamp_millis() as u128;
+        let generated: Vec<u128> = (0..1000).map(|_| ids.next_id().0.as_u128()).collect();
+        let after = Utc::now().timestamp_millis() as u128;
+
+        assert!(generated.windows(2).all(|pair| pair[0] < pair[1]));
+        let millis = generated[0] >> UlidIdGenerator::RANDOM_BITS;
+        assert!((before..=after).contains(&millis));
+    }
+
+    #[test]
+    fn test_ulid_timestamp_follows_injected_clock() {
+        let clock = ManualClock::default();
+        let ids = UlidIdGenerator::with_clock(Arc::new(clock.clone()));
+        clock.advance(Duration::from_millis(1234));
+        let id = ids.next_id().0.as_u128();
+        assert_eq!(id >> UlidIdGenerator::RANDOM_BITS, 1234);
+    }
+
+    #[test]
+    fn test_generators_are_object_safe() {
+        let generators: Vec<Box<dyn IdGenerator>> = vec![
+            Box::new(RandomIdGenerator),
+            Box::new(UuidV7IdGenerator),
+            Box::new(UlidIdGenerator::new()),
+            Box::new(SeededIdGenerator::new(1)),
+        ];
+        let ids: HashSet<EventId> = generators.iter().map(|g| g.next_id()).collect();
+        assert_eq!(ids.len(), 4);
+    }
+}
@@ -0,0 +1,20 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod clocks;
+mod error_code_registry;
+mod event_bus;
+mod event_factory;
+mod id_generators;
+mod pipeline_stage_registry;
+mod upcaster_registry;
+mod upcasting_event_store;
+
+pub use clocks::{ManualClock, SystemClock};
+pub use error_code_registry::{ErrorCodeRegistry, ErrorCodeSpec};
+pub use event_bus::{EventBus, EventBusConfig, SubscriptionId};
+pub use event_factory::EventFactory;
+pub use id_generators::{RandomIdGenerator, SeededIdGenerator, UlidIdGenerator, UuidV7IdGenerator};
+pub use pipeline_stage_registry::PipelineStageRegistry;
+pub use upcaster_registry::UpcasterRegistry;
+pub use upcasting_event_store::UpcastingEventStore;
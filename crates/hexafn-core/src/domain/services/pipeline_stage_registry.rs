@@ -0,0 +1,327 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # PipelineStageRegistry (Core Module)
+//!
+//! This module provides [`PipelineStageRegistry`], the catalogue of configurable
+//! pipeline stages. Crates register a [`StageFactory`] under a
+//! [`StageDescriptor`], which names the stage, fixes its [`PipelineStageType`]
+//! and declares the [`ParamSchema`](crate::ParamSchema) of its parameters.
+//!
+//! Stages are keyed by stage type and name, so `http` may be both a Feed and a
+//! Forward stage. [`PipelineStageRegistry::create`] checks the parameters against
+//! the declared schema, fills in defaults, calls the factory, and verifies that
+//! the factory built a stage of the declared type. The
+//! [`PipelineLoader`](crate::PipelineLoader) resolves every stage entry of a
+//! [`PipelineConfig`](crate::PipelineConfig) this way, and tooling can list what
+//! is available through [`PipelineStageRegistry::descriptors`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{
+//!     HexaError, ParamKind, ParamSpec, PipelineContext, PipelineStage, PipelineStageRegistry,
+//!     PipelineStageType, StageDescriptor,
+//! };
+//! use serde_json::{json, Value};
+//!
+//! struct Limit(u64);
+//!
+//! #[async_trait::async_trait]
+//! impl PipelineStage for Limit {
+//!     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Filter }
+//!     fn get_order(&self) -> u32 { 2 }
+//!     async fn execute(&self, _context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//!     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//! }
+//!
+//! let mut registry = PipelineStageRegistry::new();
+//! registry
+//!     .register(
+//!         StageDescriptor::new("limit", PipelineStageType::Filter)
+//!             .with_description("Drops events above a size limit")
+//!             .with_param(ParamSpec::required("max_bytes", ParamKind::Integer)),
+//!         |params: &Value| {
+//!             let max = params["max_bytes"].as_u64().unwrap_or_default();
+//!             Ok::<_, Box<dyn HexaError>>(Box::new(Limit(max)) as Box<dyn PipelineStage>)
+//!         },
+//!     )
+//!     .unwrap();
+//!
+//! let filter = PipelineStageType::Filter;
+//! assert!(registry.create(filter, "limit", &json!({ "max_bytes": 1024 })).is_ok());
+//!
+//! let err = registry.create(filter, "limit", &json!({ "max_bytes": "1kb" })).err().unwrap();
+//! assert_eq!(err.error_code(), "core.pipeline.invalid_params");
+//!
+//! let names: Vec<&str> = registry.descriptors().map(|d| d.name.as_str()).collect();
+//! assert_eq!(names, vec!["limit"]);
+//! ```
+
+use crate::domain::contracts::{HexaError, PipelineStage, PipelineStageType, StageFactory};
+use crate::domain::value_objects::{PipelineError, StageDescriptor};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+#[derive(Clone)]
+struct Entry {
+    descriptor: StageDescriptor,
+    factory: Arc<dyn StageFactory>,
+}
+
+/// Named, parameterized stage factories.
+#[derive(Clone, Default)]
+pub struct PipelineStageRegistry {
+    entries: BTreeMap<(PipelineStageType, String), Entry>,
+}
+
+impl PipelineStageRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `factory` under the stage type and name of `descriptor`.
+    ///
+    /// # Errors
+    ///
+    /// [`PipelineError::DuplicateFactory`] if that stage type and name are
+    /// already taken.
+    pub fn register<F>(
+        &mut self,
+        descriptor: StageDescriptor,
+        factory: F,
+    ) -> Result<(), PipelineError>
+    where
+        F: StageFactory + 'static,
+    {
+        let key = (descriptor.stage_type, descriptor.name.clone());
+        if self.entries.contains_key(&key) {
+            return Err(PipelineError::DuplicateFactory {
+                stage_type: key.0,
+                name: key.1,
+            });
+        }
+        let factory = Arc::new(factory);
+        self.entries.insert(
+            key,
+            Entry {
+                descriptor,
+                factory,
+            },
+        );
+        Ok(())
+    }
+
+    /// Create the `stage_type` stage registered as `name` from `params`.
+    ///
+    /// # Errors
+    ///
+    /// - [`PipelineError::UnknownFactory`] if no such stage is registered
+    /// - [`PipelineError::InvalidParams`] if `params` break the declared schema
+    /// - [`PipelineError::StageTypeMismatch`] if the factory built a stage of
+    ///   another type
+    /// - any error returned by the factory
+    pub fn create(
+        &self,
+        stage_type: PipelineStageType,
+        name: &str,
+        params: &serde_json::Value,
+    ) -> Result<Box<dyn PipelineStage>, Box<dyn HexaError>> {
+        let entry = self
+            .entries
+            .get(&(stage_type, name.to_string()))
+            .ok_or_else(|| PipelineError::UnknownFactory {
+                stage_type,
+                name: name.to_string(),
+            })?;
+        let schema = &entry.descriptor.params;
+        let violations = schema.violations(params);
+        if !violations.is_empty() {
+            return Err(Box::new(PipelineError::InvalidParams {
+                name: name.to_string(),
+                reason: violations.join("; "),
+            }));
+        }
+        let stage = entry
+            .factory
+            .create(&schema.apply_defaults(params.clone()))?;
+        if stage.stage_type() != stage_type {
+            return Err(Box::new(PipelineError::StageTypeMismatch {
+                expected: stage_type,
+                actual: stage.stage_type(),
+            }));
+        }
+        Ok(stage)
+    }
+
+    /// Whether a `stage_type` stage is registered as `name`.
+    pub fn contains(&self, stage_type: PipelineStageType, name: &str) -> bool {
+        self.descriptor(stage_type, name).is_some()
+    }
+
+    /// Metadata of the `stage_type` stage registered as `name`.
+    pub fn descriptor(
+        &self,
+        stage_type: PipelineStageType,
+        name: &str,
+    ) -> Option<&StageDescriptor> {
+        self.entries
+            .get(&(stage_type, name.to_string()))
+            .map(|entry| &entry.descriptor)
+    }
+
+    /// Metadata of every registered stage, ordered by stage type, then name.
+    pub fn descriptors(&self) -> impl Iterator<Item = &StageDescriptor> {
+        self.entries.values().map(|entry| &entry.descriptor)
+    }
+
+    /// Metadata of the stages registered for `stage_type`, ordered by name.
+    pub fn descriptors_for(
+        &self,
+        stage_type: PipelineStageType,
+    ) -> impl Iterator<Item = &StageDescriptor> {
+        self.descriptors()
+            .filter(move |descriptor| descriptor.stage_type == stage_type)
+    }
+
+    /// Number of registered stages.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no stages are registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl fmt::Debug for PipelineStageRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.descriptors()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::PipelineContext;
+    use crate::domain::value_objects::{ParamKind, ParamSchema, ParamSpec};
+    use serde_json::{json, Value};
+    use std::sync::Mutex;
+
+    struct Step(PipelineStageType);
+
+    #[async_trait::async_trait]
+    impl PipelineStage for Step {
+        fn stage_type(&self) -> PipelineStageType {
+            self.0
+        }
+        fn get_order(&self) -> u32 {
+            1
+        }
+        async fn execute(&self, _context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+    }
+
+    fn factory(stage_type: PipelineStageType) -> impl StageFactory {
+        move |_: &Value| {
+            Ok::<_, Box<dyn HexaError>>(Box::new(Step(stage_type)) as Box<dyn PipelineStage>)
+        }
+    }
+
+    #[test]
+    fn test_names_are_scoped_by_stage_type() {
+        let mut registry = PipelineStageRegistry::new();
+        for stage_type in [PipelineStageType::Forward, PipelineStageType::Feed] {
+            registry
+                .register(
+                    StageDescriptor::new("http", stage_type),
+                    factory(stage_type),
+                )
+                .unwrap();
+        }
+        let err = registry
+            .register(
+                StageDescriptor::new("http", PipelineStageType::Feed),
+                factory(PipelineStageType::Feed),
+            )
+            .unwrap_err();
+        assert_eq!(err.error_code(), "core.pipeline.duplicate_factory");
+
+        assert!(registry.contains(PipelineStageType::Forward, "http"));
+        assert!(!registry.contains(PipelineStageType::Filter, "http"));
+        let types: Vec<_> = registry.descriptors().map(|d| d.stage_type).collect();
+        assert_eq!(types, [PipelineStageType::Feed, PipelineStageType::Forward]);
+        assert_eq!(registry.descriptors_for(PipelineStageType::Feed).count(), 1);
+
+        let err = registry
+            .create(PipelineStageType::Filter, "http", &Value::Null)
+            .err()
+            .unwrap();
+        assert_eq!(err.error_code(), "core.pipeline.unknown_factory");
+    }
+
+    #[test]
+    fn test_params_are_validated_and_defaulted() {
+        let seen = Arc::new(Mutex::new(Value::Null));
+        let captured = Arc::clone(&seen);
+        let mut registry = PipelineStageRegistry::new();
+        registry
+            .register(
+                StageDescriptor::new("batch", PipelineStageType::Format)
+                    .with_param(ParamSpec::required("size", ParamKind::Integer))
+                    .with_param(
+                        ParamSpec::optional("flush_ms", ParamKind::Integer).with_default(json!(50)),
+                    ),
+                move |params: &Value| {
+                    *captured.lock().unwrap() = params.clone();
+                    Ok::<_, Box<dyn HexaError>>(
+                        Box::new(Step(PipelineStageType::Format)) as Box<dyn PipelineStage>
+                    )
+                },
+            )
+            .unwrap();
+
+        let format = PipelineStageType::Format;
+        registry
+            .create(format, "batch", &json!({ "size": 10 }))
+            .unwrap();
+        assert_eq!(*seen.lock().unwrap(), json!({ "size": 10, "flush_ms": 50 }));
+
+        let err = registry
+            .create(format, "batch", &json!({ "sise": 10 }))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid parameters for stage 'batch': missing required parameter 'size'; unknown parameter 'sise'"
+        );
+    }
+
+    #[test]
+    fn test_factory_must_build_declared_type() {
+        let mut registry = PipelineStageRegistry::new();
+        registry
+            .register(
+                StageDescriptor::new("liar", PipelineStageType::Filter)
+                    .with_params(ParamSchema::any()),
+                factory(PipelineStageType::Format),
+            )
+            .unwrap();
+        let err = registry
+            .create(
+                PipelineStageType::Filter,
+                "liar",
+                &json!({ "anything": true }),
+            )
+            .err()
+            .unwrap();
+        assert_eq!(err.error_code(), "core.pipeline.stage_type_mismatch");
+    }
+}
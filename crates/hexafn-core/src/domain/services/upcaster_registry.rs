@@ -0,0 +1,272 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # UpcasterRegistry (Core Module)
+//!
+//! This module provides [`UpcasterRegistry`], the chains of [`Upcaster`]s that
+//! bring event payloads to their latest schema version. Chains are keyed by
+//! [`Event::event_type`]; the upcaster registered for version N produces version
+//! N+1, and the latest version of a type is one past its highest upcaster.
+//!
+//! Events already at (or beyond) the latest version pass through unchanged. The
+//! registry is shared by the [`EventBus`](crate::EventBus) and
+//! [`UpcastingEventStore`](crate::UpcastingEventStore), so every consumer sees
+//! the same shape.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventEnvelope, HexaError, UpcasterRegistry};
+//! use serde_json::{json, Value};
+//!
+//! let mut registry = UpcasterRegistry::new();
+//! registry
+//!     .register("order.placed", 1, |mut payload: Value| {
+//!         payload["currency"] = json!("EUR");
+//!         Ok::<_, Box<dyn HexaError>>(payload)
+//!     })
+//!     .unwrap();
+//! registry
+//!     .register("order.placed", 2, |payload: Value| {
+//!         Ok::<_, Box<dyn HexaError>>(json!({ "amount": payload["total"], "currency": payload["currency"] }))
+//!     })
+//!     .unwrap();
+//! assert_eq!(registry.latest_version("order.placed"), 3);
+//!
+//! let stored = EventEnvelope::new("order.placed", json!({ "total": 10 }));
+//! let current = registry.upcast(stored).unwrap();
+//! assert_eq!(current.schema_version, 3);
+//! assert_eq!(current.payload, json!({ "amount": 10, "currency": "EUR" }));
+//! ```
+
+use crate::domain::contracts::{Event, Upcaster};
+use crate::domain::value_objects::{EventEnvelope, UpcastError};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+
+/// Per-event-type chains of upcasters.
+#[derive(Clone, Default)]
+pub struct UpcasterRegistry {
+    chains: HashMap<String, BTreeMap<u32, Arc<dyn Upcaster>>>,
+}
+
+impl UpcasterRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the upcaster converting `event_type` payloads from
+    /// `from_version` to `from_version + 1`.
+    ///
+    /// # Errors
+    ///
+    /// - [`UpcastError::InvalidVersion`] if `from_version` is 0
+    /// - [`UpcastError::Duplicate`] if that version already has an upcaster
+    pub fn register<U>(
+        &mut self,
+        event_type: impl Into<String>,
+        from_version: u32,
+        upcaster: U,
+    ) -> Result<(), UpcastError>
+    where
+        U: Upcaster + 'static,
+    {
+        let event_type = event_type.into();
+        if from_version == 0 {
+            return Err(UpcastError::InvalidVersion { event_type });
+        }
+        let chain = self.chains.entry(event_type.clone()).or_default();
+        if chain.contains_key(&from_version) {
+            return Err(UpcastError::Duplicate {
+                event_type,
+                from_version,
+            });
+        }
+        chain.insert(from_version, Arc::new(upcaster));
+        Ok(())
+    }
+
+    /// Latest schema version of `event_type`; the default version if it has no
+    /// upcasters.
+    pub fn latest_version(&self, event_type: &str) -> u32 {
+        self.chains
+            .get(event_type)
+            .and_then(|chain| chain.keys().next_back())
+            .map_or(EventEnvelope::DEFAULT_SCHEMA_VERSION, |last| last + 1)
+    }
+
+    /// Whether `event` is older than the latest version of its type.
+    pub fn needs_upcast(&self, event: &dyn Event) -> bool {
+        event.schema_version() < self.latest_version(event.event_type())
+    }
+
+    /// Whether an upcaster is registered for `event_type` at `from_version`.
+    pub fn contains(&self, event_type: &str, from_version: u32) -> bool {
+        self.chains
+            .get(event_type)
+            .is_some_and(|chain| chain.contains_key(&from_version))
+    }
+
+    /// Whether any upcaster is registered for `event_type`.
+    pub fn has_upcasters(&self, event_type: &str) -> bool {
+        self.chains.contains_key(event_type)
+    }
+
+    /// Event types that have upcasters, in no particular order.
+    pub fn event_types(&self) -> impl Iterator<Item = &str> {
+        self.chains.keys().map(String::as_str)
+    }
+
+    /// Whether no upcasters are registered.
+    pub fn is_empty(&self) -> bool {
+        self.chains.is_empty()
+    }
+
+    /// Bring `envelope` to the latest version of its type, updating its payload
+    /// and schema version.
+    ///
+    /// # Errors
+    ///
+    /// - [`UpcastError::Missing`] if the chain has a gap at a needed version
+    /// - [`UpcastError::Failed`] if an upcaster rejects the payload
+    pub fn upcast(&self, mut envelope: EventEnvelope) -> Result<EventEnvelope, UpcastError> {
+        let Some(chain) = self.chains.get(&envelope.event_type) else {
+            return Ok(envelope);
+        };
+        let latest = self.latest_version(&envelope.event_type);
+        while envelope.schema_version < latest {
+            let version = envelope.schema_version;
+            let upcaster = chain.get(&version).ok_or_else(|| UpcastError::Missing {
+                event_type: envelope.event_type.clone(),
+                from_version: version,
+            })?;
+            let payload = std::mem::take(&mut envelope.payload);
+            envelope.payload = upcaster
+                .upcast(payload)
+                .map_err(|error| UpcastError::Failed {
+                    event_type: envelope.event_type.clone(),
+                    from_version: version,
+                    reason: error.to_string(),
+                })?;
+            envelope.schema_version = version + 1;
+        }
+        Ok(envelope)
+    }
+}
+
+impl fmt::Debug for UpcasterRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.chains
+                    .iter()
+                    .map(|(event_type, chain)| (event_type, chain.keys().collect::<Vec<_>>())),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::HexaError;
+    use crate::GenericHexaError;
+    use serde_json::{json, Value};
+
+    fn rename(from: &'static str, to: &'static str) -> impl Upcaster {
+        move |mut payload: Value| {
+            let value = payload[from].take();
+            payload[to] = value;
+            payload.as_object_mut().unwrap().remove(from);
+            Ok::<_, Box<dyn HexaError>>(payload)
+        }
+    }
+
+    fn registry() -> UpcasterRegistry {
+        let mut registry = UpcasterRegistry::new();
+        registry
+            .register("user.created", 1, rename("name", "full_name"))
+            .unwrap();
+        registry
+            .register("user.created", 2, rename("full_name", "display_name"))
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_upcast_chains_to_latest() {
+        let registry = registry();
+        let v1 = EventEnvelope::new("user.created", json!({ "name": "Ada" }));
+        assert!(registry.needs_upcast(&v1));
+
+        let latest = registry.upcast(v1.clone()).unwrap();
+        assert_eq!(latest.schema_version, 3);
+        assert_eq!(latest.payload, json!({ "display_name": "Ada" }));
+        assert_eq!(latest.id, v1.id);
+
+        let v2 = EventEnvelope::new("user.created", json!({ "full_name": "Bob" }))
+            .with_schema_version(2);
+        assert_eq!(
+            registry.upcast(v2).unwrap().payload,
+            json!({ "display_name": "Bob" })
+        );
+    }
+
+    #[test]
+    fn test_current_and_unknown_events_pass_through() {
+        let registry = registry();
+        let current = EventEnvelope::new("user.created", json!({ "display_name": "Ada" }))
+            .with_schema_version(3);
+        assert!(!registry.needs_upcast(&current));
+        assert_eq!(registry.upcast(current.clone()).unwrap(), current);
+
+        let other = EventEnvelope::new("user.deleted", json!({}));
+        assert_eq!(registry.latest_version("user.deleted"), 1);
+        assert_eq!(registry.upcast(other.clone()).unwrap(), other);
+    }
+
+    #[test]
+    fn test_registration_is_validated() {
+        let mut registry = registry();
+        assert!(matches!(
+            registry.register("user.created", 0, rename("a", "b")),
+            Err(UpcastError::InvalidVersion { .. })
+        ));
+        assert!(matches!(
+            registry.register("user.created", 2, rename("a", "b")),
+            Err(UpcastError::Duplicate {
+                from_version: 2,
+                ..
+            })
+        ));
+        assert!(registry.contains("user.created", 1));
+        assert!(!registry.contains("user.created", 3));
+    }
+
+    #[test]
+    fn test_gaps_and_failures_are_reported() {
+        let mut registry = UpcasterRegistry::new();
+        registry.register("a.b", 2, rename("x", "y")).unwrap();
+        registry
+            .register("c.d", 1, |_payload: Value| {
+                Err::<Value, Box<dyn HexaError>>(Box::new(GenericHexaError::new(
+                    "test.upcast",
+                    "bad shape",
+                )))
+            })
+            .unwrap();
+
+        let gap = registry
+            .upcast(EventEnvelope::new("a.b", json!({})))
+            .unwrap_err();
+        assert_eq!(gap.error_code(), "core.upcast.missing");
+
+        let failed = registry
+            .upcast(EventEnvelope::new("c.d", json!({})))
+            .unwrap_err();
+        assert_eq!(failed.error_code(), "core.upcast.failed");
+        assert!(failed.to_string().contains("bad shape"));
+    }
+}
@@ -0,0 +1,208 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # UpcastingEventStore (Core Module)
+//!
+//! This module provides [`UpcastingEventStore`], an [`EventStore`] decorator that
+//! runs every event read from the wrapped store through an [`UpcasterRegistry`].
+//! Stored events keep the version they were written with and old data is never
+//! rewritten.
+//!
+//! New events must be written at the latest schema version of their type: an
+//! append carrying an older version is rejected with [`UpcastError::Outdated`].
+//! [`EventEnvelope::new`] defaults to version 1, and a latest-shape payload
+//! stored as version 1 would be run through every upcaster again on read.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{
+//!     EventEnvelope, EventStore, HexaError, InMemoryEventStore, UpcasterRegistry,
+//!     UpcastingEventStore,
+//! };
+//! use serde_json::{json, Value};
+//! use std::sync::Arc;
+//!
+//! # tokio_test::block_on(async {
+//! let mut upcasters = UpcasterRegistry::new();
+//! upcasters
+//!     .register("cart.item_added", 1, |payload: Value| {
+//!         Ok::<_, Box<dyn HexaError>>(json!({ "sku": payload["sku"], "quantity": 1 }))
+//!     })
+//!     .unwrap();
+//!
+//! // Written before the upcaster existed.
+//! let legacy = InMemoryEventStore::new();
+//! legacy
+//!     .append("cart-1", 0, vec![EventEnvelope::new("cart.item_added", json!({ "sku": "A" }))])
+//!     .await
+//!     .unwrap();
+//!
+//! let store = UpcastingEventStore::new(legacy, Arc::new(upcasters));
+//! let events = store.read_stream("cart-1", 1).await.unwrap();
+//! assert_eq!(events[0].payload, json!({ "sku": "A", "quantity": 1 }));
+//! assert_eq!(events[0].schema_version, 2);
+//!
+//! let unversioned = EventEnvelope::new("cart.item_added", json!({ "sku": "B", "quantity": 2 }));
+//! let err = store.append("cart-1", 1, vec![unversioned.clone()]).await.unwrap_err();
+//! assert_eq!(err.error_code(), "core.upcast.outdated");
+//! store.append("cart-1", 1, vec![unversioned.with_schema_version(2)]).await.unwrap();
+//! # });
+//! ```
+
+use crate::domain::contracts::{EventStore, HexaError};
+use crate::domain::services::UpcasterRegistry;
+use crate::domain::value_objects::{EventEnvelope, UpcastError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Event store that upcasts events on read.
+#[derive(Debug, Clone)]
+pub struct UpcastingEventStore<S> {
+    inner: S,
+    upcasters: Arc<UpcasterRegistry>,
+}
+
+impl<S: EventStore> UpcastingEventStore<S> {
+    /// Wrap `inner`, upcasting its events with `upcasters`.
+    pub fn new(inner: S, upcasters: Arc<UpcasterRegistry>) -> Self {
+        Self { inner, upcasters }
+    }
+
+    /// The wrapped store, which returns events as stored.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// The registry used on read.
+    pub fn upcasters(&self) -> &UpcasterRegistry {
+        &self.upcasters
+    }
+}
+
+#[async_trait]
+impl<S: EventStore> EventStore for UpcastingEventStore<S> {
+    async fn append(
+        &self,
+        aggregate_id: &str,
+        expected_version: u64,
+        events: Vec<EventEnvelope>,
+    ) -> Result<u64, Box<dyn HexaError>> {
+        for event in &events {
+            let latest = self.upcasters.latest_version(&event.event_type);
+            if event.schema_version < latest {
+                return Err(Box::new(UpcastError::Outdated {
+                    event_type: event.event_type.clone(),
+                    schema_version: event.schema_version,
+                    latest,
+                }));
+            }
+        }
+        self.inner
+            .append(aggregate_id, expected_version, events)
+            .await
+    }
+
+    async fn read_stream(
+        &self,
+        aggregate_id: &str,
+        from_sequence: u64,
+    ) -> Result<Vec<EventEnvelope>, Box<dyn HexaError>> {
+        self.inner
+            .read_stream(aggregate_id, from_sequence)
+            .await?
+            .into_iter()
+            .map(|event| self.upcasters.upcast(event).map_err(Into::into))
+            .collect()
+    }
+
+    async fn stream_version(&self, aggregate_id: &str) -> Result<u64, Box<dyn HexaError>> {
+        self.inner.stream_version(aggregate_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::Aggregate;
+    use crate::domain::entities::EventSourced;
+    use crate::infrastructure::adapters::outbound::InMemoryEventStore;
+    use serde_json::{json, Value};
+
+    #[derive(Default)]
+    struct Names(Vec<String>);
+
+    impl Aggregate for Names {
+        fn apply(&mut self, event: &EventEnvelope) -> Result<(), Box<dyn HexaError>> {
+            self.0
+                .push(event.payload["display_name"].as_str().unwrap().to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_aggregates_see_latest_shape() {
+        let mut upcasters = UpcasterRegistry::new();
+        upcasters
+            .register("user.renamed", 1, |payload: Value| {
+                Ok::<_, Box<dyn HexaError>>(json!({ "display_name": payload["name"] }))
+            })
+            .unwrap();
+        let store = UpcastingEventStore::new(InMemoryEventStore::new(), Arc::new(upcasters));
+        store
+            .inner()
+            .append(
+                "u",
+                0,
+                vec![EventEnvelope::new("user.renamed", json!({ "name": "Ada" }))],
+            )
+            .await
+            .unwrap();
+        store
+            .append(
+                "u",
+                1,
+                vec![
+                    EventEnvelope::new("user.renamed", json!({ "display_name": "Grace" }))
+                        .with_schema_version(2),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let names = EventSourced::<Names>::load(&store, "u").await.unwrap();
+        assert_eq!(names.state().0, vec!["Ada", "Grace"]);
+
+        let raw = store.inner().read_stream("u", 1).await.unwrap();
+        assert_eq!(raw[0].schema_version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_latest_shape_round_trips_and_outdated_appends_are_rejected() {
+        let mut upcasters = UpcasterRegistry::new();
+        upcasters
+            .register("user.renamed", 1, |payload: Value| {
+                Ok::<_, Box<dyn HexaError>>(json!({ "display_name": payload["name"] }))
+            })
+            .unwrap();
+        let store = UpcastingEventStore::new(InMemoryEventStore::new(), Arc::new(upcasters));
+        let latest = json!({ "display_name": "Ada" });
+
+        let err = store
+            .append(
+                "u",
+                0,
+                vec![EventEnvelope::new("user.renamed", latest.clone())],
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.error_code(), "core.upcast.outdated");
+        assert_eq!(store.stream_version("u").await.unwrap(), 0);
+
+        let event = EventEnvelope::new("user.renamed", latest.clone()).with_schema_version(2);
+        store.append("u", 0, vec![event]).await.unwrap();
+        let events = store.read_stream("u", 1).await.unwrap();
+        assert_eq!(events[0].payload, latest);
+        assert_eq!(events[0].schema_version, 2);
+    }
+}
@@ -0,0 +1,248 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # ContextMetadata (Core Module)
+//!
+//! This module defines [`ContextMetadata`], the execution metadata carried by every
+//! [`PipelineContext`](crate::PipelineContext): trace and correlation ids, the
+//! originating event, start time, deadline, and a write log recording which stage
+//! produced each context key.
+//!
+//! Feedback stages and debugging tools use the write log to explain how a result
+//! came about.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::PipelineContext;
+//! use serde_json::json;
+//!
+//! let mut context = PipelineContext::new();
+//! context.set("input".to_string(), json!(42)).unwrap();
+//!
+//! // Written before the run, so no stage is recorded for it.
+//! let metadata = context.metadata();
+//! assert_eq!(metadata.writes()[0].key, "input");
+//! assert_eq!(metadata.writes()[0].stage_type, None);
+//! assert!(!metadata.trace_id.is_empty());
+//! ```
+
+use crate::domain::contracts::{DomainEvent, Event, EventId, PipelineStageType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// One entry in the context write log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteRecord {
+    /// Context key that was written
+    pub key: String,
+    /// Stage executing when the key was written; `None` for writes made outside a run
+    pub stage_type: Option<PipelineStageType>,
+    /// When the write happened
+    pub written_at: DateTime<Utc>,
+}
+
+/// Execution metadata and provenance for a pipeline run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextMetadata {
+    /// Trace id unique to this run
+    pub trace_id: String,
+    /// Correlation id shared by related events, taken from [`DomainEvent::correlation_id`]
+    pub correlation_id: Option<String>,
+    /// Id of the event that started the run
+    pub event_id: Option<EventId>,
+    /// Type of the event that started the run
+    pub event_type: Option<String>,
+    /// When the context was created
+    pub started_at: DateTime<Utc>,
+    /// Point in time by which the run should finish
+    pub deadline: Option<DateTime<Utc>>,
+    writes: Vec<WriteRecord>,
+}
+
+impl ContextMetadata {
+    /// Create metadata with a fresh trace id and `started_at` set to now.
+    pub fn new() -> Self {
+        Self {
+            trace_id: Uuid::new_v4().to_string(),
+            correlation_id: None,
+            event_id: None,
+            event_type: None,
+            started_at: Utc::now(),
+            deadline: None,
+            writes: Vec::new(),
+        }
+    }
+
+    /// Create metadata for a run triggered by `event`.
+    pub fn from_event<E: Event + ?Sized>(event: &E) -> Self {
+        Self {
+            event_id: Some(event.event_id().clone()),
+            event_type: Some(event.event_type().to_string()),
+            ..Self::new()
+        }
+    }
+
+    /// Create metadata for a run triggered by a domain event, carrying over its
+    /// correlation id.
+    pub fn from_domain_event<E: DomainEvent + ?Sized>(event: &E) -> Self {
+        Self {
+            correlation_id: Some(event.correlation_id().to_string()),
+            event_id: Some(event.event_id().clone()),
+            event_type: Some(event.event_type().to_string()),
+            ..Self::new()
+        }
+    }
+
+    /// Set the deadline by which the run should finish.
+    pub fn with_deadline(mut self, deadline: DateTime<Utc>) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Returns `true` if a deadline is set and `now` is past it.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.deadline.is_some_and(|deadline| now > deadline)
+    }
+
+    /// Time left until the deadline at `now`, or `None` if no deadline is set.
+    ///
+    /// Returns [`Duration::ZERO`] once the deadline has passed.
+    pub fn remaining(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| (deadline - now).to_std().unwrap_or(Duration::ZERO))
+    }
+
+    /// Every write made to the context, oldest first.
+    pub fn writes(&self) -> &[WriteRecord] {
+        &self.writes
+    }
+
+    /// Writes made to `key`, oldest first.
+    pub fn history<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a WriteRecord> + 'a {
+        self.writes.iter().filter(move |record| record.key == key)
+    }
+
+    /// Stage that last wrote `key`, or `None` if the key was never written during
+    /// a stage.
+    pub fn written_by(&self, key: &str) -> Option<PipelineStageType> {
+        self.history(key)
+            .last()
+            .and_then(|record| record.stage_type)
+    }
+
+    pub(crate) fn record_write(
+        &mut self,
+        key: &str,
+        stage_type: Option<PipelineStageType>,
+        written_at: DateTime<Utc>,
+    ) {
+        self.writes.push(WriteRecord {
+            key: key.to_string(),
+            stage_type,
+            written_at,
+        });
+    }
+}
+
+impl Default for ContextMetadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct OrderPlaced {
+        id: EventId,
+    }
+
+    impl Event for OrderPlaced {
+        fn event_type(&self) -> &'static str {
+            "order.placed"
+        }
+        fn event_id(&self) -> &EventId {
+            &self.id
+        }
+        fn timestamp(&self) -> DateTime<Utc> {
+            Utc::now()
+        }
+        fn payload(&self) -> serde_json::Value {
+            json!({})
+        }
+    }
+
+    impl DomainEvent for OrderPlaced {
+        fn aggregate_id(&self) -> &str {
+            "order-1"
+        }
+        fn sequence_number(&self) -> u64 {
+            1
+        }
+        fn occurred_at(&self) -> DateTime<Utc> {
+            Utc::now()
+        }
+        fn correlation_id(&self) -> &str {
+            "corr-42"
+        }
+    }
+
+    #[test]
+    fn test_new_metadata_has_unique_trace_ids() {
+        let first = ContextMetadata::new();
+        let second = ContextMetadata::new();
+        assert_ne!(first.trace_id, second.trace_id);
+        assert!(first.event_id.is_none());
+        assert!(first.writes().is_empty());
+    }
+
+    #[test]
+    fn test_from_domain_event_copies_provenance() {
+        let event = OrderPlaced { id: EventId::new() };
+        let metadata = ContextMetadata::from_domain_event(&event);
+        assert_eq!(metadata.correlation_id.as_deref(), Some("corr-42"));
+        assert_eq!(metadata.event_id.as_ref(), Some(&event.id));
+        assert_eq!(metadata.event_type.as_deref(), Some("order.placed"));
+
+        let metadata = ContextMetadata::from_event(&event);
+        assert!(metadata.correlation_id.is_none());
+        assert_eq!(metadata.event_id.as_ref(), Some(&event.id));
+    }
+
+    #[test]
+    fn test_deadline_expiry() {
+        let now = Utc::now();
+        let metadata = ContextMetadata::new().with_deadline(now + chrono::Duration::seconds(5));
+        assert!(!metadata.is_expired(now));
+        assert!(metadata.is_expired(now + chrono::Duration::seconds(6)));
+        assert!(!ContextMetadata::new().is_expired(now));
+
+        assert_eq!(metadata.remaining(now), Some(Duration::from_secs(5)));
+        assert_eq!(
+            metadata.remaining(now + chrono::Duration::seconds(6)),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(ContextMetadata::new().remaining(now), None);
+    }
+
+    #[test]
+    fn test_write_log_tracks_last_writer() {
+        let mut metadata = ContextMetadata::new();
+        let now = Utc::now();
+        metadata.record_write("value", None, now);
+        metadata.record_write("value", Some(PipelineStageType::Format), now);
+        metadata.record_write("other", Some(PipelineStageType::Feed), now);
+
+        assert_eq!(metadata.history("value").count(), 2);
+        assert_eq!(
+            metadata.written_by("value"),
+            Some(PipelineStageType::Format)
+        );
+        assert_eq!(metadata.written_by("missing"), None);
+    }
+}
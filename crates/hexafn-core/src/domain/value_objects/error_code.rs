@@ -0,0 +1,256 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # ErrorCode (Core Module)
+//!
+//! This module defines [`ErrorCode`], a parsed and validated hierarchical error
+//! code in the `<domain>.<module>.<reason>` convention, and [`ErrorCodeError`],
+//! raised when a code or registration is invalid.
+//!
+//! Each segment must start with a lowercase ASCII letter and contain only
+//! lowercase letters, digits, and underscores.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::ErrorCode;
+//!
+//! let code = ErrorCode::parse("trigger.registry.not_found").unwrap();
+//! assert_eq!(code.domain(), "trigger");
+//! assert_eq!(code.module(), "registry");
+//! assert_eq!(code.reason(), "not_found");
+//!
+//! assert!(ErrorCode::parse("Trigger.registry").is_err());
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Errors raised while parsing or registering error codes.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ErrorCodeError {
+    /// The code does not follow `<domain>.<module>.<reason>`.
+    #[error("'{code}' is not a valid <domain>.<module>.<reason> error code")]
+    InvalidFormat {
+        /// The rejected code
+        code: String,
+    },
+
+    /// The code's domain is not one the registry accepts.
+    #[error("'{code}' uses unknown domain '{domain}'")]
+    UnknownDomain {
+        /// The rejected code
+        code: String,
+        /// Its domain segment
+        domain: String,
+    },
+
+    /// The code is already registered.
+    #[error("error code '{code}' is already registered")]
+    Duplicate {
+        /// The duplicated code
+        code: String,
+    },
+
+    /// An error carries a code that is not registered.
+    #[error("error code '{code}' is not registered")]
+    Unregistered {
+        /// The unknown code
+        code: String,
+    },
+
+    /// An error's kind differs from the kind registered for its code.
+    #[error("error code '{code}' is registered as {expected} but was raised as {actual}")]
+    KindMismatch {
+        /// The error code
+        code: String,
+        /// Registered kind
+        expected: HexaErrorKind,
+        /// Kind reported by the error
+        actual: HexaErrorKind,
+    },
+}
+
+impl HexaError for ErrorCodeError {
+    fn error_code(&self) -> &str {
+        match self {
+            ErrorCodeError::InvalidFormat { .. } => "core.error_code.invalid_format",
+            ErrorCodeError::UnknownDomain { .. } => "core.error_code.unknown_domain",
+            ErrorCodeError::Duplicate { .. } => "core.error_code.duplicate",
+            ErrorCodeError::Unregistered { .. } => "core.error_code.unregistered",
+            ErrorCodeError::KindMismatch { .. } => "core.error_code.kind_mismatch",
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            ErrorCodeError::InvalidFormat { .. } => {
+                "Error codes must follow the <domain>.<module>.<reason> convention"
+            }
+            ErrorCodeError::UnknownDomain { .. } => "Error code domain is not recognised",
+            ErrorCodeError::Duplicate { .. } => "Error code is already registered",
+            ErrorCodeError::Unregistered { .. } => "Error code is not registered",
+            ErrorCodeError::KindMismatch { .. } => {
+                "Error kind does not match the registered error code"
+            }
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        match self {
+            ErrorCodeError::Unregistered { .. } => HexaErrorKind::NotFound,
+            _ => HexaErrorKind::Validation,
+        }
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        HexaErrorSeverity::Medium
+    }
+}
+
+/// A validated `<domain>.<module>.<reason>` error code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ErrorCode {
+    code: String,
+    module_start: usize,
+    reason_start: usize,
+}
+
+impl ErrorCode {
+    /// Parse and validate an error code.
+    ///
+    /// # Errors
+    ///
+    /// [`ErrorCodeError::InvalidFormat`] if the code does not have exactly three
+    /// well-formed segments.
+    pub fn parse(code: &str) -> Result<Self, ErrorCodeError> {
+        let invalid = || ErrorCodeError::InvalidFormat {
+            code: code.to_string(),
+        };
+        let segments: Vec<&str> = code.split('.').collect();
+        if segments.len() != 3 || !segments.iter().all(|segment| is_valid_segment(segment)) {
+            return Err(invalid());
+        }
+        let module_start = segments[0].len() + 1;
+        Ok(Self {
+            code: code.to_string(),
+            module_start,
+            reason_start: module_start + segments[1].len() + 1,
+        })
+    }
+
+    /// The full code, e.g. `core.pipeline.empty`.
+    pub fn as_str(&self) -> &str {
+        &self.code
+    }
+
+    /// First segment, identifying the hexaFn module (`core`, `trigger`, ...).
+    pub fn domain(&self) -> &str {
+        &self.code[..self.module_start - 1]
+    }
+
+    /// Second segment, identifying the component within the domain.
+    pub fn module(&self) -> &str {
+        &self.code[self.module_start..self.reason_start - 1]
+    }
+
+    /// Third segment, identifying the specific failure.
+    pub fn reason(&self) -> &str {
+        &self.code[self.reason_start..]
+    }
+}
+
+fn is_valid_segment(segment: &str) -> bool {
+    let mut chars = segment.chars();
+    matches!(chars.next(), Some(first) if first.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+impl FromStr for ErrorCode {
+    type Err = ErrorCodeError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::parse(code)
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.code)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Self::parse(&code).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_valid_codes() {
+        for code in [
+            "core.pipeline.empty",
+            "store.kv.write_failed",
+            "bridge.http2.status_503",
+        ] {
+            let parsed = ErrorCode::parse(code).unwrap();
+            assert_eq!(parsed.as_str(), code);
+            assert_eq!(
+                format!(
+                    "{}.{}.{}",
+                    parsed.domain(),
+                    parsed.module(),
+                    parsed.reason()
+                ),
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_codes() {
+        for code in [
+            "",
+            "core",
+            "core.pipeline",
+            "core.pipeline.empty.extra",
+            "Core.pipeline.empty",
+            "core..empty",
+            "core.pipeline.not-found",
+            "core.1pipeline.empty",
+            "TEST_ERROR",
+        ] {
+            let error = ErrorCode::parse(code).unwrap_err();
+            assert_eq!(error.error_code(), "core.error_code.invalid_format");
+        }
+    }
+
+    #[test]
+    fn test_serde_validates_on_deserialize() {
+        let code = ErrorCode::parse("cast.delivery.retry_exhausted").unwrap();
+        let json = serde_json::to_string(&code).unwrap();
+        assert_eq!(json, "\"cast.delivery.retry_exhausted\"");
+        assert_eq!(serde_json::from_str::<ErrorCode>(&json).unwrap(), code);
+        assert!(serde_json::from_str::<ErrorCode>("\"not a code\"").is_err());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let code: ErrorCode = "watch.tracing.span_failed".parse().unwrap();
+        assert_eq!(code.module(), "tracing");
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # ErrorReport (Core Module)
+//!
+//! This module defines [`ErrorReport`], the stable, serializable shape of a
+//! [`HexaError`](crate::HexaError). Reports can be stored, published on Cast
+//! topics, or returned from HTTP endpoints, and deserialized on the other side.
+//!
+//! ## JSON Shape
+//!
+//! ```json
+//! {
+//!   "code": "core.pipeline.empty",
+//!   "kind": "Validation",
+//!   "severity": "High",
+//!   "message": "Pipeline must contain at least one stage",
+//!   "detail": "pipeline has no stages",
+//!   "causes": [],
+//!   "context": {},
+//!   "timestamp": "2025-01-01T00:00:00Z"
+//! }
+//! ```
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{ErrorReport, HexaError, PipelineError};
+//!
+//! let report = PipelineError::EmptyPipeline.to_report();
+//! let json = serde_json::to_string(&report).unwrap();
+//! let parsed: ErrorReport = serde_json::from_str(&json).unwrap();
+//! assert_eq!(parsed, report);
+//! assert_eq!(parsed.code, "core.pipeline.empty");
+//! ```
+
+use crate::domain::contracts::{HexaErrorKind, HexaErrorSeverity};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Serializable snapshot of a [`HexaError`](crate::HexaError).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorReport {
+    /// Hierarchical error code, e.g. `core.pipeline.empty`
+    pub code: String,
+    /// Error category
+    pub kind: HexaErrorKind,
+    /// Error priority
+    pub severity: HexaErrorSeverity,
+    /// Human-readable description of the error class
+    pub message: String,
+    /// Display text of this specific occurrence
+    pub detail: String,
+    /// Display text of each underlying cause, outermost first
+    #[serde(default)]
+    pub causes: Vec<String>,
+    /// Structured key/value context
+    #[serde(default)]
+    pub context: BTreeMap<String, String>,
+    /// When the report was created
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ErrorReport {
+    /// Render the report in the same format as `HexaError::to_log_entry`.
+    pub fn to_log_entry(&self) -> String {
+        format!(
+            "[{}] [{} {}] {}",
+            self.code, self.kind, self.severity, self.message
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample() -> ErrorReport {
+        ErrorReport {
+            code: "store.kv.write_failed".to_string(),
+            kind: HexaErrorKind::External,
+            severity: HexaErrorSeverity::High,
+            message: "Write failed".to_string(),
+            detail: "write to users/1 failed".to_string(),
+            causes: vec!["connection reset".to_string()],
+            context: BTreeMap::from([("key".to_string(), "users/1".to_string())]),
+            timestamp: DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+
+    #[test]
+    fn test_json_shape_is_stable() {
+        let value = serde_json::to_value(sample()).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "code": "store.kv.write_failed",
+                "kind": "External",
+                "severity": "High",
+                "message": "Write failed",
+                "detail": "write to users/1 failed",
+                "causes": ["connection reset"],
+                "context": { "key": "users/1" },
+                "timestamp": "2025-01-01T00:00:00Z"
+            })
+        );
+    }
+
+    #[test]
+    fn test_optional_sections_default_when_missing() {
+        let report: ErrorReport = serde_json::from_value(json!({
+            "code": "core.test.minimal",
+            "kind": "Unknown",
+            "severity": "Low",
+            "message": "Minimal",
+            "detail": "minimal",
+            "timestamp": "2025-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        assert!(report.causes.is_empty());
+        assert!(report.context.is_empty());
+        assert_eq!(
+            report.to_log_entry(),
+            "[core.test.minimal] [Unknown Low] Minimal"
+        );
+    }
+}
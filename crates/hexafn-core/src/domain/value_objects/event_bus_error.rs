@@ -0,0 +1,139 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Event Bus Errors (Core Module)
+//!
+//! This module defines [`EventBusError`], the concrete [`HexaError`] raised by the
+//! in-process [`EventBus`](crate::EventBus) when subscribing or publishing fails.
+//!
+//! All variants use hierarchical `core.event_bus.*` error codes.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventBusError, HexaError, HexaErrorKind};
+//!
+//! let error = EventBusError::QueueFull { shard: 2 };
+//! assert_eq!(error.error_code(), "core.event_bus.queue_full");
+//! assert_eq!(error.error_kind(), HexaErrorKind::RateLimited);
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use thiserror::Error;
+
+/// Errors produced by the in-process event bus.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EventBusError {
+    /// A subscription pattern could not be parsed.
+    #[error("invalid event pattern '{pattern}': {reason}")]
+    InvalidPattern {
+        /// The rejected pattern
+        pattern: String,
+        /// Why it was rejected
+        reason: String,
+    },
+
+    /// The target shard queue is full and the publish was not allowed to wait.
+    #[error("event bus shard {shard} queue is full")]
+    QueueFull {
+        /// Index of the full shard
+        shard: usize,
+    },
+
+    /// The bus has been shut down.
+    #[error("event bus is shut down")]
+    Closed,
+
+    /// A published event could not be upcast to the latest schema version.
+    #[error("event '{event_type}' could not be upcast: {reason}")]
+    UpcastFailed {
+        /// Type of the rejected event
+        event_type: String,
+        /// Underlying upcast error
+        reason: String,
+    },
+
+    /// A typed subscription matched an event type whose events are upcast, and
+    /// so delivered as envelopes.
+    #[error("events of type '{event_type}' are upcast and delivered as EventEnvelope; subscribe with that type")]
+    TypedUpcastSubscription {
+        /// The upcast event type
+        event_type: String,
+    },
+}
+
+impl HexaError for EventBusError {
+    fn error_code(&self) -> &str {
+        match self {
+            EventBusError::InvalidPattern { .. } => "core.event_bus.invalid_pattern",
+            EventBusError::QueueFull { .. } => "core.event_bus.queue_full",
+            EventBusError::Closed => "core.event_bus.closed",
+            EventBusError::UpcastFailed { .. } => "core.event_bus.upcast_failed",
+            EventBusError::TypedUpcastSubscription { .. } => {
+                "core.event_bus.typed_upcast_subscription"
+            }
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            EventBusError::InvalidPattern { .. } => "Event pattern is invalid",
+            EventBusError::QueueFull { .. } => "Event bus queue is full",
+            EventBusError::Closed => "Event bus is shut down",
+            EventBusError::UpcastFailed { .. } => "Event could not be upcast",
+            EventBusError::TypedUpcastSubscription { .. } => {
+                "Typed subscription cannot receive upcast events"
+            }
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        match self {
+            EventBusError::InvalidPattern { .. } => HexaErrorKind::Validation,
+            EventBusError::QueueFull { .. } => HexaErrorKind::RateLimited,
+            EventBusError::Closed => HexaErrorKind::Unavailable,
+            EventBusError::UpcastFailed { .. } => HexaErrorKind::Serialization,
+            EventBusError::TypedUpcastSubscription { .. } => HexaErrorKind::Validation,
+        }
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        match self {
+            EventBusError::InvalidPattern { .. }
+            | EventBusError::UpcastFailed { .. }
+            | EventBusError::TypedUpcastSubscription { .. } => HexaErrorSeverity::High,
+            EventBusError::QueueFull { .. } | EventBusError::Closed => HexaErrorSeverity::Medium,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_follow_hierarchy() {
+        let errors = [
+            EventBusError::InvalidPattern {
+                pattern: "a..b".to_string(),
+                reason: "empty segment".to_string(),
+            },
+            EventBusError::QueueFull { shard: 0 },
+            EventBusError::Closed,
+            EventBusError::UpcastFailed {
+                event_type: "a.b".to_string(),
+                reason: "missing field".to_string(),
+            },
+            EventBusError::TypedUpcastSubscription {
+                event_type: "a.b".to_string(),
+            },
+        ];
+        for error in errors {
+            assert!(error.error_code().starts_with("core.event_bus."));
+            assert!(!error.error_message().is_empty());
+        }
+        assert!(EventBusError::QueueFull { shard: 0 }
+            .error_kind()
+            .is_retryable());
+    }
+}
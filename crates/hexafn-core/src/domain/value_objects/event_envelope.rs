@@ -0,0 +1,344 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # EventEnvelope (Core Module)
+//!
+//! This module defines [`EventEnvelope`], a concrete, serializable event that
+//! implements both [`Event`] and [`DomainEvent`].
+//!
+//! Hand-written event structs compute their payload and cannot be rebuilt from
+//! the wire. An envelope stores everything explicitly, including an event type
+//! known only at runtime, so it can be persisted, sent across process boundaries,
+//! and deserialized on the other side. Any event can be captured into an envelope
+//! with [`EventEnvelope::from_event`] or [`EventEnvelope::from_domain_event`].
+//!
+//! ## JSON Shape
+//!
+//! ```json
+//! {
+//!   "id": "0190c5d2-8f5e-7c3a-9b1e-4f2a6d8c0e11",
+//!   "event_type": "order.placed",
+//!   "timestamp": "2025-01-01T00:00:00Z",
+//!   "payload": { "total": 42 },
+//!   "headers": { "source": "bridge.webhook" },
+//!   "correlation_id": "checkout-17",
+//!   "causation_id": null,
+//!   "aggregate_id": "order-1",
+//!   "sequence_number": 3,
+//!   "schema_version": 1
+//! }
+//! ```
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{DomainEvent, Event, EventEnvelope};
+//! use serde_json::json;
+//!
+//! let event_type = String::from("order.placed"); // e.g. loaded from config
+//! let envelope = EventEnvelope::new(event_type, json!({ "total": 42 }))
+//!     .with_aggregate("order-1", 3)
+//!     .with_correlation_id("checkout-17")
+//!     .with_header("source", "bridge.webhook");
+//!
+//! let json = serde_json::to_string(&envelope).unwrap();
+//! let parsed: EventEnvelope = serde_json::from_str(&json).unwrap();
+//! assert_eq!(parsed, envelope);
+//! assert_eq!(parsed.event_type(), "order.placed");
+//! assert_eq!(parsed.aggregate_id(), "order-1");
+//! assert_eq!(parsed.sequence_number(), 3);
+//! ```
+
+use crate::domain::contracts::{DomainEvent, Event, EventId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Serializable event with runtime event type, headers and domain metadata.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    /// Unique event id
+    pub id: EventId,
+    /// Event type, e.g. `order.placed`
+    pub event_type: String,
+    /// When the event occurred
+    pub timestamp: DateTime<Utc>,
+    /// Event data
+    #[serde(default)]
+    pub payload: serde_json::Value,
+    /// Transport and routing headers
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Id shared by all events of one business flow
+    #[serde(default)]
+    pub correlation_id: Option<String>,
+    /// Id of the event that caused this one
+    #[serde(default)]
+    pub causation_id: Option<EventId>,
+    /// Aggregate the event belongs to, for domain events
+    #[serde(default)]
+    pub aggregate_id: Option<String>,
+    /// Position of the event within its aggregate
+    #[serde(default)]
+    pub sequence_number: u64,
+    /// Version of the payload schema
+    #[serde(default = "EventEnvelope::default_schema_version")]
+    pub schema_version: u32,
+}
+
+impl EventEnvelope {
+    /// Schema version assigned to new envelopes.
+    pub const DEFAULT_SCHEMA_VERSION: u32 = 1;
+
+    fn default_schema_version() -> u32 {
+        Self::DEFAULT_SCHEMA_VERSION
+    }
+
+    /// Create an envelope with a fresh id, the current time and no domain metadata.
+    pub fn new(event_type: impl Into<String>, payload: serde_json::Value) -> Self {
+        Self {
+            id: EventId::new(),
+            event_type: event_type.into(),
+            timestamp: Utc::now(),
+            payload,
+            headers: BTreeMap::new(),
+            correlation_id: None,
+            causation_id: None,
+            aggregate_id: None,
+            sequence_number: 0,
+            schema_version: Self::DEFAULT_SCHEMA_VERSION,
+        }
+    }
+
+    /// Capture any [`Event`] into an envelope, keeping its id, type, timestamp,
+    /// payload and schema version.
+    pub fn from_event<E: Event + ?Sized>(event: &E) -> Self {
+        Self {
+            id: event.event_id().clone(),
+            timestamp: event.timestamp(),
+            schema_version: event.schema_version(),
+            ..Self::new(event.event_type(), event.payload())
+        }
+    }
+
+    /// Capture a [`DomainEvent`] into an envelope, additionally keeping its
+    /// aggregate id, sequence number and correlation id.
+    pub fn from_domain_event<E: DomainEvent + ?Sized>(event: &E) -> Self {
+        Self {
+            timestamp: event.occurred_at(),
+            aggregate_id: Some(event.aggregate_id().to_string()),
+            sequence_number: event.sequence_number(),
+            correlation_id: Some(event.correlation_id().to_string()),
+            ..Self::from_event(event)
+        }
+    }
+
+    /// Create an envelope caused by `cause`, inheriting its correlation id.
+    pub fn caused_by(
+        cause: &EventEnvelope,
+        event_type: impl Into<String>,
+        payload: serde_json::Value,
+    ) -> Self {
+        Self {
+            causation_id: Some(cause.id.clone()),
+            correlation_id: cause.correlation_id.clone(),
+            ..Self::new(event_type, payload)
+        }
+    }
+
+    /// Set the event id.
+    pub fn with_id(mut self, id: EventId) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Set the event timestamp.
+    pub fn with_timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Add a header. Later values overwrite earlier ones.
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the correlation id.
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = Some(correlation_id.into());
+        self
+    }
+
+    /// Set the causation id.
+    pub fn with_causation_id(mut self, causation_id: EventId) -> Self {
+        self.causation_id = Some(causation_id);
+        self
+    }
+
+    /// Set the aggregate id and the event's sequence number within it.
+    pub fn with_aggregate(mut self, aggregate_id: impl Into<String>, sequence_number: u64) -> Self {
+        self.aggregate_id = Some(aggregate_id.into());
+        self.sequence_number = sequence_number;
+        self
+    }
+
+    /// Set the payload schema version.
+    pub fn with_schema_version(mut self, schema_version: u32) -> Self {
+        self.schema_version = schema_version;
+        self
+    }
+
+    /// Header value for `key`, if present.
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers.get(key).map(String::as_str)
+    }
+}
+
+impl Event for EventEnvelope {
+    fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    fn event_id(&self) -> &EventId {
+        &self.id
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        self.payload.clone()
+    }
+
+    fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+}
+
+/// Envelopes without domain metadata report an empty aggregate id and
+/// correlation id.
+impl DomainEvent for EventEnvelope {
+    fn aggregate_id(&self) -> &str {
+        self.aggregate_id.as_deref().unwrap_or_default()
+    }
+
+    fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    fn occurred_at(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn correlation_id(&self) -> &str {
+        self.correlation_id.as_deref().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct Renamed {
+        id: EventId,
+        occurred_at: DateTime<Utc>,
+    }
+
+    impl Event for Renamed {
+        fn event_type(&self) -> &'static str {
+            "user.renamed"
+        }
+        fn event_id(&self) -> &EventId {
+            &self.id
+        }
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.occurred_at
+        }
+        fn payload(&self) -> serde_json::Value {
+            json!({ "name": "Ada" })
+        }
+        fn schema_version(&self) -> u32 {
+            2
+        }
+    }
+
+    impl DomainEvent for Renamed {
+        fn aggregate_id(&self) -> &str {
+            "user-1"
+        }
+        fn sequence_number(&self) -> u64 {
+            4
+        }
+        fn occurred_at(&self) -> DateTime<Utc> {
+            self.occurred_at
+        }
+        fn correlation_id(&self) -> &str {
+            "corr-9"
+        }
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let envelope = EventEnvelope::new("order.placed", json!({ "total": 42 }))
+            .with_aggregate("order-1", 2)
+            .with_correlation_id("checkout-1")
+            .with_causation_id(EventId::new())
+            .with_header("source", "test")
+            .with_schema_version(3);
+
+        let json = serde_json::to_value(&envelope).unwrap();
+        assert_eq!(json["event_type"], "order.placed");
+        assert_eq!(json["schema_version"], 3);
+        let parsed: EventEnvelope = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, envelope);
+    }
+
+    #[test]
+    fn test_optional_fields_default_when_missing() {
+        let parsed: EventEnvelope = serde_json::from_value(json!({
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "event_type": "config.loaded",
+            "timestamp": "2025-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        assert_eq!(parsed.payload, serde_json::Value::Null);
+        assert!(parsed.headers.is_empty());
+        assert_eq!(parsed.aggregate_id(), "");
+        assert_eq!(parsed.correlation_id(), "");
+        assert_eq!(parsed.sequence_number(), 0);
+        assert_eq!(parsed.schema_version, EventEnvelope::DEFAULT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_from_domain_event_keeps_metadata() {
+        let event = Renamed {
+            id: EventId::new(),
+            occurred_at: Utc::now(),
+        };
+        let envelope = EventEnvelope::from_domain_event(&event);
+        assert_eq!(envelope.event_id(), event.event_id());
+        assert_eq!(envelope.event_type(), "user.renamed");
+        assert_eq!(envelope.payload(), json!({ "name": "Ada" }));
+        assert_eq!(envelope.aggregate_id(), "user-1");
+        assert_eq!(envelope.sequence_number(), 4);
+        assert_eq!(envelope.correlation_id(), "corr-9");
+        assert_eq!(envelope.occurred_at(), event.occurred_at);
+        assert_eq!(envelope.schema_version(), 2);
+
+        let plain = EventEnvelope::from_event(&event);
+        assert!(plain.aggregate_id.is_none());
+        assert_eq!(plain.timestamp(), event.occurred_at);
+    }
+
+    #[test]
+    fn test_caused_by_links_events() {
+        let cause = EventEnvelope::new("order.placed", json!({})).with_correlation_id("flow-1");
+        let effect = EventEnvelope::caused_by(&cause, "invoice.created", json!({}));
+        assert_eq!(effect.causation_id.as_ref(), Some(&cause.id));
+        assert_eq!(effect.correlation_id(), "flow-1");
+        assert_ne!(effect.id, cause.id);
+    }
+}
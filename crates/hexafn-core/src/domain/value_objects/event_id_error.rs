@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # EventId Errors (Core Module)
+//!
+//! This module defines [`EventIdError`], raised when an [`EventId`](crate::EventId)
+//! cannot be parsed from one of its text forms.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventId, HexaError};
+//!
+//! let error = EventId::from_ulid("not-a-ulid").unwrap_err();
+//! assert_eq!(error.error_code(), "core.event_id.invalid_ulid");
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use thiserror::Error;
+
+/// Errors produced while parsing event ids.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EventIdError {
+    /// The text is not a valid ULID.
+    #[error("invalid ULID '{value}': {reason}")]
+    InvalidUlid {
+        /// The rejected text
+        value: String,
+        /// Why it was rejected
+        reason: String,
+    },
+}
+
+impl HexaError for EventIdError {
+    fn error_code(&self) -> &str {
+        match self {
+            EventIdError::InvalidUlid { .. } => "core.event_id.invalid_ulid",
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            EventIdError::InvalidUlid { .. } => "Event id is not a valid ULID",
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        HexaErrorKind::Validation
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        HexaErrorSeverity::Medium
+    }
+}
@@ -0,0 +1,175 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # EventPattern (Core Module)
+//!
+//! This module defines [`EventPattern`], a dot-separated pattern matched against
+//! [`Event::event_type`](crate::Event::event_type) when subscribing to the
+//! [`EventBus`](crate::EventBus).
+//!
+//! ## Syntax
+//!
+//! - `user.created` matches exactly `user.created`
+//! - `*` matches exactly one segment: `user.*` matches `user.created` but not
+//!   `user.profile.updated`
+//! - `**` matches zero or more segments: `trigger.**` matches `trigger`,
+//!   `trigger.fired` and `trigger.rule.matched`; `**` alone matches every event
+//!
+//! Wildcards must occupy a whole segment; `user*.created` is rejected.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::EventPattern;
+//!
+//! let pattern = EventPattern::parse("store.*.written").unwrap();
+//! assert!(pattern.matches("store.kv.written"));
+//! assert!(!pattern.matches("store.kv.deleted"));
+//!
+//! assert!(EventPattern::parse("**").unwrap().matches("any.event.type"));
+//! assert!(EventPattern::parse("user..created").is_err());
+//! ```
+
+use crate::domain::value_objects::EventBusError;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    One,
+    Many,
+}
+
+/// Parsed event type pattern with `*` and `**` wildcards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventPattern {
+    pattern: String,
+    segments: Vec<Segment>,
+}
+
+impl EventPattern {
+    /// Parse a pattern.
+    ///
+    /// # Errors
+    ///
+    /// [`EventBusError::InvalidPattern`] if the pattern is empty, has an empty
+    /// segment, or uses a wildcard inside a segment.
+    pub fn parse(pattern: &str) -> Result<Self, EventBusError> {
+        let invalid = |reason: &str| EventBusError::InvalidPattern {
+            pattern: pattern.to_string(),
+            reason: reason.to_string(),
+        };
+        let segments = pattern
+            .split('.')
+            .map(|segment| match segment {
+                "" => Err(invalid("empty segment")),
+                "*" => Ok(Segment::One),
+                "**" => Ok(Segment::Many),
+                literal if literal.contains('*') => {
+                    Err(invalid("wildcards must span a whole segment"))
+                }
+                literal => Ok(Segment::Literal(literal.to_string())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            pattern: pattern.to_string(),
+            segments,
+        })
+    }
+
+    /// The pattern as written.
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Whether the pattern contains no wildcards.
+    pub fn is_exact(&self) -> bool {
+        self.segments
+            .iter()
+            .all(|segment| matches!(segment, Segment::Literal(_)))
+    }
+
+    /// Whether `event_type` matches this pattern.
+    pub fn matches(&self, event_type: &str) -> bool {
+        let parts: Vec<&str> = event_type.split('.').collect();
+        Self::matches_from(&self.segments, &parts)
+    }
+
+    fn matches_from(segments: &[Segment], parts: &[&str]) -> bool {
+        match segments.split_first() {
+            None => parts.is_empty(),
+            Some((Segment::Many, rest)) => {
+                (0..=parts.len()).any(|skip| Self::matches_from(rest, &parts[skip..]))
+            }
+            Some((segment, rest)) => match parts.split_first() {
+                None => false,
+                Some((part, remaining)) => {
+                    let matched = match segment {
+                        Segment::Literal(literal) => literal == part,
+                        _ => true,
+                    };
+                    matched && Self::matches_from(rest, remaining)
+                }
+            },
+        }
+    }
+}
+
+impl Display for EventPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, event_type: &str) -> bool {
+        EventPattern::parse(pattern).unwrap().matches(event_type)
+    }
+
+    #[test]
+    fn test_exact_pattern() {
+        assert!(matches("user.created", "user.created"));
+        assert!(!matches("user.created", "user.deleted"));
+        assert!(!matches("user.created", "user.created.v2"));
+        assert!(EventPattern::parse("user.created").unwrap().is_exact());
+    }
+
+    #[test]
+    fn test_single_segment_wildcard() {
+        assert!(matches("user.*", "user.created"));
+        assert!(!matches("user.*", "user"));
+        assert!(!matches("user.*", "user.profile.updated"));
+        assert!(matches("*.created", "order.created"));
+        assert!(!EventPattern::parse("user.*").unwrap().is_exact());
+    }
+
+    #[test]
+    fn test_multi_segment_wildcard() {
+        assert!(matches("**", "anything"));
+        assert!(matches("**", "a.b.c"));
+        assert!(matches("trigger.**", "trigger"));
+        assert!(matches("trigger.**", "trigger.rule.matched"));
+        assert!(matches("**.failed", "run.function.failed"));
+        assert!(matches("store.**.written", "store.written"));
+        assert!(matches("store.**.written", "store.kv.bucket.written"));
+        assert!(!matches("store.**.written", "store.kv.deleted"));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        for pattern in [
+            "",
+            "user.",
+            ".user",
+            "user..created",
+            "user*.created",
+            "***",
+        ] {
+            let error = EventPattern::parse(pattern).unwrap_err();
+            assert!(matches!(error, EventBusError::InvalidPattern { .. }));
+        }
+    }
+}
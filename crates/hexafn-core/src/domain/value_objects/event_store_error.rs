@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Event Store Errors (Core Module)
+//!
+//! This module defines [`EventStoreError`], the concrete [`HexaError`] raised by
+//! [`EventStore`](crate::EventStore) backends when an append or read fails.
+//!
+//! All variants use hierarchical `core.event_store.*` error codes.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventStoreError, HexaError, HexaErrorKind};
+//!
+//! let error = EventStoreError::ConcurrencyConflict {
+//!     aggregate_id: "order-1".to_string(),
+//!     expected: 3,
+//!     actual: 4,
+//! };
+//! assert_eq!(error.error_code(), "core.event_store.concurrency_conflict");
+//! assert_eq!(error.error_kind(), HexaErrorKind::Conflict);
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use thiserror::Error;
+
+/// Errors produced by event store backends.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EventStoreError {
+    /// The stream advanced past the version the caller expected.
+    #[error("stream '{aggregate_id}' is at version {actual}, expected {expected}")]
+    ConcurrencyConflict {
+        /// Stream being appended to
+        aggregate_id: String,
+        /// Version the caller expected
+        expected: u64,
+        /// Current version of the stream
+        actual: u64,
+    },
+
+    /// An event names a different aggregate than the stream it is appended to.
+    #[error("event for aggregate '{actual}' cannot be appended to stream '{expected}'")]
+    AggregateMismatch {
+        /// Stream being appended to
+        expected: String,
+        /// Aggregate id carried by the event
+        actual: String,
+    },
+
+    /// Stored data cannot be read back.
+    #[error("stream '{aggregate_id}' is corrupted at line {line}: {reason}")]
+    Corrupted {
+        /// Affected stream
+        aggregate_id: String,
+        /// 1-based line number of the bad record
+        line: usize,
+        /// Underlying decoding error
+        reason: String,
+    },
+}
+
+impl HexaError for EventStoreError {
+    fn error_code(&self) -> &str {
+        match self {
+            EventStoreError::ConcurrencyConflict { .. } => "core.event_store.concurrency_conflict",
+            EventStoreError::AggregateMismatch { .. } => "core.event_store.aggregate_mismatch",
+            EventStoreError::Corrupted { .. } => "core.event_store.corrupted",
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            EventStoreError::ConcurrencyConflict { .. } => "Event stream was modified concurrently",
+            EventStoreError::AggregateMismatch { .. } => "Event belongs to a different aggregate",
+            EventStoreError::Corrupted { .. } => "Event stream is corrupted",
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        match self {
+            EventStoreError::ConcurrencyConflict { .. } => HexaErrorKind::Conflict,
+            EventStoreError::AggregateMismatch { .. } => HexaErrorKind::Validation,
+            EventStoreError::Corrupted { .. } => HexaErrorKind::Serialization,
+        }
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        match self {
+            EventStoreError::ConcurrencyConflict { .. } => HexaErrorSeverity::Medium,
+            EventStoreError::AggregateMismatch { .. } => HexaErrorSeverity::High,
+            EventStoreError::Corrupted { .. } => HexaErrorSeverity::Critical,
+        }
+    }
+}
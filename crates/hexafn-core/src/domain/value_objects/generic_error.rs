@@ -0,0 +1,458 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # GenericHexaError (Core Module)
+//!
+//! This module provides [`GenericHexaError`], a ready-made [`HexaError`] for code
+//! that does not need a dedicated error enum, and [`HexaErrorBuilder`] for
+//! assembling one fluently.
+//!
+//! A generic error carries a hierarchical code, a message, a kind, a severity, an
+//! optional source error chain, and structured key/value context. Conversions from
+//! common library errors map each to a sensible [`HexaErrorKind`]:
+//!
+//! | Source                                | Code                         | Kind                             |
+//! |---------------------------------------|------------------------------|----------------------------------|
+//! | `std::io::Error` (not found)          | `core.io.not_found`          | `NotFound`                       |
+//! | `std::io::Error` (timed out)          | `core.io.timeout`            | `Timeout`                        |
+//! | `std::io::Error` (invalid input)      | `core.io.invalid`            | `Validation`                     |
+//! | `std::io::Error` (permission denied)  | `core.io.permission_denied`  | `Unauthorized`                   |
+//! | `std::io::Error` (already exists)     | `core.io.already_exists`     | `Conflict`                       |
+//! | `std::io::Error` (connection refused) | `core.io.unavailable`        | `Unavailable`                    |
+//! | `std::io::Error` (interrupted)        | `core.io.interrupted`        | `Cancelled`                      |
+//! | `std::io::Error` (other)              | `core.io.failure`            | `External`                       |
+//! | `serde_json::Error`                   | `core.json.invalid`          | `Serialization` (`External` I/O) |
+//! | `uuid::Error`                         | `core.uuid.invalid`          | `Validation`                     |
+//! | `tokio::time::error::Elapsed`         | `core.timeout.elapsed`       | `Timeout`                        |
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{GenericHexaError, HexaError, HexaErrorKind, HexaErrorSeverity};
+//!
+//! let io = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused");
+//! let error = GenericHexaError::builder("bridge.webhook.unreachable", "Webhook target is unreachable")
+//!     .kind(HexaErrorKind::External)
+//!     .severity(HexaErrorSeverity::High)
+//!     .context("url", "https://example.com/hook")
+//!     .source(io)
+//!     .build();
+//!
+//! assert_eq!(error.error_code(), "bridge.webhook.unreachable");
+//! assert_eq!(error.context_value("url"), Some("https://example.com/hook"));
+//! assert_eq!(error.sources().count(), 1);
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use std::collections::BTreeMap;
+use std::error::Error as StdError;
+use std::fmt::{Debug, Display, Formatter};
+
+type BoxedSource = Box<dyn StdError + Send + Sync + 'static>;
+
+/// General-purpose [`HexaError`] with source chain and structured context.
+#[derive(Debug)]
+pub struct GenericHexaError {
+    code: String,
+    message: String,
+    kind: HexaErrorKind,
+    severity: HexaErrorSeverity,
+    source: Option<BoxedSource>,
+    context: BTreeMap<String, String>,
+}
+
+impl GenericHexaError {
+    /// Create an error with [`HexaErrorKind::Unknown`] and [`HexaErrorSeverity::Medium`].
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            kind: HexaErrorKind::Unknown,
+            severity: HexaErrorSeverity::Medium,
+            source: None,
+            context: BTreeMap::new(),
+        }
+    }
+
+    /// Start building an error with the given code and message.
+    pub fn builder(code: impl Into<String>, message: impl Into<String>) -> HexaErrorBuilder {
+        HexaErrorBuilder::new(code, message)
+    }
+
+    /// Structured key/value context attached to the error.
+    pub fn context(&self) -> &BTreeMap<String, String> {
+        &self.context
+    }
+
+    /// Context value for `key`, if present.
+    pub fn context_value(&self, key: &str) -> Option<&str> {
+        self.context.get(key).map(String::as_str)
+    }
+
+    /// Iterate over the source chain, starting with the direct source.
+    pub fn sources(&self) -> impl Iterator<Item = &(dyn StdError + 'static)> {
+        let first: Option<&(dyn StdError + 'static)> = self
+            .source
+            .as_deref()
+            .map(|source| source as &(dyn StdError + 'static));
+        std::iter::successors(first, |error| (*error).source())
+    }
+}
+
+impl Display for GenericHexaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl StdError for GenericHexaError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn StdError + 'static))
+    }
+}
+
+impl HexaError for GenericHexaError {
+    fn error_code(&self) -> &str {
+        &self.code
+    }
+
+    fn error_message(&self) -> &str {
+        &self.message
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        self.kind
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        self.severity
+    }
+
+    fn error_source(&self) -> Option<&(dyn StdError + 'static)> {
+        StdError::source(self)
+    }
+
+    fn error_context(&self) -> BTreeMap<String, String> {
+        self.context.clone()
+    }
+}
+
+/// Adapts a boxed [`HexaError`] so it can sit in a standard error source chain.
+struct HexaSource(Box<dyn HexaError>);
+
+impl Debug for HexaSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for HexaSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.0.error_code(), self.0)
+    }
+}
+
+impl StdError for HexaSource {}
+
+/// Fluent builder for [`GenericHexaError`].
+#[derive(Debug)]
+pub struct HexaErrorBuilder {
+    error: GenericHexaError,
+}
+
+impl HexaErrorBuilder {
+    /// Start a builder with the given code and message.
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            error: GenericHexaError::new(code, message),
+        }
+    }
+
+    /// Set the error kind.
+    pub fn kind(mut self, kind: HexaErrorKind) -> Self {
+        self.error.kind = kind;
+        self
+    }
+
+    /// Set the error severity.
+    pub fn severity(mut self, severity: HexaErrorSeverity) -> Self {
+        self.error.severity = severity;
+        self
+    }
+
+    /// Attach the underlying cause.
+    pub fn source(mut self, source: impl StdError + Send + Sync + 'static) -> Self {
+        self.error.source = Some(Box::new(source));
+        self
+    }
+
+    /// Attach another [`HexaError`] as the underlying cause.
+    pub fn hexa_source(mut self, source: Box<dyn HexaError>) -> Self {
+        self.error.source = Some(Box::new(HexaSource(source)));
+        self
+    }
+
+    /// Add a structured context entry. Later entries overwrite earlier ones.
+    pub fn context(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.error.context.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// Finish building the error.
+    pub fn build(self) -> GenericHexaError {
+        self.error
+    }
+}
+
+impl From<std::io::Error> for GenericHexaError {
+    fn from(error: std::io::Error) -> Self {
+        use std::io::ErrorKind;
+
+        let (code, kind) = match error.kind() {
+            ErrorKind::NotFound => ("core.io.not_found", HexaErrorKind::NotFound),
+            ErrorKind::TimedOut | ErrorKind::WouldBlock => {
+                ("core.io.timeout", HexaErrorKind::Timeout)
+            }
+            ErrorKind::InvalidInput | ErrorKind::InvalidData | ErrorKind::UnexpectedEof => {
+                ("core.io.invalid", HexaErrorKind::Validation)
+            }
+            ErrorKind::PermissionDenied => {
+                ("core.io.permission_denied", HexaErrorKind::Unauthorized)
+            }
+            ErrorKind::AlreadyExists => ("core.io.already_exists", HexaErrorKind::Conflict),
+            ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::AddrNotAvailable
+            | ErrorKind::BrokenPipe => ("core.io.unavailable", HexaErrorKind::Unavailable),
+            ErrorKind::Interrupted => ("core.io.interrupted", HexaErrorKind::Cancelled),
+            _ => ("core.io.failure", HexaErrorKind::External),
+        };
+        HexaErrorBuilder::new(code, error.to_string())
+            .kind(kind)
+            .context("io_kind", error.kind())
+            .source(error)
+            .build()
+    }
+}
+
+impl From<serde_json::Error> for GenericHexaError {
+    fn from(error: serde_json::Error) -> Self {
+        use serde_json::error::Category;
+
+        let (code, kind) = match error.classify() {
+            Category::Io => ("core.json.io", HexaErrorKind::External),
+            Category::Syntax | Category::Data | Category::Eof => {
+                ("core.json.invalid", HexaErrorKind::Serialization)
+            }
+        };
+        let mut builder = HexaErrorBuilder::new(code, error.to_string()).kind(kind);
+        if error.line() > 0 {
+            builder = builder
+                .context("line", error.line())
+                .context("column", error.column());
+        }
+        builder.source(error).build()
+    }
+}
+
+impl From<uuid::Error> for GenericHexaError {
+    fn from(error: uuid::Error) -> Self {
+        HexaErrorBuilder::new("core.uuid.invalid", error.to_string())
+            .kind(HexaErrorKind::Validation)
+            .source(error)
+            .build()
+    }
+}
+
+impl From<tokio::time::error::Elapsed> for GenericHexaError {
+    fn from(error: tokio::time::error::Elapsed) -> Self {
+        HexaErrorBuilder::new("core.timeout.elapsed", "operation timed out")
+            .kind(HexaErrorKind::Timeout)
+            .source(error)
+            .build()
+    }
+}
+
+macro_rules! box_via_generic {
+    ($($source:ty),+ $(,)?) => {
+        $(
+            impl From<$source> for Box<dyn HexaError> {
+                fn from(error: $source) -> Self {
+                    Box::new(GenericHexaError::from(error))
+                }
+            }
+        )+
+    };
+}
+
+box_via_generic!(
+    std::io::Error,
+    serde_json::Error,
+    uuid::Error,
+    tokio::time::error::Elapsed,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::PipelineError;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn test_builder_defaults_and_overrides() {
+        let error = GenericHexaError::new("core.test.default", "default");
+        assert_eq!(error.error_kind(), HexaErrorKind::Unknown);
+        assert_eq!(error.error_severity(), HexaErrorSeverity::Medium);
+        assert!(error.context().is_empty());
+        assert!(StdError::source(&error).is_none());
+
+        let error = GenericHexaError::builder("store.kv.write_failed", "Write failed")
+            .kind(HexaErrorKind::External)
+            .severity(HexaErrorSeverity::Critical)
+            .context("key", "users/1")
+            .context("attempt", 3)
+            .build();
+        assert_eq!(
+            error.to_log_entry(),
+            "[store.kv.write_failed] [External Critical] Write failed"
+        );
+        assert_eq!(error.context_value("attempt"), Some("3"));
+        assert_eq!(error.to_string(), "Write failed");
+    }
+
+    #[test]
+    fn test_source_chain_is_walkable() {
+        let inner = GenericHexaError::builder("core.io.failure", "disk full")
+            .source(std::io::Error::other("ENOSPC"))
+            .build();
+        let outer = GenericHexaError::builder("store.kv.flush_failed", "Flush failed")
+            .source(inner)
+            .build();
+
+        let chain: Vec<String> = outer.sources().map(|e| e.to_string()).collect();
+        assert_eq!(chain, vec!["disk full", "ENOSPC"]);
+    }
+
+    #[test]
+    fn test_report_includes_causes_and_context() {
+        let error = GenericHexaError::builder("store.kv.flush_failed", "Flush failed")
+            .kind(HexaErrorKind::External)
+            .context("bucket", "users")
+            .source(std::io::Error::other("disk full"))
+            .build();
+
+        let report = error.to_report();
+        assert_eq!(report.code, "store.kv.flush_failed");
+        assert_eq!(report.causes, vec!["disk full"]);
+        assert_eq!(
+            report.context.get("bucket").map(String::as_str),
+            Some("users")
+        );
+    }
+
+    #[test]
+    fn test_hexa_source_keeps_code() {
+        let error = GenericHexaError::builder("core.run.failed", "Run failed")
+            .hexa_source(Box::new(PipelineError::EmptyPipeline))
+            .build();
+        let source = StdError::source(&error).unwrap();
+        assert_eq!(
+            source.to_string(),
+            "[core.pipeline.empty] pipeline has no stages"
+        );
+    }
+
+    #[test]
+    fn test_io_error_conversion() {
+        let cases = [
+            (
+                ErrorKind::NotFound,
+                "core.io.not_found",
+                HexaErrorKind::NotFound,
+            ),
+            (
+                ErrorKind::TimedOut,
+                "core.io.timeout",
+                HexaErrorKind::Timeout,
+            ),
+            (
+                ErrorKind::InvalidData,
+                "core.io.invalid",
+                HexaErrorKind::Validation,
+            ),
+            (
+                ErrorKind::PermissionDenied,
+                "core.io.permission_denied",
+                HexaErrorKind::Unauthorized,
+            ),
+            (
+                ErrorKind::AlreadyExists,
+                "core.io.already_exists",
+                HexaErrorKind::Conflict,
+            ),
+            (
+                ErrorKind::ConnectionReset,
+                "core.io.unavailable",
+                HexaErrorKind::Unavailable,
+            ),
+            (
+                ErrorKind::Interrupted,
+                "core.io.interrupted",
+                HexaErrorKind::Cancelled,
+            ),
+            (ErrorKind::Other, "core.io.failure", HexaErrorKind::External),
+        ];
+        for (io_kind, code, kind) in cases {
+            let error = GenericHexaError::from(std::io::Error::new(io_kind, "boom"));
+            assert_eq!(error.error_code(), code);
+            assert_eq!(error.error_kind(), kind);
+            assert!(error.context_value("io_kind").is_some());
+        }
+    }
+
+    #[test]
+    fn test_serde_json_error_conversion() {
+        let json_error = serde_json::from_str::<serde_json::Value>("{ invalid").unwrap_err();
+        let error = GenericHexaError::from(json_error);
+        assert_eq!(error.error_code(), "core.json.invalid");
+        assert_eq!(error.error_kind(), HexaErrorKind::Serialization);
+        assert_eq!(error.context_value("line"), Some("1"));
+    }
+
+    #[test]
+    fn test_uuid_error_conversion() {
+        let error = GenericHexaError::from(uuid::Uuid::parse_str("not-a-uuid").unwrap_err());
+        assert_eq!(error.error_code(), "core.uuid.invalid");
+        assert_eq!(error.error_kind(), HexaErrorKind::Validation);
+    }
+
+    #[tokio::test]
+    async fn test_elapsed_conversion() {
+        let elapsed = tokio::time::timeout(
+            std::time::Duration::from_millis(1),
+            std::future::pending::<()>(),
+        )
+        .await
+        .unwrap_err();
+        let error = GenericHexaError::from(elapsed);
+        assert_eq!(error.error_code(), "core.timeout.elapsed");
+        assert_eq!(error.error_kind(), HexaErrorKind::Timeout);
+    }
+
+    #[test]
+    fn test_question_mark_into_boxed_hexa_error() {
+        fn parse(input: &str) -> Result<serde_json::Value, Box<dyn HexaError>> {
+            Ok(serde_json::from_str(input)?)
+        }
+        fn read() -> Result<(), Box<dyn HexaError>> {
+            Err(std::io::Error::new(ErrorKind::NotFound, "missing"))?
+        }
+
+        assert_eq!(parse("[").unwrap_err().error_code(), "core.json.invalid");
+        assert_eq!(read().unwrap_err().error_kind(), HexaErrorKind::NotFound);
+    }
+}
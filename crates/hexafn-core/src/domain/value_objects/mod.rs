@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod context_metadata;
+mod error_code;
+mod error_report;
+mod event_bus_error;
+mod event_envelope;
+mod event_id_error;
+mod event_pattern;
+mod event_store_error;
+mod generic_error;
+mod param_schema;
+mod pipeline_error;
+mod pipeline_run;
+mod retry_policy;
+mod stage_descriptor;
+mod upcast_error;
+
+pub use context_metadata::{ContextMetadata, WriteRecord};
+pub use error_code::{ErrorCode, ErrorCodeError};
+pub use error_report::ErrorReport;
+pub use event_bus_error::EventBusError;
+pub use event_envelope::EventEnvelope;
+pub use event_id_error::EventIdError;
+pub use event_pattern::EventPattern;
+pub use event_store_error::EventStoreError;
+pub use generic_error::{GenericHexaError, HexaErrorBuilder};
+pub use param_schema::{ParamKind, ParamSchema, ParamSpec};
+pub use pipeline_error::PipelineError;
+pub use pipeline_run::{PipelineRun, PipelineRunStatus};
+pub use retry_policy::{Backoff, RetryPolicy};
+pub use stage_descriptor::StageDescriptor;
+pub use upcast_error::UpcastError;
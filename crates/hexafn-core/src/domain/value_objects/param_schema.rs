@@ -0,0 +1,284 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # ParamSchema (Core Module)
+//!
+//! This module defines [`ParamSchema`], [`ParamSpec`] and [`ParamKind`], a small
+//! declarative schema for the parameters a configurable pipeline stage accepts.
+//!
+//! Parameters are a JSON object (`null` counts as an empty object). The schema
+//! checks that required parameters are present, that every value has the
+//! declared kind, and, unless [`ParamSchema::allow_unknown`] is set, that no
+//! undeclared parameter is given. Optional parameters may carry a default that
+//! [`ParamSchema::apply_defaults`] fills in.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{ParamKind, ParamSchema, ParamSpec};
+//! use serde_json::json;
+//!
+//! let schema = ParamSchema::new()
+//!     .with_param(ParamSpec::required("url", ParamKind::String).with_description("Endpoint to poll"))
+//!     .with_param(ParamSpec::optional("interval_ms", ParamKind::Integer).with_default(json!(1000)));
+//!
+//! assert!(schema.violations(&json!({ "url": "https://example.com" })).is_empty());
+//! assert_eq!(
+//!     schema.violations(&json!({ "interval_ms": "fast" })),
+//!     vec![
+//!         "missing required parameter 'url'".to_string(),
+//!         "parameter 'interval_ms' must be an integer".to_string(),
+//!     ]
+//! );
+//!
+//! let params = schema.apply_defaults(json!({ "url": "https://example.com" }));
+//! assert_eq!(params["interval_ms"], 1000);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt::{Display, Formatter};
+
+/// JSON kind a parameter value must have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamKind {
+    /// A JSON string
+    String,
+    /// A JSON number without a fractional part
+    Integer,
+    /// Any JSON number
+    Number,
+    /// `true` or `false`
+    Boolean,
+    /// A JSON array
+    Array,
+    /// A JSON object
+    Object,
+    /// Any value, including `null`
+    Any,
+}
+
+impl ParamKind {
+    /// Whether `value` has this kind.
+    pub fn matches(&self, value: &Value) -> bool {
+        match self {
+            ParamKind::String => value.is_string(),
+            ParamKind::Integer => value.is_i64() || value.is_u64(),
+            ParamKind::Number => value.is_number(),
+            ParamKind::Boolean => value.is_boolean(),
+            ParamKind::Array => value.is_array(),
+            ParamKind::Object => value.is_object(),
+            ParamKind::Any => true,
+        }
+    }
+}
+
+impl Display for ParamKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ParamKind::String => "a string",
+            ParamKind::Integer => "an integer",
+            ParamKind::Number => "a number",
+            ParamKind::Boolean => "a boolean",
+            ParamKind::Array => "an array",
+            ParamKind::Object => "an object",
+            ParamKind::Any => "any value",
+        })
+    }
+}
+
+/// Declaration of one stage parameter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParamSpec {
+    /// Parameter name
+    pub name: String,
+    /// Required JSON kind
+    pub kind: ParamKind,
+    /// Whether the parameter must be given
+    pub required: bool,
+    /// Human-readable description, for tooling
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Value used when an optional parameter is absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+}
+
+impl ParamSpec {
+    /// Declare a parameter that must be given.
+    pub fn required(name: impl Into<String>, kind: ParamKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            required: true,
+            description: None,
+            default: None,
+        }
+    }
+
+    /// Declare a parameter that may be omitted.
+    pub fn optional(name: impl Into<String>, kind: ParamKind) -> Self {
+        Self {
+            required: false,
+            ..Self::required(name, kind)
+        }
+    }
+
+    /// Attach a description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Use `default` when the parameter is absent.
+    pub fn with_default(mut self, default: Value) -> Self {
+        self.default = Some(default);
+        self
+    }
+}
+
+/// Declared parameters of a configurable stage.
+///
+/// The default schema declares no parameters and rejects any that are given.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParamSchema {
+    /// Declared parameters, in declaration order
+    #[serde(default)]
+    pub params: Vec<ParamSpec>,
+    /// Whether undeclared parameters are accepted
+    #[serde(default)]
+    pub allow_unknown: bool,
+}
+
+impl ParamSchema {
+    /// Create a schema declaring no parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a schema that accepts any parameters without checking them.
+    pub fn any() -> Self {
+        Self::new().allow_unknown()
+    }
+
+    /// Declare a parameter.
+    pub fn with_param(mut self, param: ParamSpec) -> Self {
+        self.params.push(param);
+        self
+    }
+
+    /// Accept parameters that are not declared.
+    pub fn allow_unknown(mut self) -> Self {
+        self.allow_unknown = true;
+        self
+    }
+
+    /// Look up a declared parameter.
+    pub fn param(&self, name: &str) -> Option<&ParamSpec> {
+        self.params.iter().find(|param| param.name == name)
+    }
+
+    /// Every way `params` breaks this schema, in a stable order; empty if valid.
+    pub fn violations(&self, params: &Value) -> Vec<String> {
+        let empty = Map::new();
+        let object = match params {
+            Value::Null => &empty,
+            Value::Object(object) => object,
+            _ => return vec!["parameters must be an object".to_string()],
+        };
+
+        let mut violations = Vec::new();
+        for spec in &self.params {
+            match object.get(&spec.name) {
+                None if spec.required => {
+                    violations.push(format!("missing required parameter '{}'", spec.name));
+                }
+                Some(value) if !spec.kind.matches(value) => {
+                    violations.push(format!("parameter '{}' must be {}", spec.name, spec.kind));
+                }
+                _ => {}
+            }
+        }
+        if !self.allow_unknown {
+            violations.extend(
+                object
+                    .keys()
+                    .filter(|key| self.param(key).is_none())
+                    .map(|key| format!("unknown parameter '{key}'")),
+            );
+        }
+        violations
+    }
+
+    /// Fill in the defaults of absent optional parameters.
+    ///
+    /// `null` becomes an object when any default applies; other non-object
+    /// values are returned unchanged.
+    pub fn apply_defaults(&self, params: Value) -> Value {
+        let mut object = match params {
+            Value::Null if self.params.iter().any(|p| p.default.is_some()) => Map::new(),
+            Value::Object(object) => object,
+            other => return other,
+        };
+        for spec in &self.params {
+            if let Some(default) = &spec.default {
+                object
+                    .entry(spec.name.clone())
+                    .or_insert_with(|| default.clone());
+            }
+        }
+        Value::Object(object)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_null_params_are_an_empty_object() {
+        let schema = ParamSchema::new().with_param(ParamSpec::optional("n", ParamKind::Integer));
+        assert!(schema.violations(&Value::Null).is_empty());
+        assert_eq!(
+            schema.violations(&json!([1])),
+            vec!["parameters must be an object".to_string()]
+        );
+        assert_eq!(schema.apply_defaults(Value::Null), Value::Null);
+    }
+
+    #[test]
+    fn test_unknown_params() {
+        let strict = ParamSchema::new();
+        assert_eq!(
+            strict.violations(&json!({ "extra": 1 })),
+            vec!["unknown parameter 'extra'".to_string()]
+        );
+        assert!(ParamSchema::any()
+            .violations(&json!({ "extra": 1 }))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_kinds() {
+        assert!(ParamKind::Integer.matches(&json!(-3)));
+        assert!(!ParamKind::Integer.matches(&json!(1.5)));
+        assert!(ParamKind::Number.matches(&json!(1.5)));
+        assert!(ParamKind::Any.matches(&Value::Null));
+        assert!(!ParamKind::Object.matches(&json!([])));
+    }
+
+    #[test]
+    fn test_schema_serializes_for_tooling() {
+        let schema = ParamSchema::new()
+            .with_param(ParamSpec::optional("retries", ParamKind::Integer).with_default(json!(2)));
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap(),
+            json!({
+                "params": [{ "name": "retries", "kind": "integer", "required": false, "default": 2 }],
+                "allow_unknown": false
+            })
+        );
+    }
+}
@@ -0,0 +1,335 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Pipeline Errors (Core Module)
+//!
+//! This module defines [`PipelineError`], the concrete [`HexaError`] raised by the
+//! core pipeline engine when a stage graph violates the 6F Lifecycle Flow:
+//! Feed → Filter → Format → Function → Forward → Feedback
+//!
+//! All variants use hierarchical `core.pipeline.*` error codes.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{HexaError, HexaErrorKind, PipelineError, PipelineStageType};
+//!
+//! let error = PipelineError::DuplicateStage {
+//!     stage_type: PipelineStageType::Filter,
+//! };
+//! assert_eq!(error.error_code(), "core.pipeline.duplicate_stage");
+//! assert_eq!(error.error_kind(), HexaErrorKind::Validation);
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity, PipelineStageType};
+use thiserror::Error;
+
+/// Errors produced while building, validating, or executing a 6F pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PipelineError {
+    /// The pipeline has no stages.
+    #[error("pipeline has no stages")]
+    EmptyPipeline,
+
+    /// A stage type required by every pipeline is absent.
+    #[error("pipeline is missing a required {stage_type:?} stage")]
+    MissingStage {
+        /// The missing stage type
+        stage_type: PipelineStageType,
+    },
+
+    /// More than one stage was registered for the same stage type.
+    #[error("pipeline already contains a {stage_type:?} stage")]
+    DuplicateStage {
+        /// The duplicated stage type
+        stage_type: PipelineStageType,
+    },
+
+    /// A stage was registered in a slot meant for a different stage type.
+    #[error("expected a {expected:?} stage but got a {actual:?} stage")]
+    StageTypeMismatch {
+        /// Stage type required by the slot
+        expected: PipelineStageType,
+        /// Stage type reported by the stage
+        actual: PipelineStageType,
+    },
+
+    /// Stage `get_order()` values contradict the 6F lifecycle sequence.
+    #[error(
+        "{stage_type:?} stage (order {order}) cannot run after {previous:?} stage (order {previous_order})"
+    )]
+    StageOrderViolation {
+        /// Stage that is out of place
+        stage_type: PipelineStageType,
+        /// Declared order of the out-of-place stage
+        order: u32,
+        /// Stage scheduled immediately before it
+        previous: PipelineStageType,
+        /// Declared order of the preceding stage
+        previous_order: u32,
+    },
+
+    /// A context value could not be converted to or from the requested type.
+    #[error("context key '{key}' has an unexpected type: {reason}")]
+    ContextTypeMismatch {
+        /// Context key being read or written
+        key: String,
+        /// Underlying serde error
+        reason: String,
+    },
+
+    /// A stage tried to write into another stage's context namespace.
+    #[error("{stage_type:?} stage cannot write context key '{key}' owned by another stage")]
+    NamespaceViolation {
+        /// Stage that attempted the write
+        stage_type: PipelineStageType,
+        /// Qualified key it tried to write
+        key: String,
+    },
+
+    /// A declared stage input is not produced by any earlier stage.
+    #[error("{stage_type:?} stage reads '{key}', which no earlier stage declares as output")]
+    UnsatisfiedInput {
+        /// Stage declaring the input
+        stage_type: PipelineStageType,
+        /// Qualified input key
+        key: String,
+    },
+
+    /// A stage ran longer than its own timeout and was cancelled.
+    #[error("{stage_type:?} stage exceeded its {timeout_ms}ms timeout")]
+    StageTimeout {
+        /// Stage that ran over
+        stage_type: PipelineStageType,
+        /// Timeout the stage declared, in milliseconds
+        timeout_ms: u64,
+    },
+
+    /// The pipeline-wide deadline expired while a stage was running or about to run.
+    #[error("pipeline deadline expired during {stage_type:?} stage")]
+    DeadlineExceeded {
+        /// Stage that was running when the deadline expired
+        stage_type: PipelineStageType,
+    },
+
+    /// A stage kept returning `Retry` after the engine's retry limit was reached.
+    #[error("{stage_type:?} stage requested a retry after {attempts} attempts")]
+    RetryExhausted {
+        /// Stage that requested the retry
+        stage_type: PipelineStageType,
+        /// Number of times the stage ran
+        attempts: u32,
+    },
+
+    /// No stage factory is registered under the requested name and stage type.
+    #[error("no {stage_type:?} stage factory is registered as '{name}'")]
+    UnknownFactory {
+        /// Requested stage type
+        stage_type: PipelineStageType,
+        /// Requested factory name
+        name: String,
+    },
+
+    /// A stage factory name was registered twice for the same stage type.
+    #[error("a {stage_type:?} stage factory is already registered as '{name}'")]
+    DuplicateFactory {
+        /// Stage type of the factory
+        stage_type: PipelineStageType,
+        /// Duplicated factory name
+        name: String,
+    },
+
+    /// Stage parameters do not match the schema the stage declares.
+    #[error("invalid parameters for stage '{name}': {reason}")]
+    InvalidParams {
+        /// Registered stage name
+        name: String,
+        /// Every schema violation, separated by `; `
+        reason: String,
+    },
+}
+
+impl HexaError for PipelineError {
+    fn error_code(&self) -> &str {
+        match self {
+            PipelineError::EmptyPipeline => "core.pipeline.empty",
+            PipelineError::MissingStage { .. } => "core.pipeline.missing_stage",
+            PipelineError::DuplicateStage { .. } => "core.pipeline.duplicate_stage",
+            PipelineError::StageTypeMismatch { .. } => "core.pipeline.stage_type_mismatch",
+            PipelineError::StageOrderViolation { .. } => "core.pipeline.stage_order_violation",
+            PipelineError::ContextTypeMismatch { .. } => "core.pipeline.context_type_mismatch",
+            PipelineError::NamespaceViolation { .. } => "core.pipeline.namespace_violation",
+            PipelineError::UnsatisfiedInput { .. } => "core.pipeline.unsatisfied_input",
+            PipelineError::StageTimeout { .. } => "core.pipeline.stage_timeout",
+            PipelineError::DeadlineExceeded { .. } => "core.pipeline.deadline_exceeded",
+            PipelineError::RetryExhausted { .. } => "core.pipeline.retry_exhausted",
+            PipelineError::UnknownFactory { .. } => "core.pipeline.unknown_factory",
+            PipelineError::DuplicateFactory { .. } => "core.pipeline.duplicate_factory",
+            PipelineError::InvalidParams { .. } => "core.pipeline.invalid_params",
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            PipelineError::EmptyPipeline => "Pipeline must contain at least one stage",
+            PipelineError::MissingStage { .. } => "Pipeline is missing a required stage",
+            PipelineError::DuplicateStage { .. } => {
+                "Each 6F stage type may appear only once in a pipeline"
+            }
+            PipelineError::StageTypeMismatch { .. } => {
+                "Stage type does not match the pipeline slot it was registered for"
+            }
+            PipelineError::StageOrderViolation { .. } => {
+                "Pipeline stages violate the 6F lifecycle order"
+            }
+            PipelineError::ContextTypeMismatch { .. } => {
+                "Pipeline context value does not match the requested type"
+            }
+            PipelineError::NamespaceViolation { .. } => {
+                "Stages may only write to their own context namespace"
+            }
+            PipelineError::UnsatisfiedInput { .. } => {
+                "Declared stage input is not produced by an earlier stage"
+            }
+            PipelineError::StageTimeout { .. } => "Pipeline stage exceeded its timeout",
+            PipelineError::DeadlineExceeded { .. } => "Pipeline deadline expired",
+            PipelineError::RetryExhausted { .. } => "Pipeline stage exhausted its retry limit",
+            PipelineError::UnknownFactory { .. } => {
+                "No stage factory is registered under that name"
+            }
+            PipelineError::DuplicateFactory { .. } => {
+                "Stage factory names must be unique per stage type"
+            }
+            PipelineError::InvalidParams { .. } => {
+                "Stage parameters do not match the declared schema"
+            }
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        match self {
+            PipelineError::StageTimeout { .. } | PipelineError::DeadlineExceeded { .. } => {
+                HexaErrorKind::Timeout
+            }
+            PipelineError::RetryExhausted { .. } => HexaErrorKind::Internal,
+            PipelineError::UnknownFactory { .. } => HexaErrorKind::NotFound,
+            PipelineError::DuplicateFactory { .. } => HexaErrorKind::Conflict,
+            _ => HexaErrorKind::Validation,
+        }
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        match self {
+            PipelineError::StageTimeout { .. }
+            | PipelineError::DeadlineExceeded { .. }
+            | PipelineError::RetryExhausted { .. } => HexaErrorSeverity::Medium,
+            _ => HexaErrorSeverity::High,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes_are_hierarchical() {
+        let errors = [
+            PipelineError::EmptyPipeline,
+            PipelineError::MissingStage {
+                stage_type: PipelineStageType::Feed,
+            },
+            PipelineError::DuplicateStage {
+                stage_type: PipelineStageType::Format,
+            },
+            PipelineError::StageTypeMismatch {
+                expected: PipelineStageType::Filter,
+                actual: PipelineStageType::Format,
+            },
+            PipelineError::StageOrderViolation {
+                stage_type: PipelineStageType::Filter,
+                order: 9,
+                previous: PipelineStageType::Function,
+                previous_order: 4,
+            },
+            PipelineError::ContextTypeMismatch {
+                key: "user".to_string(),
+                reason: "invalid type".to_string(),
+            },
+            PipelineError::NamespaceViolation {
+                stage_type: PipelineStageType::Format,
+                key: "feed.payload".to_string(),
+            },
+            PipelineError::UnsatisfiedInput {
+                stage_type: PipelineStageType::Function,
+                key: "format.normalized".to_string(),
+            },
+        ];
+
+        for error in errors {
+            let parts: Vec<&str> = error.error_code().split('.').collect();
+            assert_eq!(parts.len(), 3);
+            assert_eq!(parts[0], "core");
+            assert_eq!(parts[1], "pipeline");
+            assert_eq!(error.error_kind(), HexaErrorKind::Validation);
+        }
+    }
+
+    #[test]
+    fn test_timeouts_use_timeout_kind() {
+        let stage_timeout = PipelineError::StageTimeout {
+            stage_type: PipelineStageType::Function,
+            timeout_ms: 250,
+        };
+        assert_eq!(stage_timeout.error_code(), "core.pipeline.stage_timeout");
+        assert_eq!(stage_timeout.error_kind(), HexaErrorKind::Timeout);
+        assert_eq!(
+            stage_timeout.to_string(),
+            "Function stage exceeded its 250ms timeout"
+        );
+
+        let deadline = PipelineError::DeadlineExceeded {
+            stage_type: PipelineStageType::Forward,
+        };
+        assert_eq!(deadline.error_code(), "core.pipeline.deadline_exceeded");
+        assert_eq!(deadline.error_kind(), HexaErrorKind::Timeout);
+    }
+
+    #[test]
+    fn test_retry_exhausted_is_internal() {
+        let error = PipelineError::RetryExhausted {
+            stage_type: PipelineStageType::Forward,
+            attempts: 4,
+        };
+        assert_eq!(error.error_code(), "core.pipeline.retry_exhausted");
+        assert_eq!(error.error_kind(), HexaErrorKind::Internal);
+        assert_eq!(error.error_severity(), HexaErrorSeverity::Medium);
+        assert_eq!(
+            error.to_string(),
+            "Forward stage requested a retry after 4 attempts"
+        );
+    }
+
+    #[test]
+    fn test_display_names_offending_stage() {
+        let error = PipelineError::StageOrderViolation {
+            stage_type: PipelineStageType::Filter,
+            order: 9,
+            previous: PipelineStageType::Function,
+            previous_order: 4,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Filter stage (order 9) cannot run after Function stage (order 4)"
+        );
+    }
+
+    #[test]
+    fn test_log_entry_format() {
+        let error = PipelineError::EmptyPipeline;
+        assert_eq!(
+            error.to_log_entry(),
+            "[core.pipeline.empty] [Validation High] Pipeline must contain at least one stage"
+        );
+    }
+}
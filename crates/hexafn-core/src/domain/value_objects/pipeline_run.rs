@@ -0,0 +1,113 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # PipelineRun (Core Module)
+//!
+//! This module defines [`PipelineRun`], the result of a successful pipeline
+//! execution, and [`PipelineRunStatus`], which records whether the run went
+//! through every stage or was ended early by a stage returning
+//! [`StageOutcome::Skip`] or [`StageOutcome::Halt`].
+//!
+//! Events dropped by a stage are not errors: the run still returns `Ok`, and the
+//! status tells the caller which stage ended it.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{PipelineContext, PipelineRun, PipelineRunStatus, PipelineStageType};
+//!
+//! let run = PipelineRun::new(
+//!     PipelineContext::new(),
+//!     PipelineRunStatus::Skipped { stage_type: PipelineStageType::Filter },
+//! );
+//! assert!(!run.is_completed());
+//! assert_eq!(run.status.stopped_at(), Some(PipelineStageType::Filter));
+//! ```
+//!
+//! [`StageOutcome::Skip`]: crate::StageOutcome::Skip
+//! [`StageOutcome::Halt`]: crate::StageOutcome::Halt
+
+use crate::domain::contracts::{PipelineContext, PipelineStageType};
+use serde::{Deserialize, Serialize};
+
+/// How a pipeline run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PipelineRunStatus {
+    /// Every stage ran and returned `Continue`.
+    Completed,
+    /// A stage returned `Skip`; the remaining stages up to Feedback were bypassed.
+    Skipped {
+        /// Stage that skipped the event
+        stage_type: PipelineStageType,
+    },
+    /// A stage returned `Halt`; no further stage ran.
+    Halted {
+        /// Stage that halted the run
+        stage_type: PipelineStageType,
+    },
+}
+
+impl PipelineRunStatus {
+    /// The stage that ended the run early, or `None` if the run completed.
+    pub fn stopped_at(&self) -> Option<PipelineStageType> {
+        match self {
+            PipelineRunStatus::Completed => None,
+            PipelineRunStatus::Skipped { stage_type }
+            | PipelineRunStatus::Halted { stage_type } => Some(*stage_type),
+        }
+    }
+}
+
+/// Output of a pipeline execution: the final context and how the run ended.
+#[derive(Debug, Clone)]
+pub struct PipelineRun {
+    /// Context as left by the last stage that ran
+    pub context: PipelineContext,
+    /// How the run ended
+    pub status: PipelineRunStatus,
+}
+
+impl PipelineRun {
+    /// Create a run result from a final context and status.
+    pub fn new(context: PipelineContext, status: PipelineRunStatus) -> Self {
+        Self { context, status }
+    }
+
+    /// Returns `true` if every stage ran to completion.
+    pub fn is_completed(&self) -> bool {
+        self.status == PipelineRunStatus::Completed
+    }
+
+    /// Consume the run and return the final context.
+    pub fn into_context(self) -> PipelineContext {
+        self.context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_completed_run() {
+        let mut context = PipelineContext::new();
+        context.set("key".to_string(), json!(1)).unwrap();
+        let run = PipelineRun::new(context, PipelineRunStatus::Completed);
+
+        assert!(run.is_completed());
+        assert_eq!(run.status.stopped_at(), None);
+        assert_eq!(run.into_context().get("key"), Some(&json!(1)));
+    }
+
+    #[test]
+    fn test_status_serialization_roundtrip() {
+        let status = PipelineRunStatus::Halted {
+            stage_type: PipelineStageType::Format,
+        };
+        let json = serde_json::to_string(&status).unwrap();
+        let parsed: PipelineRunStatus = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, status);
+        assert_eq!(parsed.stopped_at(), Some(PipelineStageType::Format));
+    }
+}
@@ -0,0 +1,222 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # RetryPolicy (Core Module)
+//!
+//! This module defines [`RetryPolicy`] and [`Backoff`], a declarative description
+//! of how the pipeline engine retries a failing [`PipelineStage`](crate::PipelineStage).
+//!
+//! A stage opts in by returning a policy from `PipelineStage::retry_policy`. The
+//! engine then re-runs the stage on errors whose [`HexaErrorKind`] is listed in
+//! the policy (by default, every [`HexaErrorKind::is_retryable`] kind), waiting
+//! between attempts according to the backoff strategy.
+//! `Validation` errors are never retried: re-running a stage cannot fix bad input.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{Backoff, HexaErrorKind, RetryPolicy};
+//! use std::time::Duration;
+//!
+//! let policy = RetryPolicy::new(4)
+//!     .with_backoff(Backoff::Exponential {
+//!         initial: Duration::from_millis(100),
+//!         factor: 2,
+//!         max: Duration::from_secs(1),
+//!     })
+//!     .retry_on(&[HexaErrorKind::External, HexaErrorKind::Timeout]);
+//!
+//! assert!(policy.should_retry(HexaErrorKind::External, 1));
+//! assert!(!policy.should_retry(HexaErrorKind::External, 4));
+//! assert!(!policy.should_retry(HexaErrorKind::Validation, 1));
+//! assert_eq!(policy.delay_for(3), Duration::from_millis(400));
+//! ```
+
+use crate::domain::contracts::HexaErrorKind;
+use rand::Rng;
+use std::time::Duration;
+
+/// Delay strategy between retry attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// Wait the same amount of time before every retry.
+    Fixed(Duration),
+    /// Wait `initial * factor^(attempt - 1)`, capped at `max`.
+    Exponential {
+        /// Delay before the first retry
+        initial: Duration,
+        /// Growth factor applied per attempt
+        factor: u32,
+        /// Upper bound on any single delay
+        max: Duration,
+    },
+    /// Exponential doubling from `initial` capped at `max`, with a uniformly random
+    /// delay between zero and that value ("full jitter"). Spreads out retries from
+    /// many concurrent runs hitting the same failing target.
+    Jittered {
+        /// Base delay before the first retry
+        initial: Duration,
+        /// Upper bound on any single delay
+        max: Duration,
+    },
+}
+
+impl Backoff {
+    /// Upper bound of the delay before retry number `attempt` (1-based).
+    fn ceiling(initial: Duration, factor: u32, attempt: u32, max: Duration) -> Duration {
+        let exponent = attempt.saturating_sub(1);
+        let multiplier = factor.checked_pow(exponent).unwrap_or(u32::MAX);
+        initial.saturating_mul(multiplier).min(max)
+    }
+
+    /// Delay to wait after failed attempt number `attempt` (1-based).
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        match *self {
+            Backoff::Fixed(delay) => delay,
+            Backoff::Exponential {
+                initial,
+                factor,
+                max,
+            } => Self::ceiling(initial, factor, attempt, max),
+            Backoff::Jittered { initial, max } => {
+                let ceiling = Self::ceiling(initial, 2, attempt, max);
+                let nanos = ceiling.as_nanos().min(u64::MAX as u128) as u64;
+                Duration::from_nanos(rand::thread_rng().gen_range(0..=nanos))
+            }
+        }
+    }
+}
+
+/// Declarative retry policy driven by the pipeline engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Backoff,
+    retry_on: Vec<HexaErrorKind>,
+}
+
+impl RetryPolicy {
+    /// Create a policy allowing up to `max_attempts` executions in total
+    /// (including the first), with no delay between attempts and retrying every
+    /// kind for which [`HexaErrorKind::is_retryable`] holds.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff: Backoff::Fixed(Duration::ZERO),
+            retry_on: HexaErrorKind::ALL
+                .into_iter()
+                .filter(HexaErrorKind::is_retryable)
+                .collect(),
+        }
+    }
+
+    /// Set the delay strategy between attempts.
+    pub fn with_backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Retry only errors of the given kinds. `Validation` is ignored if listed.
+    pub fn retry_on(mut self, kinds: &[HexaErrorKind]) -> Self {
+        self.retry_on = kinds
+            .iter()
+            .copied()
+            .filter(|kind| *kind != HexaErrorKind::Validation)
+            .collect();
+        self
+    }
+
+    /// Maximum number of executions, including the first.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Configured backoff strategy.
+    pub fn backoff(&self) -> Backoff {
+        self.backoff
+    }
+
+    /// Error kinds that trigger a retry.
+    pub fn retryable_kinds(&self) -> &[HexaErrorKind] {
+        &self.retry_on
+    }
+
+    /// Whether an error of `kind` from attempt number `attempt` (1-based) should be
+    /// retried.
+    pub fn should_retry(&self, kind: HexaErrorKind, attempt: u32) -> bool {
+        attempt < self.max_attempts
+            && kind != HexaErrorKind::Validation
+            && self.retry_on.contains(&kind)
+    }
+
+    /// Delay to wait after failed attempt number `attempt` (1-based).
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        self.backoff.delay_for(attempt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_backoff() {
+        let backoff = Backoff::Fixed(Duration::from_millis(50));
+        assert_eq!(backoff.delay_for(1), Duration::from_millis(50));
+        assert_eq!(backoff.delay_for(5), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_exponential_backoff_is_capped() {
+        let backoff = Backoff::Exponential {
+            initial: Duration::from_millis(10),
+            factor: 3,
+            max: Duration::from_millis(200),
+        };
+        assert_eq!(backoff.delay_for(1), Duration::from_millis(10));
+        assert_eq!(backoff.delay_for(2), Duration::from_millis(30));
+        assert_eq!(backoff.delay_for(3), Duration::from_millis(90));
+        assert_eq!(backoff.delay_for(4), Duration::from_millis(200));
+        assert_eq!(backoff.delay_for(60), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_jittered_backoff_stays_within_ceiling() {
+        let backoff = Backoff::Jittered {
+            initial: Duration::from_millis(10),
+            max: Duration::from_millis(25),
+        };
+        for attempt in 1..10 {
+            let ceiling =
+                Duration::from_millis(10 * 2u64.pow(attempt - 1)).min(Duration::from_millis(25));
+            assert!(backoff.delay_for(attempt) <= ceiling);
+        }
+    }
+
+    #[test]
+    fn test_validation_is_never_retried() {
+        let policy =
+            RetryPolicy::new(5).retry_on(&[HexaErrorKind::Validation, HexaErrorKind::Internal]);
+        assert_eq!(policy.retryable_kinds(), &[HexaErrorKind::Internal]);
+        assert!(!policy.should_retry(HexaErrorKind::Validation, 1));
+        assert!(policy.should_retry(HexaErrorKind::Internal, 1));
+        assert!(!policy.should_retry(HexaErrorKind::External, 1));
+    }
+
+    #[test]
+    fn test_max_attempts_includes_first_attempt() {
+        let policy = RetryPolicy::new(3);
+        assert!(policy.should_retry(HexaErrorKind::Timeout, 1));
+        assert!(policy.should_retry(HexaErrorKind::Timeout, 2));
+        assert!(!policy.should_retry(HexaErrorKind::Timeout, 3));
+        assert_eq!(RetryPolicy::new(0).max_attempts(), 1);
+    }
+
+    #[test]
+    fn test_default_kinds_follow_kind_classification() {
+        let policy = RetryPolicy::new(2);
+        for kind in HexaErrorKind::ALL {
+            assert_eq!(policy.should_retry(kind, 1), kind.is_retryable());
+        }
+    }
+}
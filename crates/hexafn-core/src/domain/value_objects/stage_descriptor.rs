@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # StageDescriptor (Core Module)
+//!
+//! This module defines [`StageDescriptor`], the metadata a configurable pipeline
+//! stage is registered with in a
+//! [`PipelineStageRegistry`](crate::PipelineStageRegistry): its registered name,
+//! the [`PipelineStageType`] it fills, a description, and the [`ParamSchema`] its
+//! parameters are checked against.
+//!
+//! Descriptors serialize to JSON so that tooling can list the available stages.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{ParamKind, ParamSpec, PipelineStageType, StageDescriptor};
+//!
+//! let descriptor = StageDescriptor::new("http.poll", PipelineStageType::Feed)
+//!     .with_description("Polls an HTTP endpoint")
+//!     .with_param(ParamSpec::required("url", ParamKind::String));
+//!
+//! assert_eq!(descriptor.params.params.len(), 1);
+//! let json = serde_json::to_value(&descriptor).unwrap();
+//! assert_eq!(json["stage_type"], "Feed");
+//! ```
+
+use crate::domain::contracts::PipelineStageType;
+use crate::domain::value_objects::{ParamSchema, ParamSpec};
+use serde::{Deserialize, Serialize};
+
+/// Metadata of a registered, configurable pipeline stage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageDescriptor {
+    /// Name the stage is registered and referenced under
+    pub name: String,
+    /// Lifecycle slot the stage fills
+    pub stage_type: PipelineStageType,
+    /// Human-readable description, for tooling
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Accepted parameters
+    #[serde(default)]
+    pub params: ParamSchema,
+}
+
+impl StageDescriptor {
+    /// Describe a stage taking no parameters.
+    pub fn new(name: impl Into<String>, stage_type: PipelineStageType) -> Self {
+        Self {
+            name: name.into(),
+            stage_type,
+            description: None,
+            params: ParamSchema::new(),
+        }
+    }
+
+    /// Attach a description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Declare a parameter.
+    pub fn with_param(mut self, param: ParamSpec) -> Self {
+        self.params = self.params.with_param(param);
+        self
+    }
+
+    /// Replace the whole parameter schema.
+    pub fn with_params(mut self, params: ParamSchema) -> Self {
+        self.params = params;
+        self
+    }
+}
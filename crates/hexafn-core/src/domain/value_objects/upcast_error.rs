@@ -0,0 +1,113 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Upcast Errors (Core Module)
+//!
+//! This module defines [`UpcastError`], raised by the
+//! [`UpcasterRegistry`](crate::UpcasterRegistry) when upcasters are registered
+//! inconsistently or an event payload cannot be brought to its latest schema
+//! version.
+//!
+//! All variants use hierarchical `core.upcast.*` error codes.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{HexaError, UpcastError};
+//!
+//! let error = UpcastError::Missing {
+//!     event_type: "order.placed".to_string(),
+//!     from_version: 2,
+//! };
+//! assert_eq!(error.error_code(), "core.upcast.missing");
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use thiserror::Error;
+
+/// Errors produced while registering or applying upcasters.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum UpcastError {
+    /// Schema versions start at 1, so nothing can upcast from version 0.
+    #[error("upcaster for '{event_type}' must start from version 1 or later")]
+    InvalidVersion {
+        /// Event type of the rejected upcaster
+        event_type: String,
+    },
+
+    /// An upcaster for the same event type and version is already registered.
+    #[error("upcaster for '{event_type}' v{from_version} is already registered")]
+    Duplicate {
+        /// Event type of the rejected upcaster
+        event_type: String,
+        /// Version it would upcast from
+        from_version: u32,
+    },
+
+    /// The upcaster chain of an event type has a gap.
+    #[error("no upcaster for '{event_type}' v{from_version}")]
+    Missing {
+        /// Event type being upcast
+        event_type: String,
+        /// Version with no upcaster
+        from_version: u32,
+    },
+
+    /// An upcaster rejected the payload.
+    #[error("upcasting '{event_type}' from v{from_version} failed: {reason}")]
+    Failed {
+        /// Event type being upcast
+        event_type: String,
+        /// Version the failing upcaster starts from
+        from_version: u32,
+        /// Error returned by the upcaster
+        reason: String,
+    },
+
+    /// An event was appended with a schema version older than the latest one.
+    #[error("'{event_type}' events must be written at v{latest}, not v{schema_version}")]
+    Outdated {
+        /// Event type of the rejected event
+        event_type: String,
+        /// Schema version the event carries
+        schema_version: u32,
+        /// Latest schema version of the event type
+        latest: u32,
+    },
+}
+
+impl HexaError for UpcastError {
+    fn error_code(&self) -> &str {
+        match self {
+            UpcastError::InvalidVersion { .. } => "core.upcast.invalid_version",
+            UpcastError::Duplicate { .. } => "core.upcast.duplicate",
+            UpcastError::Missing { .. } => "core.upcast.missing",
+            UpcastError::Failed { .. } => "core.upcast.failed",
+            UpcastError::Outdated { .. } => "core.upcast.outdated",
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            UpcastError::InvalidVersion { .. } => "Upcaster version is invalid",
+            UpcastError::Duplicate { .. } => "Upcaster is already registered",
+            UpcastError::Missing { .. } => "Upcaster chain is incomplete",
+            UpcastError::Failed { .. } => "Event payload could not be upcast",
+            UpcastError::Outdated { .. } => "Event is written at an outdated schema version",
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        match self {
+            UpcastError::InvalidVersion { .. } => HexaErrorKind::Validation,
+            UpcastError::Duplicate { .. } => HexaErrorKind::Conflict,
+            UpcastError::Missing { .. } => HexaErrorKind::Internal,
+            UpcastError::Failed { .. } => HexaErrorKind::Serialization,
+            UpcastError::Outdated { .. } => HexaErrorKind::Validation,
+        }
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        HexaErrorSeverity::High
+    }
+}
@@ -0,0 +1,8 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod pipeline_loader;
+mod pipeline_watcher;
+
+pub use pipeline_loader::PipelineLoader;
+pub use pipeline_watcher::PipelineWatcher;
@@ -0,0 +1,411 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Pipeline Loader (Core Module)
+//!
+//! This module provides [`PipelineLoader`], the inbound adapter that reads a
+//! [`PipelineConfig`] from a TOML, YAML or JSON file and resolves it into a
+//! validated [`SixFPipeline`].
+//!
+//! Every stage entry is built by the stage registered under its `type` and
+//! `name` in a [`PipelineStageRegistry`], which also checks its `params`. The
+//! loader adds each stage to the pipeline and finally runs
+//! [`Pipeline::build`]. Each failure is reported as a [`PipelineConfigError`]
+//! pointing at the stage entry in the file, for example
+//! `pipelines/orders.yaml:12:11: no Format stage is registered as 'json.fromat'`,
+//! or at the `stages` entry when the pipeline as a whole is invalid. Locations
+//! are looked up only once something has failed.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{
+//!     ConfigFormat, HexaError, ParamKind, ParamSpec, Pipeline, PipelineContext, PipelineLoader,
+//!     PipelineStage, PipelineStageRegistry, PipelineStageType, StageDescriptor,
+//! };
+//! use serde_json::{json, Value};
+//!
+//! struct Constant(Value);
+//!
+//! #[async_trait::async_trait]
+//! impl PipelineStage for Constant {
+//!     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Feed }
+//!     fn get_order(&self) -> u32 { 1 }
+//!     async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+//!         context.set("input".to_string(), self.0.clone())?;
+//!         Ok(())
+//!     }
+//!     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//! }
+//!
+//! let mut registry = PipelineStageRegistry::new();
+//! registry
+//!     .register(
+//!         StageDescriptor::new("constant", PipelineStageType::Feed)
+//!             .with_param(ParamSpec::required("value", ParamKind::Any)),
+//!         |params: &Value| {
+//!             Ok::<_, Box<dyn HexaError>>(Box::new(Constant(params["value"].clone())) as Box<dyn PipelineStage>)
+//!         },
+//!     )
+//!     .unwrap();
+//!
+//! let loader = PipelineLoader::new(registry);
+//! let yaml = "
+//! stages:
+//!   - type: feed
+//!     name: constant
+//!     params: { value: 42 }
+//! ";
+//! let pipeline = loader.load_str(yaml, ConfigFormat::Yaml).unwrap();
+//!
+//! # tokio_test::block_on(async {
+//! let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+//! assert_eq!(run.context.get("input"), Some(&json!(42)));
+//! # });
+//!
+//! let err = loader
+//!     .load_str("stages:\n  - type: feed\n    name: constnat\n", ConfigFormat::Yaml)
+//!     .err().unwrap();
+//! assert_eq!(err.to_string(), "<string>:3:11: no Feed stage is registered as 'constnat'");
+//! ```
+
+use crate::domain::contracts::{Clock, Pipeline};
+use crate::domain::entities::SixFPipeline;
+use crate::domain::services::PipelineStageRegistry;
+use crate::infrastructure::dtos::{
+    ConfigFormat, ConfigSource, PipelineConfig, PipelineConfigError, INLINE_SOURCE,
+};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Builds pipelines from config files through a [`PipelineStageRegistry`].
+#[derive(Debug, Clone)]
+pub struct PipelineLoader {
+    registry: PipelineStageRegistry,
+    clock: Option<Arc<dyn Clock>>,
+}
+
+impl PipelineLoader {
+    /// Create a loader resolving stages through `registry`.
+    pub fn new(registry: PipelineStageRegistry) -> Self {
+        Self {
+            registry,
+            clock: None,
+        }
+    }
+
+    /// Install `clock` into every pipeline this loader builds.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// The registry stages are resolved through.
+    pub fn registry(&self) -> &PipelineStageRegistry {
+        &self.registry
+    }
+
+    /// Read and build the pipeline described by the file at `path`. The format
+    /// is taken from the file extension.
+    ///
+    /// # Errors
+    ///
+    /// - [`PipelineConfigError::UnsupportedFormat`] for unknown extensions
+    /// - [`PipelineConfigError::Io`] if the file cannot be read
+    /// - any error of [`load_str`](Self::load_str), located in this file
+    pub async fn load_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<SixFPipeline, PipelineConfigError> {
+        let path = path.as_ref();
+        if ConfigFormat::from_path(path).is_none() {
+            return Err(PipelineConfigError::UnsupportedFormat {
+                path: path.display().to_string(),
+            });
+        }
+        let text = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| PipelineConfigError::Io {
+                path: path.display().to_string(),
+                reason: e.to_string(),
+            })?;
+        self.load_file_contents(path, &text)
+    }
+
+    /// Build the pipeline described by `text`, read from the file at `path`.
+    pub(crate) fn load_file_contents(
+        &self,
+        path: &Path,
+        text: &str,
+    ) -> Result<SixFPipeline, PipelineConfigError> {
+        let file = path.display().to_string();
+        let format = ConfigFormat::from_path(path)
+            .ok_or_else(|| PipelineConfigError::UnsupportedFormat { path: file.clone() })?;
+        self.load_source(&file, text, format)
+    }
+
+    /// Build the pipeline described by `text`.
+    ///
+    /// # Errors
+    ///
+    /// - [`PipelineConfigError::Parse`] for syntax and schema errors
+    /// - [`PipelineConfigError::UnknownStage`] for unregistered stage names
+    /// - [`PipelineConfigError::InvalidStage`] if a factory fails or builds a
+    ///   stage of another type
+    /// - [`PipelineConfigError::InvalidPipeline`] if the stages do not form a
+    ///   valid 6F pipeline
+    pub fn load_str(
+        &self,
+        text: &str,
+        format: ConfigFormat,
+    ) -> Result<SixFPipeline, PipelineConfigError> {
+        self.load_source(INLINE_SOURCE, text, format)
+    }
+
+    /// Build the pipeline described by an already parsed `config`. Errors carry
+    /// no line numbers.
+    ///
+    /// # Errors
+    ///
+    /// As [`load_str`](Self::load_str), except for parse errors.
+    pub fn build(&self, config: &PipelineConfig) -> Result<SixFPipeline, PipelineConfigError> {
+        self.resolve(config, ConfigSource::parsed(INLINE_SOURCE))
+    }
+
+    fn load_source(
+        &self,
+        file: &str,
+        text: &str,
+        format: ConfigFormat,
+    ) -> Result<SixFPipeline, PipelineConfigError> {
+        let config = PipelineConfig::parse_source(file, text, format)?;
+        self.resolve(&config, ConfigSource::new(file, text, format))
+    }
+
+    fn resolve(
+        &self,
+        config: &PipelineConfig,
+        source: ConfigSource<'_>,
+    ) -> Result<SixFPipeline, PipelineConfigError> {
+        let mut pipeline = SixFPipeline::new();
+        if let Some(max_retries) = config.max_retries {
+            pipeline = pipeline.with_max_retries(max_retries);
+        }
+        if let Some(deadline_ms) = config.deadline_ms {
+            pipeline = pipeline.with_deadline(Duration::from_millis(deadline_ms));
+        }
+        if let Some(clock) = &self.clock {
+            pipeline = pipeline.with_clock(Arc::clone(clock));
+        }
+
+        for (index, stage_config) in config.stages.iter().enumerate() {
+            let (stage_type, name) = (stage_config.stage_type, &stage_config.name);
+            if !self.registry.contains(stage_type, name) {
+                return Err(PipelineConfigError::UnknownStage {
+                    location: source.stage(index),
+                    stage_type,
+                    name: name.clone(),
+                });
+            }
+            let stage = self
+                .registry
+                .create(stage_type, name, &stage_config.params)
+                .map_err(|e| PipelineConfigError::InvalidStage {
+                    location: source.stage(index),
+                    name: name.clone(),
+                    reason: e.to_string(),
+                })?;
+            pipeline
+                .add_stage(stage)
+                .map_err(|e| PipelineConfigError::InvalidPipeline {
+                    location: source.stage(index),
+                    reason: e.to_string(),
+                })?;
+        }
+
+        pipeline
+            .build()
+            .map_err(|e| PipelineConfigError::InvalidPipeline {
+                location: source.stages(),
+                reason: e.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::{
+        HexaError, PipelineContext, PipelineStage, PipelineStageType, StageFactory,
+    };
+    use crate::domain::value_objects::{ParamKind, ParamSpec, StageDescriptor};
+    use crate::infrastructure::dtos::ConfigLocation;
+    use serde_json::{json, Value};
+
+    struct Step(PipelineStageType, u32);
+
+    #[async_trait::async_trait]
+    impl PipelineStage for Step {
+        fn stage_type(&self) -> PipelineStageType {
+            self.0
+        }
+        fn get_order(&self) -> u32 {
+            self.1
+        }
+        async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            context.set(format!("{:?}", self.0), json!(true))?;
+            Ok(())
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+    }
+
+    fn step(stage_type: PipelineStageType, order: u32) -> impl StageFactory {
+        move |_: &Value| {
+            Ok::<_, Box<dyn HexaError>>(Box::new(Step(stage_type, order)) as Box<dyn PipelineStage>)
+        }
+    }
+
+    fn loader() -> PipelineLoader {
+        let mut registry = PipelineStageRegistry::new();
+        let stages = [
+            ("feed", PipelineStageType::Feed, 1),
+            ("filter", PipelineStageType::Filter, 2),
+            ("function", PipelineStageType::Function, 4),
+        ];
+        for (name, stage_type, order) in stages {
+            registry
+                .register(
+                    StageDescriptor::new(name, stage_type),
+                    step(stage_type, order),
+                )
+                .unwrap();
+        }
+        registry
+            .register(
+                StageDescriptor::new("strict", PipelineStageType::Filter)
+                    .with_param(ParamSpec::required("limit", ParamKind::Integer)),
+                step(PipelineStageType::Filter, 2),
+            )
+            .unwrap();
+        PipelineLoader::new(registry)
+    }
+
+    #[tokio::test]
+    async fn test_loads_and_runs_configured_pipeline() {
+        let toml = r#"
+max_retries = 0
+
+[[stages]]
+type = "function"
+name = "function"
+
+[[stages]]
+type = "feed"
+name = "feed"
+"#;
+        let pipeline = loader().load_str(toml, ConfigFormat::Toml).unwrap();
+        let types: Vec<_> = pipeline
+            .get_stages()
+            .iter()
+            .map(|s| s.stage_type())
+            .collect();
+        assert_eq!(
+            types,
+            [PipelineStageType::Feed, PipelineStageType::Function]
+        );
+
+        let run = pipeline.execute(PipelineContext::new()).await.unwrap();
+        assert_eq!(run.context.get("Function"), Some(&json!(true)));
+    }
+
+    #[test]
+    fn test_unknown_stage_points_at_its_entry() {
+        let yaml = "stages:\n  - type: feed\n    name: feed\n  - type: filter\n    name: filtr\n";
+        let err = loader().load_str(yaml, ConfigFormat::Yaml).err().unwrap();
+        assert_eq!(err.error_code(), "core.pipeline_config.unknown_stage");
+        assert_eq!(err.location(), Some(&ConfigLocation::at("<string>", 5, 11)));
+    }
+
+    #[test]
+    fn test_params_are_checked_against_stage_schema() {
+        let json = r#"{
+  "stages": [
+    { "type": "feed", "name": "feed" },
+    { "type": "filter", "name": "strict", "params": {} }
+  ]
+}"#;
+        let err = loader().load_str(json, ConfigFormat::Json).err().unwrap();
+        assert_eq!(err.error_code(), "core.pipeline_config.invalid_stage");
+        assert_eq!(err.location().unwrap().line, Some(4));
+        assert!(
+            err.to_string()
+                .ends_with("missing required parameter 'limit'"),
+            "{err}"
+        );
+
+        // Names are scoped by stage type.
+        let wrong_type = "[[stages]]\ntype = \"format\"\nname = \"feed\"\n";
+        let err = loader()
+            .load_str(wrong_type, ConfigFormat::Toml)
+            .err()
+            .unwrap();
+        assert_eq!(err.error_code(), "core.pipeline_config.unknown_stage");
+        assert_eq!(err.location().unwrap().line, Some(3));
+    }
+
+    #[test]
+    fn test_pipeline_rules_are_enforced() {
+        let no_feed = "stages:\n  - type: filter\n    name: filter\n";
+        let err = loader()
+            .load_str(no_feed, ConfigFormat::Yaml)
+            .err()
+            .unwrap();
+        assert_eq!(err.error_code(), "core.pipeline_config.invalid_pipeline");
+        assert!(err.to_string().contains("Feed"), "{err}");
+        assert_eq!(err.location(), Some(&ConfigLocation::at("<string>", 1, 1)));
+
+        let empty = loader()
+            .load_str("name = \"empty\"\n", ConfigFormat::Toml)
+            .err()
+            .unwrap();
+        assert_eq!(empty.location(), Some(&ConfigLocation::new("<string>")));
+
+        let twice = "stages:\n  - type: feed\n    name: feed\n  - type: feed\n    name: feed\n";
+        let err = loader().load_str(twice, ConfigFormat::Yaml).err().unwrap();
+        assert_eq!(err.error_code(), "core.pipeline_config.invalid_pipeline");
+        assert_eq!(err.location().unwrap().line, Some(5));
+    }
+
+    #[tokio::test]
+    async fn test_load_file_detects_format_and_names_path() {
+        let dir = std::env::temp_dir().join(format!("hexafn-config-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("pipeline.yml");
+        std::fs::write(
+            &path,
+            "stages:\n  - type: feed\n    name: feed\n    extra: 1\n",
+        )
+        .unwrap();
+
+        let err = loader().load_file(&path).await.err().unwrap();
+        let location = err.location().unwrap();
+        assert_eq!(location.file, path.display().to_string());
+        assert_eq!(location.line, Some(4));
+
+        let missing = loader()
+            .load_file(dir.join("none.toml"))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(missing.error_code(), "core.pipeline_config.io");
+        let unsupported = loader().load_file(dir.join("p.ini")).await.err().unwrap();
+        assert_eq!(
+            unsupported.error_code(),
+            "core.pipeline_config.unsupported_format"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,343 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Pipeline Watcher (Core Module)
+//!
+//! This module provides [`PipelineWatcher`], which keeps a [`HotSwapPipeline`] in
+//! sync with a pipeline config file.
+//!
+//! The watcher polls the file and, whenever its contents change, rebuilds the
+//! pipeline through a [`PipelineLoader`], checks it with
+//! [`Pipeline::validate`](crate::Pipeline::validate) and swaps it in.
+//! Executions already running finish on the version they started with. If the
+//! new contents cannot be read, parsed or validated, the change is rejected with
+//! a [`PipelineConfigError`] and the previous version stays live; the same
+//! contents are not retried until the file changes again.
+//!
+//! Polling uses the watcher's [`Clock`], so it works on every platform and can be
+//! driven by a [`ManualClock`](crate::ManualClock) in tests.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{
+//!     HexaError, PipelineContext, PipelineLoader, PipelineStage, PipelineStageRegistry,
+//!     PipelineStageType, PipelineWatcher, StageDescriptor,
+//! };
+//! use serde_json::Value;
+//!
+//! struct Noop;
+//!
+//! #[async_trait::async_trait]
+//! impl PipelineStage for Noop {
+//!     fn stage_type(&self) -> PipelineStageType { PipelineStageType::Feed }
+//!     fn get_order(&self) -> u32 { 1 }
+//!     async fn execute(&self, _context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//!     fn validate(&self) -> Result<(), Box<dyn HexaError>> { Ok(()) }
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! let mut registry = PipelineStageRegistry::new();
+//! registry
+//!     .register(StageDescriptor::new("noop", PipelineStageType::Feed), |_: &Value| {
+//!         Ok::<_, Box<dyn HexaError>>(Box::new(Noop) as Box<dyn PipelineStage>)
+//!     })
+//!     .unwrap();
+//!
+//! let dir = std::env::temp_dir().join(format!("hexafn-doc-{}", uuid::Uuid::new_v4()));
+//! std::fs::create_dir_all(&dir).unwrap();
+//! let path = dir.join("pipeline.yaml");
+//! std::fs::write(&path, "stages:\n  - type: feed\n    name: noop\n").unwrap();
+//!
+//! let watcher = PipelineWatcher::open(PipelineLoader::new(registry), &path).await.unwrap();
+//! let live = watcher.pipeline();
+//! assert_eq!(live.version(), 1);
+//!
+//! // A broken edit is rejected and version 1 stays live.
+//! std::fs::write(&path, "stages:\n  - type: feed\n    name: nope\n").unwrap();
+//! let err = watcher.check().await.unwrap_err();
+//! assert_eq!(err.error_code(), "core.pipeline_config.unknown_stage");
+//! assert_eq!(live.version(), 1);
+//!
+//! // Fixing it swaps in version 2.
+//! std::fs::write(&path, "max_retries: 1\nstages:\n  - type: feed\n    name: noop\n").unwrap();
+//! assert_eq!(watcher.check().await.unwrap(), Some(2));
+//!
+//! // Polling in the background:
+//! let task = watcher.spawn(std::time::Duration::from_secs(1), |error| eprintln!("{error}"));
+//! task.abort();
+//! # std::fs::remove_dir_all(&dir).unwrap();
+//! # });
+//! ```
+
+use crate::domain::contracts::{Clock, HexaError};
+use crate::domain::entities::{HotSwapPipeline, SixFPipeline};
+use crate::domain::services::SystemClock;
+use crate::infrastructure::adapters::inbound::PipelineLoader;
+use crate::infrastructure::dtos::{ConfigLocation, PipelineConfigError};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+/// Reloads a [`HotSwapPipeline`] whenever its config file changes.
+#[derive(Debug)]
+pub struct PipelineWatcher {
+    loader: PipelineLoader,
+    path: PathBuf,
+    pipeline: Arc<HotSwapPipeline<SixFPipeline>>,
+    clock: Arc<dyn Clock>,
+    /// Contents of the last load attempt; `None` after a read failure.
+    last_seen: Mutex<Option<String>>,
+}
+
+impl PipelineWatcher {
+    /// Load the pipeline at `path` as version 1 and start tracking the file.
+    ///
+    /// # Errors
+    ///
+    /// Any [`PipelineConfigError`] of [`PipelineLoader::load_file`], or
+    /// [`PipelineConfigError::InvalidPipeline`] if validation fails.
+    pub async fn open(
+        loader: PipelineLoader,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, PipelineConfigError> {
+        let path = path.as_ref().to_path_buf();
+        let text = Self::read(&path).await?;
+        let pipeline = loader.load_file_contents(&path, &text)?;
+        let pipeline = HotSwapPipeline::new(pipeline).map_err(|e| invalid(&path, &*e))?;
+        Ok(Self {
+            loader,
+            path,
+            pipeline: Arc::new(pipeline),
+            clock: SystemClock::shared(),
+            last_seen: Mutex::new(Some(text)),
+        })
+    }
+
+    /// Poll on `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// The live pipeline. Share it with whatever executes events.
+    pub fn pipeline(&self) -> Arc<HotSwapPipeline<SixFPipeline>> {
+        Arc::clone(&self.pipeline)
+    }
+
+    /// The watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reload the file if it changed since the last check. Returns the new
+    /// version if one was swapped in.
+    ///
+    /// # Errors
+    ///
+    /// The [`PipelineConfigError`] that rejected the new contents. The previous
+    /// version stays live, and the same contents are not reported again.
+    pub async fn check(&self) -> Result<Option<u64>, PipelineConfigError> {
+        let mut last_seen = self.last_seen.lock().await;
+        let text = match Self::read(&self.path).await {
+            Ok(text) => text,
+            // Report a vanished file once, not on every poll.
+            Err(_) if last_seen.is_none() => return Ok(None),
+            Err(e) => {
+                *last_seen = None;
+                return Err(e);
+            }
+        };
+        if last_seen.as_deref() == Some(text.as_str()) {
+            return Ok(None);
+        }
+        let loaded = self.loader.load_file_contents(&self.path, &text);
+        *last_seen = Some(text);
+
+        let pipeline = loaded?;
+        let version = self
+            .pipeline
+            .swap(pipeline)
+            .map_err(|e| invalid(&self.path, &*e))?;
+        tracing::info!(path = %self.path.display(), version, "pipeline config reloaded");
+        Ok(Some(version))
+    }
+
+    /// Check the file every `interval` on a background task until it is
+    /// aborted, passing each rejected change to `on_error`.
+    pub fn spawn<F>(self, interval: Duration, on_error: F) -> JoinHandle<()>
+    where
+        F: Fn(PipelineConfigError) + Send + Sync + 'static,
+    {
+        tokio::spawn(async move {
+            loop {
+                self.clock.sleep(interval).await;
+                if let Err(error) = self.check().await {
+                    tracing::warn!(
+                        path = %self.path.display(),
+                        error_code = error.error_code(),
+                        "rejected pipeline config change: {error}"
+                    );
+                    on_error(error);
+                }
+            }
+        })
+    }
+
+    async fn read(path: &Path) -> Result<String, PipelineConfigError> {
+        tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| PipelineConfigError::Io {
+                path: path.display().to_string(),
+                reason: e.to_string(),
+            })
+    }
+}
+
+fn invalid(path: &Path, error: &dyn HexaError) -> PipelineConfigError {
+    PipelineConfigError::InvalidPipeline {
+        location: ConfigLocation::new(path.display().to_string()),
+        reason: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::{PipelineContext, PipelineStage, PipelineStageType};
+    use crate::domain::services::{ManualClock, PipelineStageRegistry};
+    use crate::domain::value_objects::{ParamKind, ParamSpec, StageDescriptor};
+    use serde_json::{json, Value};
+    use std::sync::Mutex as StdMutex;
+
+    struct Label(Value);
+
+    #[async_trait::async_trait]
+    impl PipelineStage for Label {
+        fn stage_type(&self) -> PipelineStageType {
+            PipelineStageType::Feed
+        }
+        fn get_order(&self) -> u32 {
+            1
+        }
+        async fn execute(&self, context: &mut PipelineContext) -> Result<(), Box<dyn HexaError>> {
+            context.set("label".to_string(), self.0.clone())?;
+            Ok(())
+        }
+        fn validate(&self) -> Result<(), Box<dyn HexaError>> {
+            Ok(())
+        }
+    }
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            let dir = std::env::temp_dir().join(format!("hexafn-watch-{}", uuid::Uuid::new_v4()));
+            std::fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn loader() -> PipelineLoader {
+        let mut registry = PipelineStageRegistry::new();
+        registry
+            .register(
+                StageDescriptor::new("label", PipelineStageType::Feed)
+                    .with_param(ParamSpec::required("text", ParamKind::String)),
+                |params: &Value| {
+                    Ok::<_, Box<dyn HexaError>>(
+                        Box::new(Label(params["text"].clone())) as Box<dyn PipelineStage>
+                    )
+                },
+            )
+            .unwrap();
+        PipelineLoader::new(registry)
+    }
+
+    fn config(text: &str) -> String {
+        format!("[[stages]]\ntype = \"feed\"\nname = \"label\"\nparams = {{ text = \"{text}\" }}\n")
+    }
+
+    async fn label(live: &HotSwapPipeline<SixFPipeline>) -> Value {
+        let run = live.execute(PipelineContext::new()).await.unwrap();
+        run.context.get("label").cloned().unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_rejected_change_keeps_previous_version() {
+        let dir = TempDir::new();
+        let path = dir.0.join("p.toml");
+        std::fs::write(&path, config("one")).unwrap();
+        let watcher = PipelineWatcher::open(loader(), &path).await.unwrap();
+        let live = watcher.pipeline();
+        assert_eq!(watcher.check().await.unwrap(), None);
+
+        std::fs::write(&path, "[[stages]]\ntype = \"feed\"\nname = \"label\"\n").unwrap();
+        let err = watcher.check().await.unwrap_err();
+        assert_eq!(err.error_code(), "core.pipeline_config.invalid_stage");
+        assert_eq!(err.location().unwrap().line, Some(3));
+        // Unchanged broken contents are not reported twice.
+        assert_eq!(watcher.check().await.unwrap(), None);
+        assert_eq!(live.version(), 1);
+        assert_eq!(label(&live).await, json!("one"));
+
+        std::fs::write(&path, config("two")).unwrap();
+        assert_eq!(watcher.check().await.unwrap(), Some(2));
+        assert_eq!(label(&live).await, json!("two"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_file_is_reported_once() {
+        let dir = TempDir::new();
+        let path = dir.0.join("p.toml");
+        std::fs::write(&path, config("one")).unwrap();
+        let watcher = PipelineWatcher::open(loader(), &path).await.unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+        let err = watcher.check().await.unwrap_err();
+        assert_eq!(err.error_code(), "core.pipeline_config.io");
+        assert_eq!(watcher.check().await.unwrap(), None);
+
+        std::fs::write(&path, config("one")).unwrap();
+        assert_eq!(watcher.check().await.unwrap(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_spawned_watcher_polls_on_its_clock() {
+        let dir = TempDir::new();
+        let path = dir.0.join("p.toml");
+        std::fs::write(&path, config("one")).unwrap();
+        let clock = ManualClock::default();
+        let watcher = PipelineWatcher::open(loader(), &path)
+            .await
+            .unwrap()
+            .with_clock(Arc::new(clock.clone()));
+        let live = watcher.pipeline();
+        let errors = Arc::new(StdMutex::new(Vec::new()));
+        let task = watcher.spawn(Duration::from_secs(5), {
+            let errors = Arc::clone(&errors);
+            move |error| errors.lock().unwrap().push(error.error_code().to_string())
+        });
+
+        std::fs::write(&path, "not toml").unwrap();
+        while clock.sleeper_count() == 0 {
+            tokio::task::yield_now().await;
+        }
+        clock.advance(Duration::from_secs(5));
+        while errors.lock().unwrap().is_empty() {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(*errors.lock().unwrap(), ["core.pipeline_config.parse"]);
+        assert_eq!(live.version(), 1);
+        task.abort();
+    }
+}
@@ -0,0 +1,5 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+pub mod inbound;
+pub mod outbound;
@@ -0,0 +1,515 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # File Event Store (Core Module)
+//!
+//! This module provides [`FileEventStore`], an [`EventStore`] that persists each
+//! stream as a JSON Lines file: one [`EventEnvelope`] per line, in sequence order.
+//!
+//! ## Layout
+//!
+//! Streams live directly under the store directory as `<aggregate_id>.jsonl`, with
+//! every character other than ASCII letters, digits, `-` and `_` percent-encoded
+//! so that any aggregate id maps to a single safe file name. Each append writes
+//! its events as complete lines and syncs them to disk before it returns.
+//!
+//! An append interrupted part-way, by a crash or a failed write, can leave a last
+//! line without its newline. Readers ignore such a line, and the next append to
+//! the stream removes it, so the stream stays readable.
+//!
+//! Optimistic concurrency is enforced within one `FileEventStore` instance (and
+//! its clones). Several processes must not write to the same directory.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventEnvelope, EventStore, FileEventStore};
+//! use serde_json::json;
+//!
+//! # tokio_test::block_on(async {
+//! let dir = std::env::temp_dir().join(format!("hexafn-doc-{}", uuid::Uuid::new_v4()));
+//! let store = FileEventStore::open(&dir).await.unwrap();
+//! store
+//!     .append("order-1", 0, vec![EventEnvelope::new("order.placed", json!({ "total": 10 }))])
+//!     .await
+//!     .unwrap();
+//!
+//! // A fresh instance sees the persisted stream.
+//! let reopened = FileEventStore::open(&dir).await.unwrap();
+//! assert_eq!(reopened.stream_version("order-1").await.unwrap(), 1);
+//! # std::fs::remove_dir_all(&dir).unwrap();
+//! # });
+//! ```
+
+use crate::domain::contracts::{stamp_batch, EventStore, HexaError};
+use crate::domain::value_objects::{EventEnvelope, EventStoreError};
+use async_trait::async_trait;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::collections::HashMap;
+use std::future::Future;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs::{self, File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+/// Characters kept verbatim in stream file names.
+const FILE_NAME: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'_');
+
+/// File extension of stream files.
+const STREAM_EXTENSION: &str = "jsonl";
+
+/// Contents of a stream file.
+struct StreamFile {
+    events: Vec<EventEnvelope>,
+    /// Length of the file up to the end of its last complete line
+    complete_len: u64,
+    /// Whether an incomplete last line follows the complete ones
+    torn: bool,
+}
+
+/// File operations of an append, so tests can make them fail.
+#[async_trait]
+trait StreamWriter: Send {
+    async fn len(&self) -> io::Result<u64>;
+    async fn write_all(&mut self, buffer: &[u8]) -> io::Result<()>;
+    async fn set_len(&self, len: u64) -> io::Result<()>;
+    async fn sync_data(&self) -> io::Result<()>;
+}
+
+#[async_trait]
+impl StreamWriter for File {
+    async fn len(&self) -> io::Result<u64> {
+        Ok(self.metadata().await?.len())
+    }
+
+    async fn write_all(&mut self, buffer: &[u8]) -> io::Result<()> {
+        AsyncWriteExt::write_all(self, buffer).await
+    }
+
+    async fn set_len(&self, len: u64) -> io::Result<()> {
+        File::set_len(self, len).await
+    }
+
+    async fn sync_data(&self) -> io::Result<()> {
+        File::sync_data(self).await
+    }
+}
+
+/// Append `buffer` and sync it, cutting a partial write back off.
+///
+/// If the cut fails, its error is returned and the torn line stays in the file.
+async fn write_batch(file: &mut impl StreamWriter, buffer: &[u8]) -> io::Result<()> {
+    let previous_len = file.len().await?;
+    if let Err(e) = file.write_all(buffer).await {
+        file.set_len(previous_len).await?;
+        return Err(e);
+    }
+    file.sync_data().await
+}
+
+/// Open the stream file at `path` for appending.
+async fn open_for_append(path: PathBuf) -> io::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await
+}
+
+/// Event store persisting one JSON Lines file per stream.
+///
+/// Clones share the same directory, lock and version cache.
+#[derive(Debug, Clone)]
+pub struct FileEventStore {
+    root: PathBuf,
+    /// Known stream versions; the lock also serialises appends.
+    versions: Arc<Mutex<HashMap<String, u64>>>,
+}
+
+impl FileEventStore {
+    /// Open a store rooted at `root`, creating the directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the directory cannot be created.
+    pub async fn open(root: impl AsRef<Path>) -> Result<Self, Box<dyn HexaError>> {
+        let root = root.as_ref().to_path_buf();
+        fs::create_dir_all(&root).await?;
+        Ok(Self {
+            root,
+            versions: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    /// Directory holding the stream files.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Path of the file backing the stream of `aggregate_id`.
+    pub fn stream_path(&self, aggregate_id: &str) -> PathBuf {
+        let name = utf8_percent_encode(aggregate_id, FILE_NAME).to_string();
+        self.root.join(format!("{name}.{STREAM_EXTENSION}"))
+    }
+
+    async fn load(&self, aggregate_id: &str) -> Result<StreamFile, Box<dyn HexaError>> {
+        let bytes = match fs::read(self.stream_path(aggregate_id)).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Ok(StreamFile {
+                    events: Vec::new(),
+                    complete_len: 0,
+                    torn: false,
+                })
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let corrupted = |line: usize, reason: String| -> Box<dyn HexaError> {
+            EventStoreError::Corrupted {
+                aggregate_id: aggregate_id.to_string(),
+                line,
+                reason,
+            }
+            .into()
+        };
+        let complete_len = bytes.iter().rposition(|b| *b == b'\n').map_or(0, |i| i + 1);
+        let contents = std::str::from_utf8(&bytes[..complete_len]).map_err(|e| {
+            let line = bytes[..e.valid_up_to()]
+                .iter()
+                .filter(|b| **b == b'\n')
+                .count()
+                + 1;
+            corrupted(line, e.to_string())
+        })?;
+        let events = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line).map_err(|e| corrupted(index + 1, e.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(StreamFile {
+            events,
+            complete_len: complete_len as u64,
+            torn: complete_len < bytes.len(),
+        })
+    }
+
+    async fn current_version(
+        &self,
+        versions: &mut HashMap<String, u64>,
+        aggregate_id: &str,
+    ) -> Result<u64, Box<dyn HexaError>> {
+        if let Some(version) = versions.get(aggregate_id) {
+            return Ok(*version);
+        }
+        let stream = self.load(aggregate_id).await?;
+        if stream.torn {
+            tracing::warn!(
+                aggregate_id,
+                "removing incomplete last line left by an interrupted append"
+            );
+            let file = OpenOptions::new()
+                .write(true)
+                .open(self.stream_path(aggregate_id))
+                .await?;
+            file.set_len(stream.complete_len).await?;
+            file.sync_data().await?;
+        }
+        let version = stream.events.last().map_or(0, |e| e.sequence_number);
+        versions.insert(aggregate_id.to_string(), version);
+        Ok(version)
+    }
+
+    async fn append_with<W, F, Fut>(
+        &self,
+        aggregate_id: &str,
+        expected_version: u64,
+        events: Vec<EventEnvelope>,
+        open: F,
+    ) -> Result<u64, Box<dyn HexaError>>
+    where
+        W: StreamWriter,
+        F: FnOnce(PathBuf) -> Fut + Send,
+        Fut: Future<Output = io::Result<W>> + Send,
+    {
+        let mut versions = self.versions.lock().await;
+        let current = self.current_version(&mut versions, aggregate_id).await?;
+        let stamped = stamp_batch(aggregate_id, expected_version, current, events)?;
+        let Some(last) = stamped.last().map(|e| e.sequence_number) else {
+            return Ok(current);
+        };
+
+        let mut buffer = Vec::new();
+        for event in &stamped {
+            serde_json::to_writer(&mut buffer, event)?;
+            buffer.push(b'\n');
+        }
+        let written = match open(self.stream_path(aggregate_id)).await {
+            Ok(mut file) => write_batch(&mut file, &buffer).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = written {
+            // The file may hold none, part or all of the batch: re-read it on the
+            // next call, which also removes a torn last line.
+            versions.remove(aggregate_id);
+            return Err(e.into());
+        }
+
+        versions.insert(aggregate_id.to_string(), last);
+        Ok(last)
+    }
+}
+
+#[async_trait]
+impl EventStore for FileEventStore {
+    async fn append(
+        &self,
+        aggregate_id: &str,
+        expected_version: u64,
+        events: Vec<EventEnvelope>,
+    ) -> Result<u64, Box<dyn HexaError>> {
+        self.append_with(aggregate_id, expected_version, events, open_for_append)
+            .await
+    }
+
+    async fn read_stream(
+        &self,
+        aggregate_id: &str,
+        from_sequence: u64,
+    ) -> Result<Vec<EventEnvelope>, Box<dyn HexaError>> {
+        let mut events = self.load(aggregate_id).await?.events;
+        events.retain(|e| e.sequence_number >= from_sequence);
+        Ok(events)
+    }
+
+    async fn stream_version(&self, aggregate_id: &str) -> Result<u64, Box<dyn HexaError>> {
+        let mut versions = self.versions.lock().await;
+        self.current_version(&mut versions, aggregate_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("hexafn-store-{}", uuid::Uuid::new_v4())))
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn event(event_type: &str) -> EventEnvelope {
+        EventEnvelope::new(event_type, json!({ "type": event_type }))
+    }
+
+    #[tokio::test]
+    async fn test_streams_persist_across_instances() {
+        let dir = TempDir::new();
+        let store = FileEventStore::open(&dir.0).await.unwrap();
+        store
+            .append("a", 0, vec![event("1"), event("2")])
+            .await
+            .unwrap();
+
+        let reopened = FileEventStore::open(&dir.0).await.unwrap();
+        assert_eq!(reopened.stream_version("a").await.unwrap(), 2);
+        assert_eq!(reopened.append("a", 2, vec![event("3")]).await.unwrap(), 3);
+
+        let events = reopened.read_stream("a", 2).await.unwrap();
+        let types: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(types, vec!["2", "3"]);
+        assert_eq!(events[1].payload, json!({ "type": "3" }));
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_conflict_after_reopen() {
+        let dir = TempDir::new();
+        FileEventStore::open(&dir.0)
+            .await
+            .unwrap()
+            .append("a", 0, vec![event("1")])
+            .await
+            .unwrap();
+
+        let store = FileEventStore::open(&dir.0).await.unwrap();
+        let err = store.append("a", 0, vec![event("2")]).await.unwrap_err();
+        assert_eq!(err.error_code(), "core.event_store.concurrency_conflict");
+        assert_eq!(store.read_stream("a", 1).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_ids_map_to_safe_file_names() {
+        let dir = TempDir::new();
+        let store = FileEventStore::open(&dir.0).await.unwrap();
+        store
+            .append("../tenant/1", 0, vec![event("1")])
+            .await
+            .unwrap();
+
+        let path = store.stream_path("../tenant/1");
+        assert_eq!(path.parent(), Some(dir.0.as_path()));
+        assert_eq!(path.file_name().unwrap(), "%2E%2E%2Ftenant%2F1.jsonl");
+        assert!(path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_corrupted_line_is_reported() {
+        let dir = TempDir::new();
+        let store = FileEventStore::open(&dir.0).await.unwrap();
+        store.append("a", 0, vec![event("1")]).await.unwrap();
+        let path = store.stream_path("a");
+        let mut contents = std::fs::read_to_string(&path).unwrap();
+        contents.push_str("{not json\n");
+        std::fs::write(&path, contents).unwrap();
+
+        let err = store.read_stream("a", 1).await.unwrap_err();
+        assert_eq!(err.error_code(), "core.event_store.corrupted");
+        assert!(err.to_string().contains("line 2"));
+    }
+
+    #[tokio::test]
+    async fn test_torn_last_line_is_ignored_and_removed() {
+        let dir = TempDir::new();
+        let store = FileEventStore::open(&dir.0).await.unwrap();
+        store.append("a", 0, vec![event("1")]).await.unwrap();
+        let path = store.stream_path("a");
+        let mut contents = std::fs::read(&path).unwrap();
+        let complete = contents.len();
+        contents.extend_from_slice("{\"id\":\"é".as_bytes().split_last().unwrap().1);
+        std::fs::write(&path, contents).unwrap();
+
+        let reopened = FileEventStore::open(&dir.0).await.unwrap();
+        assert_eq!(reopened.read_stream("a", 1).await.unwrap().len(), 1);
+        assert_eq!(reopened.stream_version("a").await.unwrap(), 1);
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), complete as u64);
+
+        reopened.append("a", 1, vec![event("2")]).await.unwrap();
+        let events = reopened.read_stream("a", 1).await.unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].event_type, "2");
+    }
+
+    /// Stream file whose operations fail on request.
+    #[derive(Default, Clone, Copy)]
+    struct Faults {
+        write: bool,
+        set_len: bool,
+        sync: bool,
+    }
+
+    struct FaultyFile(File, Faults);
+
+    fn injected() -> io::Error {
+        io::Error::other("injected")
+    }
+
+    #[async_trait]
+    impl StreamWriter for FaultyFile {
+        async fn len(&self) -> io::Result<u64> {
+            self.0.len().await
+        }
+
+        async fn write_all(&mut self, buffer: &[u8]) -> io::Result<()> {
+            if !self.1.write {
+                return StreamWriter::write_all(&mut self.0, buffer).await;
+            }
+            StreamWriter::write_all(&mut self.0, &buffer[..buffer.len() - 1]).await?;
+            Err(injected())
+        }
+
+        async fn set_len(&self, len: u64) -> io::Result<()> {
+            if self.1.set_len {
+                return Err(injected());
+            }
+            StreamWriter::set_len(&self.0, len).await
+        }
+
+        async fn sync_data(&self) -> io::Result<()> {
+            if self.1.sync {
+                return Err(injected());
+            }
+            StreamWriter::sync_data(&self.0).await
+        }
+    }
+
+    async fn failing_append(store: &FileEventStore, faults: Faults) -> Box<dyn HexaError> {
+        let open = move |path| async move { Ok(FaultyFile(open_for_append(path).await?, faults)) };
+        store
+            .append_with("a", 1, vec![event("2")], open)
+            .await
+            .unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn test_failed_sync_does_not_allow_duplicate_sequence_numbers() {
+        let dir = TempDir::new();
+        let store = FileEventStore::open(&dir.0).await.unwrap();
+        store.append("a", 0, vec![event("1")]).await.unwrap();
+
+        let faults = Faults {
+            sync: true,
+            ..Faults::default()
+        };
+        assert!(failing_append(&store, faults)
+            .await
+            .to_string()
+            .contains("injected"));
+
+        let err = store.append("a", 1, vec![event("3")]).await.unwrap_err();
+        assert_eq!(err.error_code(), "core.event_store.concurrency_conflict");
+        assert_eq!(store.stream_version("a").await.unwrap(), 2);
+        assert_eq!(store.read_stream("a", 1).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_write_is_cut_off() {
+        let dir = TempDir::new();
+        let store = FileEventStore::open(&dir.0).await.unwrap();
+        store.append("a", 0, vec![event("1")]).await.unwrap();
+        let len = std::fs::metadata(store.stream_path("a")).unwrap().len();
+
+        let faults = Faults {
+            write: true,
+            ..Faults::default()
+        };
+        failing_append(&store, faults).await;
+        assert_eq!(
+            std::fs::metadata(store.stream_path("a")).unwrap().len(),
+            len
+        );
+        assert_eq!(store.append("a", 1, vec![event("2")]).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_cut_off_is_reported_and_repaired_by_next_append() {
+        let dir = TempDir::new();
+        let store = FileEventStore::open(&dir.0).await.unwrap();
+        store.append("a", 0, vec![event("1")]).await.unwrap();
+
+        let faults = Faults {
+            write: true,
+            set_len: true,
+            ..Faults::default()
+        };
+        failing_append(&store, faults).await;
+        assert_eq!(store.read_stream("a", 1).await.unwrap().len(), 1);
+
+        assert_eq!(store.append("a", 1, vec![event("2")]).await.unwrap(), 2);
+        let events = store.read_stream("a", 1).await.unwrap();
+        let types: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(types, vec!["1", "2"]);
+    }
+}
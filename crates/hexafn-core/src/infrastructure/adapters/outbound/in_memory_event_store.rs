@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # In-Memory Event Store (Core Module)
+//!
+//! This module provides [`InMemoryEventStore`], an [`EventStore`] that keeps every
+//! stream in process memory. It is intended for tests and for functions whose
+//! state does not need to survive a restart.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventEnvelope, EventStore, InMemoryEventStore};
+//! use serde_json::json;
+//!
+//! # tokio_test::block_on(async {
+//! let store = InMemoryEventStore::new();
+//! store
+//!     .append("user-7", 0, vec![EventEnvelope::new("user.registered", json!({}))])
+//!     .await
+//!     .unwrap();
+//! assert_eq!(store.stream_version("user-7").await.unwrap(), 1);
+//! # });
+//! ```
+
+use crate::domain::contracts::{stamp_batch, EventStore, HexaError};
+use crate::domain::value_objects::EventEnvelope;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Event store backed by a shared in-process map.
+///
+/// Clones share the same streams.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryEventStore {
+    streams: Arc<Mutex<HashMap<String, Vec<EventEnvelope>>>>,
+}
+
+impl InMemoryEventStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ids of all streams holding at least one event, in no particular order.
+    pub fn aggregate_ids(&self) -> Vec<String> {
+        self.lock().keys().cloned().collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Vec<EventEnvelope>>> {
+        // A panic while holding the lock cannot leave a stream half-appended,
+        // since batches are validated before being extended in one step.
+        self.streams
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append(
+        &self,
+        aggregate_id: &str,
+        expected_version: u64,
+        events: Vec<EventEnvelope>,
+    ) -> Result<u64, Box<dyn HexaError>> {
+        let mut streams = self.lock();
+        let current = streams.get(aggregate_id).map_or(0, |s| s.len() as u64);
+        let stamped = stamp_batch(aggregate_id, expected_version, current, events)?;
+        if stamped.is_empty() {
+            return Ok(current);
+        }
+        let stream = streams.entry(aggregate_id.to_string()).or_default();
+        stream.extend(stamped);
+        Ok(stream.len() as u64)
+    }
+
+    async fn read_stream(
+        &self,
+        aggregate_id: &str,
+        from_sequence: u64,
+    ) -> Result<Vec<EventEnvelope>, Box<dyn HexaError>> {
+        let streams = self.lock();
+        let Some(stream) = streams.get(aggregate_id) else {
+            return Ok(Vec::new());
+        };
+        let skip = from_sequence.saturating_sub(1).min(stream.len() as u64) as usize;
+        Ok(stream[skip..].to_vec())
+    }
+
+    async fn stream_version(&self, aggregate_id: &str) -> Result<u64, Box<dyn HexaError>> {
+        Ok(self.lock().get(aggregate_id).map_or(0, |s| s.len() as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(event_type: &str) -> EventEnvelope {
+        EventEnvelope::new(event_type, json!({}))
+    }
+
+    #[tokio::test]
+    async fn test_append_stamps_sequence_numbers() {
+        let store = InMemoryEventStore::new();
+        assert_eq!(
+            store
+                .append("a", 0, vec![event("x"), event("y")])
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(store.append("a", 2, vec![event("z")]).await.unwrap(), 3);
+
+        let events = store.read_stream("a", 0).await.unwrap();
+        let sequences: Vec<u64> = events.iter().map(|e| e.sequence_number).collect();
+        assert_eq!(sequences, vec![1, 2, 3]);
+        assert!(events
+            .iter()
+            .all(|e| e.aggregate_id.as_deref() == Some("a")));
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_conflict_writes_nothing() {
+        let store = InMemoryEventStore::new();
+        store.append("a", 0, vec![event("x")]).await.unwrap();
+
+        let err = store.append("a", 0, vec![event("y")]).await.unwrap_err();
+        assert_eq!(err.error_code(), "core.event_store.concurrency_conflict");
+        assert_eq!(store.stream_version("a").await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_mismatch_is_rejected() {
+        let store = InMemoryEventStore::new();
+        let foreign = event("x").with_aggregate("b", 9);
+        let err = store
+            .append("a", 0, vec![event("ok"), foreign])
+            .await
+            .unwrap_err();
+        assert_eq!(err.error_code(), "core.event_store.aggregate_mismatch");
+        assert!(store.read_stream("a", 1).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_read_from_sequence() {
+        let store = InMemoryEventStore::new();
+        store
+            .append("a", 0, vec![event("1"), event("2"), event("3")])
+            .await
+            .unwrap();
+
+        let tail = store.read_stream("a", 2).await.unwrap();
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail[0].event_type, "2");
+        assert!(store.read_stream("a", 10).await.unwrap().is_empty());
+        assert!(store.read_stream("missing", 1).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_empty_append_checks_version_only() {
+        let store = InMemoryEventStore::new();
+        assert_eq!(store.append("a", 0, Vec::new()).await.unwrap(), 0);
+        assert!(store.append("a", 1, Vec::new()).await.is_err());
+        assert!(store.aggregate_ids().is_empty());
+    }
+}
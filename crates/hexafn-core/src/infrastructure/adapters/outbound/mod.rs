@@ -0,0 +1,8 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod file_event_store;
+mod in_memory_event_store;
+
+pub use file_event_store::FileEventStore;
+pub use in_memory_event_store::InMemoryEventStore;
@@ -0,0 +1,544 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # CloudEvent DTO (Core Module)
+//!
+//! This module defines [`CloudEvent`], a CloudEvents 1.0 data transfer object,
+//! and [`BinaryCloudEvent`], its binary content mode representation.
+//!
+//! - **Structured mode** carries the whole event as one JSON document
+//!   (`application/cloudevents+json`), see [`CloudEvent::to_structured`].
+//! - **Binary mode** carries context attributes as `ce-` headers and the data as
+//!   the message body, see [`CloudEvent::to_binary`]. Header values are
+//!   percent-encoded as the HTTP protocol binding requires.
+//!
+//! Both decoders validate the result against the specification. Use
+//! [`CloudEventMapper`](crate::CloudEventMapper) to convert to and from hexaFn
+//! events.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::CloudEvent;
+//! use serde_json::json;
+//!
+//! let mut event = CloudEvent::new("A234-1234-1234", "/sensors/tn-1", "com.example.reading");
+//! event.data = Some(json!({ "celsius": 21.5 }));
+//! event.extensions.insert("region".to_string(), json!("eu-west"));
+//!
+//! let binary = event.to_binary().unwrap();
+//! assert_eq!(binary.headers["ce-type"], "com.example.reading");
+//! assert_eq!(binary.headers["ce-region"], "eu-west");
+//!
+//! let decoded = CloudEvent::from_binary(&binary).unwrap();
+//! assert_eq!(decoded, event);
+//!
+//! let json = event.to_structured().unwrap();
+//! assert_eq!(CloudEvent::from_structured(&json).unwrap(), event);
+//! ```
+
+use crate::infrastructure::dtos::CloudEventError;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{DateTime, SecondsFormat, Utc};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Characters the HTTP binding requires to be percent-encoded in header values.
+const HEADER_VALUE: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'%');
+
+/// Context attribute names that extensions must not reuse.
+const RESERVED_ATTRIBUTES: [&str; 9] = [
+    "specversion",
+    "id",
+    "source",
+    "type",
+    "datacontenttype",
+    "dataschema",
+    "subject",
+    "time",
+    "data",
+];
+
+/// CloudEvents 1.0 event.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CloudEvent {
+    /// CloudEvents specification version, always `1.0`
+    pub specversion: String,
+    /// Event identifier, unique within `source`
+    pub id: String,
+    /// URI-reference identifying the event producer
+    pub source: String,
+    /// Event type, e.g. `com.example.object.created`
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// Media type of `data`; JSON when absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datacontenttype: Option<String>,
+    /// URI of the schema `data` adheres to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dataschema: Option<String>,
+    /// Subject of the event within the producer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// When the occurrence happened
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime<Utc>>,
+    /// Event data as JSON
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+    /// Binary event data, base64-encoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_base64: Option<String>,
+    /// Extension attributes (booleans, 32-bit integers or strings)
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, Value>,
+}
+
+/// A CloudEvent in binary content mode: `ce-` headers plus the raw body.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BinaryCloudEvent {
+    /// Message headers with lowercase names
+    pub headers: BTreeMap<String, String>,
+    /// Message body holding the event data
+    pub body: Vec<u8>,
+}
+
+impl CloudEvent {
+    /// The only supported specification version.
+    pub const SPEC_VERSION: &'static str = "1.0";
+    /// Media type of structured mode messages.
+    pub const STRUCTURED_CONTENT_TYPE: &'static str = "application/cloudevents+json";
+
+    /// Create an event with the required attributes.
+    pub fn new(
+        id: impl Into<String>,
+        source: impl Into<String>,
+        event_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            specversion: Self::SPEC_VERSION.to_string(),
+            id: id.into(),
+            source: source.into(),
+            event_type: event_type.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Extension attribute value for `name`, if present.
+    pub fn extension(&self, name: &str) -> Option<&Value> {
+        self.extensions.get(name)
+    }
+
+    /// Check the event against the CloudEvents 1.0 specification.
+    ///
+    /// # Errors
+    ///
+    /// The first [`CloudEventError`] found.
+    pub fn validate(&self) -> Result<(), CloudEventError> {
+        if self.specversion.is_empty() {
+            return Err(missing("specversion"));
+        }
+        if self.specversion != Self::SPEC_VERSION {
+            return Err(CloudEventError::UnsupportedSpecVersion {
+                version: self.specversion.clone(),
+            });
+        }
+        for (name, value) in [
+            ("id", &self.id),
+            ("source", &self.source),
+            ("type", &self.event_type),
+        ] {
+            if value.is_empty() {
+                return Err(missing(name));
+            }
+        }
+        if let Some(content_type) = &self.datacontenttype {
+            if !content_type.contains('/') {
+                return Err(invalid("datacontenttype", "must be an RFC 2046 media type"));
+            }
+        }
+        if let Some(schema) = &self.dataschema {
+            if !schema.contains(':') {
+                return Err(invalid("dataschema", "must be an absolute URI"));
+            }
+        }
+        if self.subject.as_deref() == Some("") {
+            return Err(invalid("subject", "must not be empty"));
+        }
+        if self.data.is_some() && self.data_base64.is_some() {
+            return Err(CloudEventError::ConflictingData);
+        }
+        for (name, value) in &self.extensions {
+            validate_extension(name, value)?;
+        }
+        Ok(())
+    }
+
+    /// Encode in structured content mode.
+    ///
+    /// # Errors
+    ///
+    /// A [`CloudEventError`] if the event is invalid.
+    pub fn to_structured(&self) -> Result<Vec<u8>, CloudEventError> {
+        self.validate()?;
+        serde_json::to_vec(self).map_err(|error| CloudEventError::InvalidData {
+            reason: error.to_string(),
+        })
+    }
+
+    /// Decode and validate a structured content mode message.
+    ///
+    /// Attributes with `null` values are treated as absent.
+    ///
+    /// # Errors
+    ///
+    /// A [`CloudEventError`] if the message is not valid JSON or not a valid event.
+    pub fn from_structured(bytes: &[u8]) -> Result<Self, CloudEventError> {
+        let mut event: CloudEvent =
+            serde_json::from_slice(bytes).map_err(|error| CloudEventError::InvalidData {
+                reason: error.to_string(),
+            })?;
+        event.extensions.retain(|_, value| !value.is_null());
+        event.validate()?;
+        Ok(event)
+    }
+
+    /// Encode in binary content mode.
+    ///
+    /// The `content-type` header carries `datacontenttype` and is omitted when
+    /// that attribute is absent.
+    ///
+    /// # Errors
+    ///
+    /// A [`CloudEventError`] if the event is invalid.
+    pub fn to_binary(&self) -> Result<BinaryCloudEvent, CloudEventError> {
+        self.validate()?;
+        let mut headers = BTreeMap::new();
+        let mut put = |name: &str, value: &str| {
+            headers.insert(
+                format!("ce-{}", name),
+                utf8_percent_encode(value, HEADER_VALUE).to_string(),
+            );
+        };
+        put("specversion", &self.specversion);
+        put("id", &self.id);
+        put("source", &self.source);
+        put("type", &self.event_type);
+        if let Some(schema) = &self.dataschema {
+            put("dataschema", schema);
+        }
+        if let Some(subject) = &self.subject {
+            put("subject", subject);
+        }
+        if let Some(time) = &self.time {
+            put("time", &time.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+        }
+        for (name, value) in &self.extensions {
+            match value {
+                Value::String(text) => put(name, text),
+                other => put(name, &other.to_string()),
+            }
+        }
+        if let Some(content_type) = &self.datacontenttype {
+            headers.insert("content-type".to_string(), content_type.clone());
+        }
+
+        let body = match (&self.data, &self.data_base64) {
+            (_, Some(encoded)) => {
+                BASE64
+                    .decode(encoded)
+                    .map_err(|error| CloudEventError::InvalidData {
+                        reason: error.to_string(),
+                    })?
+            }
+            (Some(Value::String(text)), None) if !self.is_json() => text.as_bytes().to_vec(),
+            (Some(data), None) => {
+                serde_json::to_vec(data).map_err(|error| CloudEventError::InvalidData {
+                    reason: error.to_string(),
+                })?
+            }
+            (None, None) => Vec::new(),
+        };
+        Ok(BinaryCloudEvent { headers, body })
+    }
+
+    /// Decode and validate a binary content mode message.
+    ///
+    /// Header names are matched case-insensitively. Extension values arrive as
+    /// strings, since binary mode does not carry attribute types.
+    ///
+    /// # Errors
+    ///
+    /// A [`CloudEventError`] if a header or the body cannot be decoded, or the
+    /// result is not a valid event.
+    pub fn from_binary(message: &BinaryCloudEvent) -> Result<Self, CloudEventError> {
+        let mut event = CloudEvent::default();
+        for (name, raw) in &message.headers {
+            let name = name.to_ascii_lowercase();
+            if name == "content-type" {
+                event.datacontenttype = Some(raw.clone());
+                continue;
+            }
+            let Some(attribute) = name.strip_prefix("ce-") else {
+                continue;
+            };
+            let value = percent_decode_str(raw)
+                .decode_utf8()
+                .map_err(|error| invalid(attribute, &error.to_string()))?
+                .into_owned();
+            match attribute {
+                "specversion" => event.specversion = value,
+                "id" => event.id = value,
+                "source" => event.source = value,
+                "type" => event.event_type = value,
+                "dataschema" => event.dataschema = Some(value),
+                "subject" => event.subject = Some(value),
+                "time" => {
+                    let time = DateTime::parse_from_rfc3339(&value)
+                        .map_err(|error| invalid("time", &error.to_string()))?;
+                    event.time = Some(time.with_timezone(&Utc));
+                }
+                extension => {
+                    event
+                        .extensions
+                        .insert(extension.to_string(), Value::String(value));
+                }
+            }
+        }
+
+        if !message.body.is_empty() {
+            match event.datacontenttype.as_deref() {
+                None => match serde_json::from_slice(&message.body) {
+                    Ok(data) => event.data = Some(data),
+                    Err(_) => event.data_base64 = Some(BASE64.encode(&message.body)),
+                },
+                Some(_) if event.is_json() => {
+                    let data = serde_json::from_slice(&message.body).map_err(|error| {
+                        CloudEventError::InvalidData {
+                            reason: error.to_string(),
+                        }
+                    })?;
+                    event.data = Some(data);
+                }
+                Some(_) if event.is_text() => {
+                    let text = String::from_utf8(message.body.clone()).map_err(|error| {
+                        CloudEventError::InvalidData {
+                            reason: error.to_string(),
+                        }
+                    })?;
+                    event.data = Some(Value::String(text));
+                }
+                Some(_) => event.data_base64 = Some(BASE64.encode(&message.body)),
+            }
+        }
+        event.validate()?;
+        Ok(event)
+    }
+
+    fn media_type(&self) -> Option<String> {
+        self.datacontenttype.as_deref().map(|content_type| {
+            content_type
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase()
+        })
+    }
+
+    /// Whether `data` is JSON: `datacontenttype` is absent or a JSON media type.
+    pub fn is_json(&self) -> bool {
+        self.media_type().map_or(true, |media_type| {
+            media_type == "application/json"
+                || media_type == "text/json"
+                || media_type.ends_with("+json")
+        })
+    }
+
+    fn is_text(&self) -> bool {
+        self.media_type().is_some_and(|media_type| {
+            media_type.starts_with("text/")
+                || media_type.ends_with("/xml")
+                || media_type.ends_with("+xml")
+        })
+    }
+}
+
+fn missing(name: &str) -> CloudEventError {
+    CloudEventError::MissingAttribute {
+        name: name.to_string(),
+    }
+}
+
+fn invalid(name: &str, reason: &str) -> CloudEventError {
+    CloudEventError::InvalidAttribute {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Check that `name` and `value` form a valid extension attribute.
+pub(crate) fn validate_extension(name: &str, value: &Value) -> Result<(), CloudEventError> {
+    let reject = |reason: &str| CloudEventError::InvalidExtension {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    };
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    {
+        return Err(reject("names must consist of lowercase letters and digits"));
+    }
+    if RESERVED_ATTRIBUTES.contains(&name) {
+        return Err(reject("name is reserved for a context attribute"));
+    }
+    match value {
+        Value::Bool(_) | Value::String(_) => Ok(()),
+        Value::Number(number) => match number.as_i64() {
+            Some(integer) if i32::try_from(integer).is_ok() => Ok(()),
+            _ => Err(reject("integers must fit in 32 bits")),
+        },
+        _ => Err(reject("values must be booleans, integers or strings")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::HexaError;
+    use serde_json::json;
+
+    fn sample() -> CloudEvent {
+        let mut event = CloudEvent::new("evt-1", "https://example.com/orders", "order.placed");
+        event.subject = Some("order 1".to_string());
+        event.time = Some(
+            DateTime::parse_from_rfc3339("2025-03-01T12:00:00.250Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+        event.datacontenttype = Some("application/json".to_string());
+        event.data = Some(json!({ "total": 42 }));
+        event.extensions.insert("tenant".to_string(), json!("acme"));
+        event.extensions.insert("priority".to_string(), json!(5));
+        event
+    }
+
+    #[test]
+    fn test_structured_roundtrip_and_shape() {
+        let event = sample();
+        let bytes = event.to_structured().unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(value["specversion"], "1.0");
+        assert_eq!(value["type"], "order.placed");
+        assert_eq!(value["tenant"], "acme");
+        assert!(value.get("data_base64").is_none());
+        assert_eq!(CloudEvent::from_structured(&bytes).unwrap(), event);
+    }
+
+    #[test]
+    fn test_structured_null_attributes_are_absent() {
+        let event = CloudEvent::from_structured(
+            br#"{"specversion":"1.0","id":"1","source":"/s","type":"t","data":null,"traceparent":null}"#,
+        )
+        .unwrap();
+        assert!(event.data.is_none());
+        assert!(event.extensions.is_empty());
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let event = sample();
+        let binary = event.to_binary().unwrap();
+        assert_eq!(binary.headers["ce-subject"], "order%201");
+        assert_eq!(binary.headers["ce-time"], "2025-03-01T12:00:00.250Z");
+        assert_eq!(binary.headers["content-type"], "application/json");
+        assert_eq!(binary.body, br#"{"total":42}"#);
+
+        let decoded = CloudEvent::from_binary(&binary).unwrap();
+        assert_eq!(decoded.subject, event.subject);
+        assert_eq!(decoded.time, event.time);
+        assert_eq!(decoded.data, event.data);
+        // Binary mode carries extension values as strings.
+        assert_eq!(decoded.extension("priority"), Some(&json!("5")));
+    }
+
+    #[test]
+    fn test_binary_headers_are_case_insensitive() {
+        let message = BinaryCloudEvent {
+            headers: BTreeMap::from([
+                ("CE-SpecVersion".to_string(), "1.0".to_string()),
+                ("Ce-Id".to_string(), "1".to_string()),
+                ("ce-source".to_string(), "/s".to_string()),
+                ("ce-type".to_string(), "t".to_string()),
+                ("Content-Type".to_string(), "text/plain".to_string()),
+            ]),
+            body: b"hello".to_vec(),
+        };
+        let event = CloudEvent::from_binary(&message).unwrap();
+        assert_eq!(event.id, "1");
+        assert_eq!(event.data, Some(json!("hello")));
+        assert_eq!(event.to_binary().unwrap().body, b"hello");
+    }
+
+    #[test]
+    fn test_binary_data_uses_base64() {
+        let mut event = CloudEvent::new("1", "/s", "blob.stored");
+        event.datacontenttype = Some("application/octet-stream".to_string());
+        event.data_base64 = Some(BASE64.encode([0u8, 159, 146, 150]));
+
+        let binary = event.to_binary().unwrap();
+        assert_eq!(binary.body, vec![0u8, 159, 146, 150]);
+        assert_eq!(CloudEvent::from_binary(&binary).unwrap(), event);
+    }
+
+    #[test]
+    fn test_validation_follows_spec() {
+        type Mutation = Box<dyn Fn(&mut CloudEvent)>;
+        let cases: Vec<(Mutation, &str)> = vec![
+            (
+                Box::new(|e| e.specversion = "0.3".to_string()),
+                "core.cloud_event.unsupported_spec_version",
+            ),
+            (
+                Box::new(|e| e.source.clear()),
+                "core.cloud_event.missing_attribute",
+            ),
+            (
+                Box::new(|e| e.datacontenttype = Some("json".to_string())),
+                "core.cloud_event.invalid_attribute",
+            ),
+            (
+                Box::new(|e| e.data_base64 = Some("AA==".to_string())),
+                "core.cloud_event.conflicting_data",
+            ),
+            (
+                Box::new(|e| {
+                    e.extensions.insert("Tenant".to_string(), json!("x"));
+                }),
+                "core.cloud_event.invalid_extension",
+            ),
+            (
+                Box::new(|e| {
+                    e.extensions.insert("big".to_string(), json!(1u64 << 40));
+                }),
+                "core.cloud_event.invalid_extension",
+            ),
+            (
+                Box::new(|e| {
+                    e.extensions.insert("nested".to_string(), json!({ "a": 1 }));
+                }),
+                "core.cloud_event.invalid_extension",
+            ),
+        ];
+        for (mutate, code) in cases {
+            let mut event = sample();
+            mutate(&mut event);
+            assert_eq!(event.validate().unwrap_err().error_code(), code);
+        }
+    }
+}
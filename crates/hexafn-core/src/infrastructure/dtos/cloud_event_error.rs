@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # CloudEvent Errors (Core Module)
+//!
+//! This module defines [`CloudEventError`], the concrete [`HexaError`] raised when a
+//! CloudEvents 1.0 message is malformed or cannot be mapped to a hexaFn event.
+//!
+//! All variants use hierarchical `core.cloud_event.*` error codes.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{CloudEventError, HexaError, HexaErrorKind};
+//!
+//! let error = CloudEventError::MissingAttribute { name: "source".to_string() };
+//! assert_eq!(error.error_code(), "core.cloud_event.missing_attribute");
+//! assert_eq!(error.error_kind(), HexaErrorKind::Validation);
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use thiserror::Error;
+
+/// Errors produced while validating, encoding or decoding CloudEvents.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CloudEventError {
+    /// The `specversion` attribute is not `1.0`.
+    #[error("unsupported CloudEvents specversion '{version}'")]
+    UnsupportedSpecVersion {
+        /// The rejected version
+        version: String,
+    },
+
+    /// A required context attribute is absent or empty.
+    #[error("required CloudEvents attribute '{name}' is missing")]
+    MissingAttribute {
+        /// Attribute name
+        name: String,
+    },
+
+    /// A context attribute has an invalid value.
+    #[error("CloudEvents attribute '{name}' is invalid: {reason}")]
+    InvalidAttribute {
+        /// Attribute name
+        name: String,
+        /// Why it was rejected
+        reason: String,
+    },
+
+    /// An extension attribute has an invalid name or value.
+    #[error("CloudEvents extension '{name}' is invalid: {reason}")]
+    InvalidExtension {
+        /// Extension name
+        name: String,
+        /// Why it was rejected
+        reason: String,
+    },
+
+    /// Both `data` and `data_base64` are present.
+    #[error("CloudEvents 'data' and 'data_base64' are mutually exclusive")]
+    ConflictingData,
+
+    /// The event data or message body cannot be decoded.
+    #[error("CloudEvents data cannot be decoded: {reason}")]
+    InvalidData {
+        /// Underlying decoding error
+        reason: String,
+    },
+}
+
+impl HexaError for CloudEventError {
+    fn error_code(&self) -> &str {
+        match self {
+            CloudEventError::UnsupportedSpecVersion { .. } => {
+                "core.cloud_event.unsupported_spec_version"
+            }
+            CloudEventError::MissingAttribute { .. } => "core.cloud_event.missing_attribute",
+            CloudEventError::InvalidAttribute { .. } => "core.cloud_event.invalid_attribute",
+            CloudEventError::InvalidExtension { .. } => "core.cloud_event.invalid_extension",
+            CloudEventError::ConflictingData => "core.cloud_event.conflicting_data",
+            CloudEventError::InvalidData { .. } => "core.cloud_event.invalid_data",
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            CloudEventError::UnsupportedSpecVersion { .. } => {
+                "Only CloudEvents specversion 1.0 is supported"
+            }
+            CloudEventError::MissingAttribute { .. } => "Required CloudEvents attribute is missing",
+            CloudEventError::InvalidAttribute { .. } => "CloudEvents attribute is invalid",
+            CloudEventError::InvalidExtension { .. } => "CloudEvents extension is invalid",
+            CloudEventError::ConflictingData => "CloudEvents data is ambiguous",
+            CloudEventError::InvalidData { .. } => "CloudEvents data cannot be decoded",
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        match self {
+            CloudEventError::InvalidData { .. } => HexaErrorKind::Serialization,
+            _ => HexaErrorKind::Validation,
+        }
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        HexaErrorSeverity::Medium
+    }
+}
@@ -0,0 +1,14 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod cloud_event;
+mod cloud_event_error;
+mod pipeline_config;
+mod pipeline_config_error;
+
+pub(crate) use cloud_event::validate_extension;
+pub use cloud_event::{BinaryCloudEvent, CloudEvent};
+pub use cloud_event_error::CloudEventError;
+pub use pipeline_config::{ConfigFormat, PipelineConfig, PipelineStageConfig};
+pub(crate) use pipeline_config::{ConfigSource, INLINE_SOURCE};
+pub use pipeline_config_error::{ConfigLocation, PipelineConfigError};
@@ -0,0 +1,577 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Pipeline Config DTO (Core Module)
+//!
+//! This module defines [`PipelineConfig`] and [`PipelineStageConfig`], the file
+//! format for declaring a 6F pipeline outside of Rust code, and [`ConfigFormat`],
+//! the supported encodings: TOML, YAML and JSON.
+//!
+//! Each stage entry gives its lifecycle `type`, the `name` the stage is
+//! registered under in a [`PipelineStageRegistry`](crate::PipelineStageRegistry),
+//! and optional `params` checked against the stage's declared schema. Unknown
+//! keys are rejected so that typos surface as errors instead of being ignored.
+//! Parse errors carry the line and column of the offending input; see
+//! [`PipelineConfigError`].
+//!
+//! Use [`PipelineLoader`](crate::PipelineLoader) to turn a config into a
+//! runnable pipeline.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{ConfigFormat, PipelineConfig, PipelineStageType};
+//!
+//! let config = PipelineConfig::parse(
+//!     r#"
+//! name = "orders"
+//! max_retries = 1
+//!
+//! [[stages]]
+//! type = "feed"
+//! name = "http.feed"
+//! params = { path = "/orders" }
+//!
+//! [[stages]]
+//! type = "function"
+//! name = "orders.total"
+//! "#,
+//!     ConfigFormat::Toml,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(config.name.as_deref(), Some("orders"));
+//! assert_eq!(config.stages[0].stage_type, PipelineStageType::Feed);
+//! assert_eq!(config.stages[0].params["path"], "/orders");
+//! assert!(config.stages[1].params.is_null());
+//! ```
+
+use crate::domain::contracts::PipelineStageType;
+use crate::infrastructure::dtos::{ConfigLocation, PipelineConfigError};
+use serde::de::{
+    DeserializeSeed, Deserializer, Error as _, IgnoredAny, MapAccess, SeqAccess, Visitor,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::path::Path;
+
+/// Source name used for configs parsed from memory.
+pub(crate) const INLINE_SOURCE: &str = "<string>";
+
+/// Encoding of a pipeline config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigFormat {
+    /// TOML, `.toml`
+    Toml,
+    /// YAML, `.yaml` or `.yml`
+    Yaml,
+    /// JSON, `.json`
+    Json,
+}
+
+impl ConfigFormat {
+    /// Detect the format from the file extension, ignoring case.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "toml" => Some(ConfigFormat::Toml),
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "json" => Some(ConfigFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Declarative description of a 6F pipeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineConfig {
+    /// Optional pipeline name, for logs and diagnostics
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Overrides [`SixFPipeline::DEFAULT_MAX_RETRIES`](crate::SixFPipeline::DEFAULT_MAX_RETRIES)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+    /// Budget for each run, in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline_ms: Option<u64>,
+    /// Stage entries; their order in the file does not matter
+    #[serde(default)]
+    pub stages: Vec<PipelineStageConfig>,
+}
+
+/// One stage entry of a [`PipelineConfig`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineStageConfig {
+    /// Lifecycle slot the stage fills
+    #[serde(rename = "type")]
+    pub stage_type: PipelineStageType,
+    /// Name the stage is registered under
+    pub name: String,
+    /// Factory parameters; `null` when absent
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub params: Value,
+}
+
+impl PipelineConfig {
+    /// Parse a config from `text`.
+    ///
+    /// # Errors
+    ///
+    /// [`PipelineConfigError::Parse`] with the line and column of the first
+    /// syntax or schema error.
+    pub fn parse(text: &str, format: ConfigFormat) -> Result<Self, PipelineConfigError> {
+        Self::parse_source(INLINE_SOURCE, text, format)
+    }
+
+    /// Parse a config from `text`, naming `file` in error locations.
+    pub(crate) fn parse_source(
+        file: &str,
+        text: &str,
+        format: ConfigFormat,
+    ) -> Result<Self, PipelineConfigError> {
+        let (message, location) = match format {
+            ConfigFormat::Toml => match toml::from_str(text) {
+                Ok(config) => return Ok(config),
+                Err(e) => {
+                    let location = match e.span() {
+                        Some(span) => offset_location(file, text, span.start),
+                        None => ConfigLocation::new(file),
+                    };
+                    (e.message().to_string(), location)
+                }
+            },
+            ConfigFormat::Yaml => match serde_yaml::from_str(text) {
+                Ok(config) => return Ok(config),
+                Err(e) => {
+                    let location = match e.location() {
+                        Some(at) => ConfigLocation::at(file, at.line(), at.column()),
+                        None => ConfigLocation::new(file),
+                    };
+                    (e.to_string(), location)
+                }
+            },
+            ConfigFormat::Json => match serde_json::from_str(text) {
+                Ok(config) => return Ok(config),
+                Err(e) if e.line() == 0 => (e.to_string(), ConfigLocation::new(file)),
+                Err(e) => (
+                    e.to_string(),
+                    ConfigLocation::at(file, e.line(), e.column().max(1)),
+                ),
+            },
+        };
+        Err(PipelineConfigError::Parse {
+            message: strip_position(&message, &location),
+            location,
+        })
+    }
+}
+
+/// Text a config was parsed from, used to locate entries for error messages.
+///
+/// Locations are only looked up when an error is reported, one entry at a time.
+/// TOML values carry their spans. The YAML and JSON parsers only report
+/// positions with errors, so an entry is located by deserializing the text up
+/// to it and failing there on purpose: YAML marks the scalar it has just read,
+/// JSON reports the last character it consumed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ConfigSource<'a> {
+    file: &'a str,
+    text: Option<(&'a str, ConfigFormat)>,
+}
+
+impl<'a> ConfigSource<'a> {
+    /// Source `file` holding `text` in `format`.
+    pub(crate) fn new(file: &'a str, text: &'a str, format: ConfigFormat) -> Self {
+        Self {
+            file,
+            text: Some((text, format)),
+        }
+    }
+
+    /// Source of an already parsed config, whose locations have no line.
+    pub(crate) fn parsed(file: &'a str) -> Self {
+        Self { file, text: None }
+    }
+
+    /// Location of the `stages` entry, or of the file if it has none.
+    pub(crate) fn stages(&self) -> ConfigLocation {
+        self.locate(Target::Stages)
+    }
+
+    /// Location of the `name` of stage entry `index`.
+    pub(crate) fn stage(&self, index: usize) -> ConfigLocation {
+        self.locate(Target::Name(index))
+    }
+
+    fn locate(&self, target: Target) -> ConfigLocation {
+        let file = self.file;
+        let Some((text, format)) = self.text else {
+            return ConfigLocation::new(file);
+        };
+        let located = match format {
+            ConfigFormat::Toml => toml::from_str::<SpannedStages>(text)
+                .ok()
+                .and_then(|spanned| {
+                    let stages = spanned.stages?;
+                    match target {
+                        Target::Stages => Some(stages.span().start),
+                        Target::Name(index) => Some(stages.get_ref().get(index)?.name.span().start),
+                    }
+                })
+                .map(|offset| offset_location(file, text, offset)),
+            ConfigFormat::Yaml => {
+                let probe = Probe {
+                    target,
+                    read_name: true,
+                };
+                let error = probe
+                    .deserialize(serde_yaml::Deserializer::from_str(text))
+                    .err();
+                error
+                    .filter(|e| is_probe(&e.to_string()))
+                    .and_then(|e| e.location())
+                    .map(|at| ConfigLocation::at(file, at.line(), at.column()))
+            }
+            ConfigFormat::Json => {
+                let probe = Probe {
+                    target,
+                    read_name: false,
+                };
+                let error = probe
+                    .deserialize(&mut serde_json::Deserializer::from_str(text))
+                    .err();
+                let error = error.filter(|e| is_probe(&e.to_string()) && e.line() > 0);
+                error.map(|e| {
+                    let column = match target {
+                        // Failed on the closing quote of `"stages"`
+                        Target::Stages => (e.column() + 1).saturating_sub(STAGES_KEY.len()),
+                        // Failed after the character before the name
+                        Target::Name(_) => e.column() + 1,
+                    };
+                    ConfigLocation::at(file, e.line(), column.max(1))
+                })
+            }
+        };
+        located.unwrap_or_else(|| ConfigLocation::new(file))
+    }
+}
+
+#[derive(Deserialize)]
+struct SpannedStages {
+    stages: Option<toml::Spanned<Vec<SpannedStage>>>,
+}
+
+#[derive(Deserialize)]
+struct SpannedStage {
+    name: toml::Spanned<String>,
+}
+
+/// Entry a [`Probe`] stops at.
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    /// The value of the `stages` key
+    Stages,
+    /// The `name` of the stage entry at this index
+    Name(usize),
+}
+
+/// The `stages` key as written in JSON.
+const STAGES_KEY: &str = "\"stages\"";
+
+/// Message of the error a [`Probe`] fails with.
+const PROBE: &str = "stage name located";
+
+fn is_probe(message: &str) -> bool {
+    message.contains(PROBE)
+}
+
+/// Deserializes a config up to its target and fails there, after reading a
+/// stage name as a string if `read_name` is set.
+#[derive(Clone, Copy)]
+struct Probe {
+    target: Target,
+    read_name: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for Probe {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Probe {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a pipeline config")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let stop_at_key = matches!(self.target, Target::Stages);
+        while let Some(is_stages) = map.next_key_seed(KeyProbe(stop_at_key))? {
+            if let (true, Target::Name(index)) = (is_stages, self.target) {
+                return map.next_value_seed(StagesProbe {
+                    index,
+                    read_name: self.read_name,
+                });
+            }
+            map.next_value::<IgnoredAny>()?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a root key, telling whether it is `stages`, and fails on `stages` if
+/// the flag is set.
+struct KeyProbe(bool);
+
+impl<'de> DeserializeSeed<'de> for KeyProbe {
+    type Value = bool;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<bool, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeyProbe {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a key")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, key: &str) -> Result<bool, E> {
+        match key == "stages" {
+            true if self.0 => Err(E::custom(PROBE)),
+            is_stages => Ok(is_stages),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct StagesProbe {
+    index: usize,
+    read_name: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for StagesProbe {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for StagesProbe {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a list of stages")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        for _ in 0..self.index {
+            if seq.next_element::<IgnoredAny>()?.is_none() {
+                return Ok(());
+            }
+        }
+        seq.next_element_seed(StageProbe(self.read_name))?;
+        Ok(())
+    }
+}
+
+struct StageProbe(bool);
+
+impl<'de> DeserializeSeed<'de> for StageProbe {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for StageProbe {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a stage")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "name" {
+                return map.next_value_seed(Fail(self.0));
+            }
+            map.next_value::<IgnoredAny>()?;
+        }
+        Ok(())
+    }
+}
+
+/// Fails on the value, after reading it as a string if the flag is set.
+struct Fail(bool);
+
+impl<'de> DeserializeSeed<'de> for Fail {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        if self.0 {
+            deserializer.deserialize_str(self)
+        } else {
+            Err(D::Error::custom(PROBE))
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for Fail {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a stage name")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, _name: &str) -> Result<(), E> {
+        Err(E::custom(PROBE))
+    }
+}
+
+/// Convert a byte offset into a 1-based line and column.
+fn offset_location(file: &str, text: &str, offset: usize) -> ConfigLocation {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    ConfigLocation::at(file, line, column)
+}
+
+/// Remove the ` at line L column C` suffix some parsers append, since the
+/// location is reported separately.
+fn strip_position(message: &str, location: &ConfigLocation) -> String {
+    let trimmed = match (location.line, location.column) {
+        (Some(line), Some(column)) => message
+            .strip_suffix(&format!(" at line {line} column {column}"))
+            .unwrap_or(message),
+        _ => message,
+    };
+    trimmed.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_formats_parse_to_the_same_config() {
+        let toml = r#"
+deadline_ms = 500
+
+[[stages]]
+type = "Feed"
+name = "source"
+params = { topic = "orders" }
+"#;
+        let yaml = "
+deadline_ms: 500
+stages:
+  - type: feed
+    name: source
+    params:
+      topic: orders
+";
+        let json = r#"{ "deadline_ms": 500, "stages": [
+            { "type": "feed", "name": "source", "params": { "topic": "orders" } }
+        ] }"#;
+
+        let expected = PipelineConfig::parse(toml, ConfigFormat::Toml).unwrap();
+        assert_eq!(expected.deadline_ms, Some(500));
+        assert_eq!(expected.stages[0].params, json!({ "topic": "orders" }));
+        assert_eq!(
+            PipelineConfig::parse(yaml, ConfigFormat::Yaml).unwrap(),
+            expected
+        );
+        assert_eq!(
+            PipelineConfig::parse(json, ConfigFormat::Json).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_report_line_and_column() {
+        let toml = "[[stages]]\ntype = \"feed\"\nname = \"source\"\nparms = {}\n";
+        let err = PipelineConfig::parse(toml, ConfigFormat::Toml).unwrap_err();
+        let location = err.location().unwrap();
+        assert_eq!(location.line, Some(4));
+        assert!(err.to_string().contains("parms"), "{err}");
+
+        let yaml = "stages:\n  - type: feed\n    name: [unclosed\n";
+        let err = PipelineConfig::parse(yaml, ConfigFormat::Yaml).unwrap_err();
+        assert!(err.location().unwrap().line >= Some(3), "{err}");
+
+        let json = "{\n  \"stages\": [\n    { \"type\": \"feeed\", \"name\": \"x\" }\n  ]\n}";
+        let err = PipelineConfig::parse(json, ConfigFormat::Json).unwrap_err();
+        assert_eq!(err.location().unwrap().line, Some(3));
+        assert!(!err.to_string().contains("at line"), "{err}");
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            ConfigFormat::from_path("a/b.TOML"),
+            Some(ConfigFormat::Toml)
+        );
+        assert_eq!(ConfigFormat::from_path("b.yml"), Some(ConfigFormat::Yaml));
+        assert_eq!(ConfigFormat::from_path("b.json"), Some(ConfigFormat::Json));
+        assert_eq!(ConfigFormat::from_path("b.ini"), None);
+        assert_eq!(ConfigFormat::from_path("pipeline"), None);
+    }
+
+    #[test]
+    fn test_stage_locations_come_from_the_parser() {
+        let positions = |text: &str, format: ConfigFormat| {
+            let source = ConfigSource::new("p", text, format);
+            let mut located: Vec<_> = (0..3).map(|index| source.stage(index)).collect();
+            located.push(source.stages());
+            located
+                .iter()
+                .map(|l| (l.line, l.column))
+                .collect::<Vec<_>>()
+        };
+        let yaml = "name: feed\nstages:\n  - type: feed\n    name: feed\n  - {type: function, name: feed}\n";
+        assert_eq!(
+            positions(yaml, ConfigFormat::Yaml),
+            [
+                (Some(4), Some(11)),
+                (Some(5), Some(28)),
+                (None, None),
+                (Some(2), Some(1))
+            ]
+        );
+        let toml = "name = \"feed\"\n[[stages]]\ntype = \"feed\"\nname = \"feed\"\n\n[[stages]]\nname='feed'\ntype = \"function\"\n";
+        assert_eq!(
+            positions(toml, ConfigFormat::Toml),
+            [
+                (Some(4), Some(8)),
+                (Some(7), Some(6)),
+                (None, None),
+                (Some(2), Some(1))
+            ]
+        );
+        let json = "{\"name\": \"feed\", \"stages\": [\n  {\"type\": \"feed\", \"name\": \"feed\"},\n  {\"name\":\n    \"feed\", \"type\": \"function\"}]}";
+        assert_eq!(
+            positions(json, ConfigFormat::Json),
+            [
+                (Some(2), Some(28)),
+                (Some(4), Some(5)),
+                (None, None),
+                (Some(1), Some(18))
+            ]
+        );
+        assert_eq!(ConfigSource::parsed("p").stage(0), ConfigLocation::new("p"));
+    }
+}
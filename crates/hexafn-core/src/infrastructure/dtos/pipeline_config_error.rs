@@ -0,0 +1,226 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Pipeline Config Errors (Core Module)
+//!
+//! This module defines [`PipelineConfigError`], the concrete [`HexaError`] raised
+//! when a pipeline config file cannot be read, parsed, or resolved into a
+//! pipeline, and [`ConfigLocation`], the `file:line:column` position it points at.
+//!
+//! All variants use hierarchical `core.pipeline_config.*` error codes.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{ConfigLocation, HexaError, HexaErrorKind, PipelineConfigError, PipelineStageType};
+//!
+//! let error = PipelineConfigError::UnknownStage {
+//!     location: ConfigLocation::at("pipeline.toml", 7, 8),
+//!     stage_type: PipelineStageType::Format,
+//!     name: "json.fromat".to_string(),
+//! };
+//! assert_eq!(error.error_code(), "core.pipeline_config.unknown_stage");
+//! assert_eq!(error.error_kind(), HexaErrorKind::NotFound);
+//! assert_eq!(
+//!     error.to_string(),
+//!     "pipeline.toml:7:8: no Format stage is registered as 'json.fromat'"
+//! );
+//! ```
+
+use crate::domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity, PipelineStageType};
+use std::fmt;
+use thiserror::Error;
+
+/// Position in a pipeline config file. Line and column are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConfigLocation {
+    /// File path, or `<string>` for configs parsed from memory
+    pub file: String,
+    /// Line number, if known
+    pub line: Option<usize>,
+    /// Column number, if known
+    pub column: Option<usize>,
+}
+
+impl ConfigLocation {
+    /// A location naming only the file.
+    pub fn new(file: impl Into<String>) -> Self {
+        Self {
+            file: file.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// A location at `line` and `column` of `file`.
+    pub fn at(file: impl Into<String>, line: usize, column: usize) -> Self {
+        Self {
+            file: file.into(),
+            line: Some(line),
+            column: Some(column),
+        }
+    }
+}
+
+impl fmt::Display for ConfigLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file)?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+            if let Some(column) = self.column {
+                write!(f, ":{column}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Errors produced while loading a pipeline from a config file.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PipelineConfigError {
+    /// The config file could not be read.
+    #[error("cannot read pipeline config '{path}': {reason}")]
+    Io {
+        /// File path
+        path: String,
+        /// Underlying I/O error
+        reason: String,
+    },
+
+    /// The file extension does not name a supported format.
+    #[error("cannot tell the format of pipeline config '{path}'; use .toml, .yaml, .yml or .json")]
+    UnsupportedFormat {
+        /// File path
+        path: String,
+    },
+
+    /// The file is not valid TOML, YAML or JSON, or does not match the config schema.
+    #[error("{location}: {message}")]
+    Parse {
+        /// Where parsing failed
+        location: ConfigLocation,
+        /// Parser message
+        message: String,
+    },
+
+    /// A stage entry names a stage that is not in the stage registry.
+    #[error("{location}: no {stage_type:?} stage is registered as '{name}'")]
+    UnknownStage {
+        /// The stage entry
+        location: ConfigLocation,
+        /// Declared stage type
+        stage_type: PipelineStageType,
+        /// Requested stage name
+        name: String,
+    },
+
+    /// Stage parameters break the declared schema, the factory failed, or it
+    /// built a stage of the wrong type.
+    #[error("{location}: stage '{name}' is invalid: {reason}")]
+    InvalidStage {
+        /// The stage entry
+        location: ConfigLocation,
+        /// Registered stage name
+        name: String,
+        /// Why it was rejected
+        reason: String,
+    },
+
+    /// The resolved stages do not form a valid 6F pipeline.
+    #[error("{location}: {reason}")]
+    InvalidPipeline {
+        /// The offending stage entry, or just the file
+        location: ConfigLocation,
+        /// Underlying pipeline error
+        reason: String,
+    },
+}
+
+impl PipelineConfigError {
+    /// Where in the config the error was found, if it concerns file contents.
+    pub fn location(&self) -> Option<&ConfigLocation> {
+        match self {
+            PipelineConfigError::Io { .. } | PipelineConfigError::UnsupportedFormat { .. } => None,
+            PipelineConfigError::Parse { location, .. }
+            | PipelineConfigError::UnknownStage { location, .. }
+            | PipelineConfigError::InvalidStage { location, .. }
+            | PipelineConfigError::InvalidPipeline { location, .. } => Some(location),
+        }
+    }
+}
+
+impl HexaError for PipelineConfigError {
+    fn error_code(&self) -> &str {
+        match self {
+            PipelineConfigError::Io { .. } => "core.pipeline_config.io",
+            PipelineConfigError::UnsupportedFormat { .. } => {
+                "core.pipeline_config.unsupported_format"
+            }
+            PipelineConfigError::Parse { .. } => "core.pipeline_config.parse",
+            PipelineConfigError::UnknownStage { .. } => "core.pipeline_config.unknown_stage",
+            PipelineConfigError::InvalidStage { .. } => "core.pipeline_config.invalid_stage",
+            PipelineConfigError::InvalidPipeline { .. } => "core.pipeline_config.invalid_pipeline",
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            PipelineConfigError::Io { .. } => "Pipeline config file cannot be read",
+            PipelineConfigError::UnsupportedFormat { .. } => {
+                "Pipeline config file format is not supported"
+            }
+            PipelineConfigError::Parse { .. } => "Pipeline config file cannot be parsed",
+            PipelineConfigError::UnknownStage { .. } => "Pipeline config names an unknown stage",
+            PipelineConfigError::InvalidStage { .. } => "Pipeline config stage is invalid",
+            PipelineConfigError::InvalidPipeline { .. } => {
+                "Pipeline config does not describe a valid 6F pipeline"
+            }
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        match self {
+            PipelineConfigError::Io { .. } => HexaErrorKind::Internal,
+            PipelineConfigError::Parse { .. } => HexaErrorKind::Serialization,
+            PipelineConfigError::UnknownStage { .. } => HexaErrorKind::NotFound,
+            _ => HexaErrorKind::Validation,
+        }
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        HexaErrorSeverity::High
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_display_omits_unknown_parts() {
+        assert_eq!(ConfigLocation::new("a.yaml").to_string(), "a.yaml");
+        assert_eq!(ConfigLocation::at("a.yaml", 3, 5).to_string(), "a.yaml:3:5");
+        let line_only = ConfigLocation {
+            line: Some(3),
+            ..ConfigLocation::new("a.yaml")
+        };
+        assert_eq!(line_only.to_string(), "a.yaml:3");
+    }
+
+    #[test]
+    fn test_location_accessor() {
+        let io = PipelineConfigError::Io {
+            path: "a.toml".to_string(),
+            reason: "missing".to_string(),
+        };
+        assert!(io.location().is_none());
+        assert_eq!(io.error_kind(), HexaErrorKind::Internal);
+
+        let parse = PipelineConfigError::Parse {
+            location: ConfigLocation::at("a.toml", 2, 1),
+            message: "expected `=`".to_string(),
+        };
+        assert_eq!(parse.location().and_then(|l| l.line), Some(2));
+        assert_eq!(parse.to_string(), "a.toml:2:1: expected `=`");
+    }
+}
@@ -0,0 +1,536 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # CloudEventMapper (Core Module)
+//!
+//! This module provides [`CloudEventMapper`], which converts between hexaFn events
+//! ([`EventEnvelope`], or any [`Event`] / [`DomainEvent`]) and CloudEvents 1.0.
+//!
+//! ## Attribute Mapping
+//!
+//! | hexaFn                          | CloudEvents                        |
+//! |---------------------------------|------------------------------------|
+//! | `event_id()`                    | `id`                               |
+//! | `event_type()`                  | `type`                             |
+//! | `timestamp()`                   | `time`                             |
+//! | `payload()`                     | `data`                             |
+//! | `correlation_id()`              | `correlationid` extension          |
+//! | `aggregate_id()`                | `aggregateid` extension            |
+//! | `sequence_number()`             | `sequencenumber` extension (string)|
+//! | `causation_id`                  | `causationid` extension            |
+//! | `schema_version`                | `schemaversion` extension          |
+//! | other envelope headers          | extensions of the same name        |
+//! | reserved or invalid header names| `hexafnheaders` extension (JSON)   |
+//!
+//! `source` is the mapper's configured source. CloudEvents attributes without a
+//! hexaFn counterpart (a foreign `source`, `subject`, `dataschema`,
+//! `datacontenttype`, `data_base64`) are kept in envelope headers prefixed with
+//! `ce-`. So are the facts an envelope cannot express on its own:
+//!
+//! - `ce-id` and `ce-causationid` keep the original text of an `id` or
+//!   `causationid` that is not a UUID in canonical lowercase hyphenated form,
+//!   e.g. `A234-1234-1234` or an uppercase UUID; a UUID in any form is still
+//!   parsed into the envelope's id
+//! - `ce-absent` lists `time` and `schemaversion` when the CloudEvent lacked
+//!   them; the envelope still gets a timestamp, the time it was decoded
+//! - `ce-type-<name>` records that extension `<name>` was a boolean or an
+//!   integer, whose JSON text is in header `<name>`
+//!
+//! With these, a valid CloudEvent passed through
+//! [`from_cloud_event`](CloudEventMapper::from_cloud_event) and back through
+//! [`to_cloud_event`](CloudEventMapper::to_cloud_event) comes out unchanged,
+//! except that the hexaFn extensions above take the types shown. An envelope
+//! round-trips the other way unchanged.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{CloudEventMapper, EventEnvelope};
+//! use serde_json::json;
+//!
+//! let mapper = CloudEventMapper::new("urn:hexafn:bridge");
+//! let envelope = EventEnvelope::new("order.placed", json!({ "total": 42 }))
+//!     .with_aggregate("order-1", 3)
+//!     .with_correlation_id("checkout-17");
+//!
+//! let cloud_event = mapper.to_cloud_event(&envelope).unwrap();
+//! assert_eq!(cloud_event.event_type, "order.placed");
+//! assert_eq!(cloud_event.extension("aggregateid"), Some(&json!("order-1")));
+//!
+//! let restored = mapper.from_cloud_event(&cloud_event).unwrap();
+//! assert_eq!(restored, envelope);
+//! ```
+
+use crate::domain::contracts::{DomainEvent, Event, EventId};
+use crate::domain::value_objects::EventEnvelope;
+use crate::infrastructure::dtos::{validate_extension, CloudEvent, CloudEventError};
+use chrono::Utc;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+const CORRELATION_ID: &str = "correlationid";
+const CAUSATION_ID: &str = "causationid";
+const AGGREGATE_ID: &str = "aggregateid";
+const SEQUENCE_NUMBER: &str = "sequencenumber";
+const SCHEMA_VERSION: &str = "schemaversion";
+const HEADERS: &str = "hexafnheaders";
+
+/// Extensions written by the mapper, which headers must not overwrite.
+const MAPPER_EXTENSIONS: [&str; 6] = [
+    CORRELATION_ID,
+    CAUSATION_ID,
+    AGGREGATE_ID,
+    SEQUENCE_NUMBER,
+    SCHEMA_VERSION,
+    HEADERS,
+];
+
+const HEADER_ID: &str = "ce-id";
+const HEADER_CAUSATION_ID: &str = "ce-causationid";
+const HEADER_SOURCE: &str = "ce-source";
+const HEADER_SUBJECT: &str = "ce-subject";
+const HEADER_DATASCHEMA: &str = "ce-dataschema";
+const HEADER_DATACONTENTTYPE: &str = "ce-datacontenttype";
+const HEADER_DATA_ENCODING: &str = "ce-data-encoding";
+const BASE64_ENCODING: &str = "base64";
+const HEADER_ABSENT: &str = "ce-absent";
+const HEADER_TYPE_PREFIX: &str = "ce-type-";
+const TIME: &str = "time";
+
+/// Converts hexaFn events to and from CloudEvents 1.0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloudEventMapper {
+    source: String,
+}
+
+impl CloudEventMapper {
+    /// Create a mapper that stamps outgoing events with `source`.
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+
+    /// The configured `source` attribute.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Encode any [`Event`].
+    ///
+    /// # Errors
+    ///
+    /// A [`CloudEventError`] if the resulting event is invalid.
+    pub fn encode_event<E: Event + ?Sized>(
+        &self,
+        event: &E,
+    ) -> Result<CloudEvent, CloudEventError> {
+        self.to_cloud_event(&EventEnvelope::from_event(event))
+    }
+
+    /// Encode any [`DomainEvent`], including its aggregate, sequence and
+    /// correlation metadata.
+    ///
+    /// # Errors
+    ///
+    /// A [`CloudEventError`] if the resulting event is invalid.
+    pub fn encode_domain_event<E: DomainEvent + ?Sized>(
+        &self,
+        event: &E,
+    ) -> Result<CloudEvent, CloudEventError> {
+        self.to_cloud_event(&EventEnvelope::from_domain_event(event))
+    }
+
+    /// Convert an envelope to a validated CloudEvent.
+    ///
+    /// # Errors
+    ///
+    /// - [`CloudEventError::InvalidExtension`] if a header typed by a
+    ///   `ce-type-` header is not JSON, or the schema version exceeds the
+    ///   CloudEvents integer range
+    /// - any other [`CloudEventError`] if the resulting event is invalid
+    pub fn to_cloud_event(&self, envelope: &EventEnvelope) -> Result<CloudEvent, CloudEventError> {
+        let header = |name: &str| envelope.header(name).map(str::to_string);
+        let absent = |attribute: &str| {
+            envelope
+                .header(HEADER_ABSENT)
+                .is_some_and(|names| names.split(',').any(|name| name == attribute))
+        };
+        let mut event = CloudEvent::new(
+            header(HEADER_ID).unwrap_or_else(|| envelope.id.to_string()),
+            header(HEADER_SOURCE).unwrap_or_else(|| self.source.clone()),
+            envelope.event_type.clone(),
+        );
+        if !absent(TIME) {
+            event.time = Some(envelope.timestamp);
+        }
+        event.subject = header(HEADER_SUBJECT);
+        event.dataschema = header(HEADER_DATASCHEMA);
+        event.datacontenttype = header(HEADER_DATACONTENTTYPE);
+        match (&envelope.payload, envelope.header(HEADER_DATA_ENCODING)) {
+            (Value::String(encoded), Some(BASE64_ENCODING)) => {
+                event.data_base64 = Some(encoded.clone())
+            }
+            (Value::Null, _) => {}
+            (payload, _) => event.data = Some(payload.clone()),
+        }
+
+        let extensions = &mut event.extensions;
+        if let Some(correlation_id) = &envelope.correlation_id {
+            extensions.insert(
+                CORRELATION_ID.to_string(),
+                Value::from(correlation_id.as_str()),
+            );
+        }
+        if let Some(causation_id) = &envelope.causation_id {
+            extensions.insert(
+                CAUSATION_ID.to_string(),
+                Value::from(
+                    header(HEADER_CAUSATION_ID).unwrap_or_else(|| causation_id.to_string()),
+                ),
+            );
+        }
+        if let Some(aggregate_id) = &envelope.aggregate_id {
+            extensions.insert(AGGREGATE_ID.to_string(), Value::from(aggregate_id.as_str()));
+        }
+        if envelope.aggregate_id.is_some() || envelope.sequence_number != 0 {
+            extensions.insert(
+                SEQUENCE_NUMBER.to_string(),
+                Value::from(envelope.sequence_number.to_string()),
+            );
+        }
+        let schema_version = i32::try_from(envelope.schema_version).map_err(|_| {
+            CloudEventError::InvalidExtension {
+                name: SCHEMA_VERSION.to_string(),
+                reason: "integers must fit in 32 bits".to_string(),
+            }
+        })?;
+        if !absent(SCHEMA_VERSION)
+            || envelope.schema_version != EventEnvelope::DEFAULT_SCHEMA_VERSION
+        {
+            extensions.insert(SCHEMA_VERSION.to_string(), Value::from(schema_version));
+        }
+        let mut namespaced = Map::new();
+        for (name, text) in &envelope.headers {
+            if name.starts_with("ce-") {
+                continue;
+            }
+            let value = match envelope.header(&format!("{HEADER_TYPE_PREFIX}{name}")) {
+                Some(_) => serde_json::from_str(text)
+                    .map_err(|_| invalid_extension(name, "typed header value is not JSON"))?,
+                None => Value::from(text.as_str()),
+            };
+            if MAPPER_EXTENSIONS.contains(&name.as_str())
+                || validate_extension(name, &value).is_err()
+            {
+                namespaced.insert(name.clone(), Value::from(text.as_str()));
+            } else {
+                extensions.insert(name.clone(), value);
+            }
+        }
+        if !namespaced.is_empty() {
+            extensions.insert(
+                HEADERS.to_string(),
+                Value::from(Value::Object(namespaced).to_string()),
+            );
+        }
+
+        event.validate()?;
+        Ok(event)
+    }
+
+    /// Convert a CloudEvent to an envelope, validating it first.
+    ///
+    /// # Errors
+    ///
+    /// - [`CloudEventError::InvalidExtension`] if a hexaFn extension has an
+    ///   unparseable value
+    /// - any other [`CloudEventError`] if the event is invalid
+    pub fn from_cloud_event(&self, event: &CloudEvent) -> Result<EventEnvelope, CloudEventError> {
+        event.validate()?;
+
+        let mut envelope = EventEnvelope::new(event.event_type.clone(), Value::Null)
+            .with_timestamp(event.time.unwrap_or_else(Utc::now));
+        let absent: Vec<&str> = [
+            (TIME, event.time.is_none()),
+            (SCHEMA_VERSION, event.extension(SCHEMA_VERSION).is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(attribute, missing)| missing.then_some(attribute))
+        .collect();
+        if !absent.is_empty() {
+            envelope = envelope.with_header(HEADER_ABSENT, absent.join(","));
+        }
+        if let Ok(id) = EventId::from_string(&event.id) {
+            envelope.id = id;
+        }
+        if envelope.id.to_string() != event.id {
+            envelope = envelope.with_header(HEADER_ID, event.id.clone());
+        }
+        if event.source != self.source {
+            envelope = envelope.with_header(HEADER_SOURCE, event.source.clone());
+        }
+        for (header, value) in [
+            (HEADER_SUBJECT, &event.subject),
+            (HEADER_DATASCHEMA, &event.dataschema),
+            (HEADER_DATACONTENTTYPE, &event.datacontenttype),
+        ] {
+            if let Some(value) = value {
+                envelope = envelope.with_header(header, value.clone());
+            }
+        }
+        if let Some(encoded) = &event.data_base64 {
+            envelope.payload = Value::String(encoded.clone());
+            envelope = envelope.with_header(HEADER_DATA_ENCODING, BASE64_ENCODING);
+        } else if let Some(data) = &event.data {
+            envelope.payload = data.clone();
+        }
+
+        for (name, value) in &event.extensions {
+            let text = match value {
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            match name.as_str() {
+                CORRELATION_ID => envelope.correlation_id = Some(text),
+                AGGREGATE_ID => envelope.aggregate_id = Some(text),
+                CAUSATION_ID => {
+                    let causation_id = EventId::from_string(&text)
+                        .map_err(|error| invalid_extension(name, &error.to_string()))?;
+                    if causation_id.to_string() != text {
+                        envelope = envelope.with_header(HEADER_CAUSATION_ID, text);
+                    }
+                    envelope.causation_id = Some(causation_id);
+                }
+                SEQUENCE_NUMBER => {
+                    envelope.sequence_number = text
+                        .parse()
+                        .map_err(|_| invalid_extension(name, "expected an unsigned integer"))?
+                }
+                SCHEMA_VERSION => {
+                    envelope.schema_version = text
+                        .parse()
+                        .map_err(|_| invalid_extension(name, "expected an unsigned integer"))?
+                }
+                HEADERS => {
+                    let headers: BTreeMap<String, String> =
+                        serde_json::from_str(&text).map_err(|_| {
+                            invalid_extension(name, "expected a JSON object of strings")
+                        })?;
+                    envelope.headers.extend(headers);
+                }
+                _ => {
+                    let kind = match value {
+                        Value::Bool(_) => Some("boolean"),
+                        Value::Number(_) => Some("integer"),
+                        _ => None,
+                    };
+                    if let Some(kind) = kind {
+                        envelope =
+                            envelope.with_header(format!("{HEADER_TYPE_PREFIX}{name}"), kind);
+                    }
+                    envelope = envelope.with_header(name.clone(), text);
+                }
+            }
+        }
+        Ok(envelope)
+    }
+}
+
+fn invalid_extension(name: &str, reason: &str) -> CloudEventError {
+    CloudEventError::InvalidExtension {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::HexaError;
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+
+    struct Shipped {
+        id: EventId,
+        occurred_at: DateTime<Utc>,
+    }
+
+    impl Event for Shipped {
+        fn event_type(&self) -> &'static str {
+            "order.shipped"
+        }
+        fn event_id(&self) -> &EventId {
+            &self.id
+        }
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.occurred_at
+        }
+        fn payload(&self) -> serde_json::Value {
+            json!({ "carrier": "ups" })
+        }
+    }
+
+    impl DomainEvent for Shipped {
+        fn aggregate_id(&self) -> &str {
+            "order-7"
+        }
+        fn sequence_number(&self) -> u64 {
+            12
+        }
+        fn occurred_at(&self) -> DateTime<Utc> {
+            self.occurred_at
+        }
+        fn correlation_id(&self) -> &str {
+            "flow-3"
+        }
+    }
+
+    fn mapper() -> CloudEventMapper {
+        CloudEventMapper::new("urn:hexafn:test")
+    }
+
+    #[test]
+    fn test_domain_event_maps_to_spec_attributes() {
+        let event = Shipped {
+            id: EventId::new(),
+            occurred_at: Utc::now(),
+        };
+        let cloud_event = mapper().encode_domain_event(&event).unwrap();
+
+        assert_eq!(cloud_event.specversion, "1.0");
+        assert_eq!(cloud_event.id, event.id.to_string());
+        assert_eq!(cloud_event.source, "urn:hexafn:test");
+        assert_eq!(cloud_event.event_type, "order.shipped");
+        assert_eq!(cloud_event.time, Some(event.occurred_at));
+        assert_eq!(cloud_event.data, Some(json!({ "carrier": "ups" })));
+        assert_eq!(
+            cloud_event.extension("correlationid"),
+            Some(&json!("flow-3"))
+        );
+        assert_eq!(
+            cloud_event.extension("aggregateid"),
+            Some(&json!("order-7"))
+        );
+        assert_eq!(cloud_event.extension("sequencenumber"), Some(&json!("12")));
+        assert_eq!(cloud_event.extension("schemaversion"), Some(&json!(1)));
+
+        let envelope = mapper().from_cloud_event(&cloud_event).unwrap();
+        assert_eq!(envelope, EventEnvelope::from_domain_event(&event));
+    }
+
+    #[test]
+    fn test_envelope_roundtrips_through_binary_mode() {
+        let envelope = EventEnvelope::new("order.placed", json!({ "total": 42 }))
+            .with_aggregate("order-1", u64::MAX)
+            .with_correlation_id("checkout 17")
+            .with_causation_id(EventId::new())
+            .with_header("tenant", "acme")
+            .with_schema_version(2);
+
+        let binary = mapper()
+            .to_cloud_event(&envelope)
+            .unwrap()
+            .to_binary()
+            .unwrap();
+        let decoded = CloudEvent::from_binary(&binary).unwrap();
+        assert_eq!(mapper().from_cloud_event(&decoded).unwrap(), envelope);
+    }
+
+    #[test]
+    fn test_foreign_cloud_event_roundtrips() {
+        let mut foreign = CloudEvent::new("A234-1234-1234", "/sensors/tn-1", "com.example.reading");
+        foreign.subject = Some("room-4".to_string());
+        foreign.datacontenttype = Some("application/octet-stream".to_string());
+        foreign.data_base64 = Some("AJ+Slg==".to_string());
+        foreign
+            .extensions
+            .insert("traceparent".to_string(), json!("00-abc-01"));
+        foreign.extensions.insert("retries".to_string(), json!(3));
+        foreign
+            .extensions
+            .insert("sampled".to_string(), json!(true));
+
+        let envelope = mapper().from_cloud_event(&foreign).unwrap();
+        assert_eq!(envelope.header("ce-id"), Some("A234-1234-1234"));
+        assert_eq!(envelope.header("ce-source"), Some("/sensors/tn-1"));
+        assert_eq!(envelope.header("traceparent"), Some("00-abc-01"));
+        assert_eq!(envelope.header("retries"), Some("3"));
+        assert_eq!(envelope.header("ce-absent"), Some("time,schemaversion"));
+
+        let restored = mapper().to_cloud_event(&envelope).unwrap();
+        assert_eq!(restored, foreign);
+    }
+
+    #[test]
+    fn test_non_canonical_uuids_roundtrip_unchanged() {
+        let id = EventId::new();
+        let causation_id = EventId::new();
+        let mut foreign = CloudEvent::new(
+            id.to_string().to_uppercase(),
+            "urn:hexafn:test",
+            "order.placed",
+        );
+        foreign.time = Some(Utc::now());
+        foreign.extensions.insert(
+            "causationid".to_string(),
+            json!(causation_id.0.simple().to_string()),
+        );
+        foreign
+            .extensions
+            .insert("schemaversion".to_string(), json!(1));
+
+        let envelope = mapper().from_cloud_event(&foreign).unwrap();
+        assert_eq!(envelope.id, id);
+        assert_eq!(envelope.causation_id, Some(causation_id));
+        assert_eq!(envelope.header("ce-id"), Some(foreign.id.as_str()));
+
+        let restored = mapper().to_cloud_event(&envelope).unwrap();
+        assert_eq!(restored, foreign);
+    }
+
+    #[test]
+    fn test_headers_that_are_not_extensions_are_namespaced() {
+        let envelope = EventEnvelope::new("t", json!({}))
+            .with_header("X-Trace", "abc")
+            .with_header("source", "legacy")
+            .with_header("correlationid", "shadowed")
+            .with_header("tenant", "acme");
+
+        let cloud_event = mapper().to_cloud_event(&envelope).unwrap();
+        assert_eq!(cloud_event.source, "urn:hexafn:test");
+        assert_eq!(cloud_event.extension("tenant"), Some(&json!("acme")));
+        assert_eq!(cloud_event.extension("correlationid"), None);
+        assert_eq!(
+            cloud_event.extension("hexafnheaders"),
+            Some(&json!(
+                r#"{"X-Trace":"abc","correlationid":"shadowed","source":"legacy"}"#
+            ))
+        );
+
+        let binary = cloud_event.to_binary().unwrap();
+        let decoded = CloudEvent::from_binary(&binary).unwrap();
+        assert_eq!(mapper().from_cloud_event(&decoded).unwrap(), envelope);
+    }
+
+    #[test]
+    fn test_invalid_extensions_are_rejected() {
+        let mut cloud_event = CloudEvent::new("1", "/s", "t");
+        cloud_event
+            .extensions
+            .insert("sequencenumber".to_string(), json!("-1"));
+        assert_eq!(
+            mapper()
+                .from_cloud_event(&cloud_event)
+                .unwrap_err()
+                .error_code(),
+            "core.cloud_event.invalid_extension"
+        );
+
+        let envelope = EventEnvelope::new("t", json!({})).with_schema_version(u32::MAX);
+        assert_eq!(
+            mapper().to_cloud_event(&envelope).unwrap_err().error_code(),
+            "core.cloud_event.invalid_extension"
+        );
+    }
+}
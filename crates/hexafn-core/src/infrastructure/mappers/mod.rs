@@ -0,0 +1,6 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod cloud_event_mapper;
+
+pub use cloud_event_mapper::CloudEventMapper;
@@ -0,0 +1,6 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+pub mod adapters;
+pub mod dtos;
+pub mod mappers;
@@ -1,8 +1,40 @@
 // SPDX-FileCopyrightText: 2025 Husamettin ARABACI
 // SPDX-License-Identifier: MIT
 pub mod domain;
+pub mod infrastructure;
 
-pub use domain::contracts::DomainEvent;
-pub use domain::contracts::{Event, EventId};
+pub use domain::contracts::StageFactory;
+pub use domain::contracts::Upcaster;
+pub use domain::contracts::{Aggregate, Clock, DomainEvent, EventStore};
+pub use domain::contracts::{
+    ContextKey, Pipeline, PipelineContext, PipelineStage, PipelineStageType, StageOutcome,
+    StageSchema,
+};
+pub use domain::contracts::{Event, EventHandler, EventId, IdGenerator};
 pub use domain::contracts::{HexaError, HexaErrorKind, HexaErrorSeverity};
-pub use domain::contracts::{Pipeline, PipelineContext, PipelineStage, PipelineStageType};
+
+pub use domain::entities::{EventSourced, HotSwapPipeline, PipelineBuilder, SixFPipeline};
+pub use domain::services::PipelineStageRegistry;
+pub use domain::services::{ErrorCodeRegistry, ErrorCodeSpec};
+pub use domain::services::{EventBus, EventBusConfig, EventFactory, SubscriptionId};
+pub use domain::services::{ManualClock, SystemClock};
+pub use domain::services::{
+    RandomIdGenerator, SeededIdGenerator, UlidIdGenerator, UuidV7IdGenerator,
+};
+pub use domain::services::{UpcasterRegistry, UpcastingEventStore};
+pub use domain::value_objects::EventIdError;
+pub use domain::value_objects::UpcastError;
+pub use domain::value_objects::{Backoff, RetryPolicy};
+pub use domain::value_objects::{ContextMetadata, WriteRecord};
+pub use domain::value_objects::{ErrorCode, ErrorCodeError, ErrorReport};
+pub use domain::value_objects::{EventBusError, EventEnvelope, EventPattern, EventStoreError};
+pub use domain::value_objects::{GenericHexaError, HexaErrorBuilder};
+pub use domain::value_objects::{ParamKind, ParamSchema, ParamSpec, StageDescriptor};
+pub use domain::value_objects::{PipelineError, PipelineRun, PipelineRunStatus};
+
+pub use infrastructure::adapters::inbound::{PipelineLoader, PipelineWatcher};
+pub use infrastructure::adapters::outbound::{FileEventStore, InMemoryEventStore};
+pub use infrastructure::dtos::PipelineConfigError;
+pub use infrastructure::dtos::{BinaryCloudEvent, CloudEvent, CloudEventError};
+pub use infrastructure::dtos::{ConfigFormat, ConfigLocation, PipelineConfig, PipelineStageConfig};
+pub use infrastructure::mappers::CloudEventMapper;
@@ -13,8 +13,9 @@
 //! ```rust
 //! use hexafn_trigger::Trigger;
 //! use hexafn_trigger::TriggerCondition;
-//! use hexafn_core::HexaError;
-//! use std::any::Any;
+//! use hexafn_trigger::TriggerContext;
+//! use hexafn_core::{EventEnvelope, HexaError};
+//! use serde_json::json;
 //!
 //! struct MyTrigger;
 //!
@@ -22,7 +23,7 @@
 //!     fn id(&self) -> String { "my-trigger-1".to_string() }
 //!     fn name(&self) -> String { "Test Trigger".to_string() }
 //!     fn is_active(&self) -> bool { true }
-//!     fn evaluate(&self, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+//!     fn evaluate(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
 //!         // Example: always fire
 //!         Ok(true)
 //!     }
@@ -32,12 +33,14 @@
 //! }
 //!
 //! let trigger = MyTrigger;
-//! let result = trigger.evaluate(&42u32 as &dyn Any);
+//! let event = EventEnvelope::new("order.created", json!({}));
+//! let result = trigger.evaluate(&TriggerContext::for_event(&event));
 //! assert_eq!(result.unwrap(), true);
 //! ```
 
 use super::trigger_condition::TriggerCondition;
-use hexafn_core::HexaError;
+use crate::domain::value_objects::TriggerContext;
+use hexafn_core::{EventPattern, HexaError};
 
 /// Trait representing a trigger in the system.
 ///
@@ -48,9 +51,8 @@ use hexafn_core::HexaError;
 /// # Example
 ///
 /// ```rust
-/// use hexafn_trigger::{Trigger, TriggerCondition};
+/// use hexafn_trigger::{Trigger, TriggerCondition, TriggerContext};
 /// use hexafn_core::HexaError;
-/// use std::any::Any;
 ///
 /// struct AlwaysActiveTrigger;
 ///
@@ -58,7 +60,7 @@ use hexafn_core::HexaError;
 ///     fn id(&self) -> String { "always-active".to_string() }
 ///     fn name(&self) -> String { "Always Active".to_string() }
 ///     fn is_active(&self) -> bool { true }
-///     fn evaluate(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+///     fn evaluate(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
 ///     fn get_conditions(&self) -> Vec<Box<dyn TriggerCondition>> { vec![] }
 /// }
 /// ```
@@ -77,7 +79,7 @@ pub trait Trigger {
     ///     fn id(&self) -> String { "trigger-123".to_string() }
     ///     # fn name(&self) -> String { "".to_string() }
     ///     # fn is_active(&self) -> bool { true }
-    ///     # fn evaluate(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+    ///     # fn evaluate(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
     ///     # fn get_conditions(&self) -> Vec<Box<dyn TriggerCondition>> { vec![] }
     /// }
     /// let t = MyTrigger;
@@ -96,7 +98,7 @@ pub trait Trigger {
     ///     fn id(&self) -> String { "".to_string() }
     ///     fn name(&self) -> String { "MyTrigger".to_string() }
     ///     # fn is_active(&self) -> bool { true }
-    ///     # fn evaluate(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
+    ///     # fn evaluate(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
     ///     # fn get_conditions(&self) -> Vec<Box<dyn hexafn_trigger::TriggerCondition>> { vec![] }
     /// }
     /// let t = NamedTrigger;
@@ -117,7 +119,7 @@ pub trait Trigger {
     ///     fn id(&self) -> String { "".to_string() }
     ///     fn name(&self) -> String { "".to_string() }
     ///     fn is_active(&self) -> bool { false }
-    ///     # fn evaluate(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(false) }
+    ///     # fn evaluate(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(false) }
     ///     # fn get_conditions(&self) -> Vec<Box<dyn hexafn_trigger::TriggerCondition>> { vec![] }
     /// }
     /// let t = InactiveTrigger;
@@ -129,7 +131,7 @@ pub trait Trigger {
     ///
     /// # Arguments
     ///
-    /// * `context` - A reference to any context object (typically event data) to evaluate.
+    /// * `context` - The incoming event and the pipeline context it arrived in.
     ///
     /// # Returns
     ///
@@ -141,20 +143,21 @@ pub trait Trigger {
     ///
     /// ```rust
     /// # use hexafn_trigger::Trigger;
-    /// # use hexafn_core::HexaError;
-    /// # use std::any::Any;
+    /// # use hexafn_trigger::TriggerContext;
+    /// # use hexafn_core::{EventEnvelope, HexaError};
     /// struct AlwaysFire;
     /// impl Trigger for AlwaysFire {
     ///     fn id(&self) -> String { "".to_string() }
     ///     fn name(&self) -> String { "".to_string() }
     ///     fn is_active(&self) -> bool { true }
-    ///     fn evaluate(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
     ///     fn get_conditions(&self) -> Vec<Box<dyn hexafn_trigger::TriggerCondition>> { vec![] }
     /// }
     /// let t = AlwaysFire;
-    /// assert_eq!(t.evaluate(&42u32 as &dyn Any).unwrap(), true);
+    /// let event = EventEnvelope::new("order.created", serde_json::json!({}));
+    /// assert_eq!(t.evaluate(&TriggerContext::for_event(&event)).unwrap(), true);
     /// ```
-    fn evaluate(&self, context: &dyn std::any::Any) -> Result<bool, Box<dyn HexaError>>;
+    fn evaluate(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>>;
 
     /// Returns the list of conditions associated with this trigger.
     ///
@@ -166,7 +169,7 @@ pub trait Trigger {
     /// # use hexafn_trigger::{Trigger, TriggerCondition};
     /// struct DummyCondition;
     /// impl TriggerCondition for DummyCondition {
-    ///     fn matches(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
+    ///     fn matches(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
     ///     fn description(&self) -> String { "dummy".to_string() }
     ///     fn get_priority(&self) -> u32 { 0 }
     /// }
@@ -175,7 +178,7 @@ pub trait Trigger {
     ///     fn id(&self) -> String { "".to_string() }
     ///     fn name(&self) -> String { "".to_string() }
     ///     fn is_active(&self) -> bool { true }
-    ///     fn evaluate(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
     ///     fn get_conditions(&self) -> Vec<Box<dyn TriggerCondition>> {
     ///         vec![Box::new(DummyCondition)]
     ///     }
@@ -184,18 +187,49 @@ pub trait Trigger {
     /// assert_eq!(t.get_conditions().len(), 1);
     /// ```
     fn get_conditions(&self) -> Vec<Box<dyn TriggerCondition>>;
+
+    /// Returns the event types this trigger can fire for, or `None` if it may
+    /// fire for any event.
+    ///
+    /// Evaluators use this to skip triggers an event cannot match; the trigger
+    /// is still evaluated for the events that pass. A pattern may cover more
+    /// event types than the trigger actually fires for, but never fewer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexafn_trigger::{Trigger, TriggerCondition, TriggerContext};
+    /// # use hexafn_core::{EventPattern, HexaError};
+    /// struct OrderTrigger;
+    /// impl Trigger for OrderTrigger {
+    ///     fn id(&self) -> String { "orders".to_string() }
+    ///     fn name(&self) -> String { "".to_string() }
+    ///     fn is_active(&self) -> bool { true }
+    ///     fn evaluate(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+    ///         Ok(context.event_type().starts_with("order."))
+    ///     }
+    ///     fn get_conditions(&self) -> Vec<Box<dyn TriggerCondition>> { vec![] }
+    ///     fn event_patterns(&self) -> Option<Vec<EventPattern>> {
+    ///         Some(vec![EventPattern::parse("order.*").unwrap()])
+    ///     }
+    /// }
+    /// assert!(OrderTrigger.event_patterns().unwrap()[0].matches("order.created"));
+    /// ```
+    fn event_patterns(&self) -> Option<Vec<EventPattern>> {
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use hexafn_core::HexaError;
-    use std::any::Any;
+    use hexafn_core::{EventEnvelope, HexaError};
+    use serde_json::json;
 
     struct AlwaysTrueCondition;
 
     impl TriggerCondition for AlwaysTrueCondition {
-        fn matches(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+        fn matches(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
             Ok(true)
         }
         fn description(&self) -> String {
@@ -218,7 +252,7 @@ mod tests {
         fn is_active(&self) -> bool {
             true
         }
-        fn evaluate(&self, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+        fn evaluate(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
             // Fires only if all conditions match
             for cond in self.get_conditions() {
                 if !cond.matches(context)? {
@@ -248,8 +282,8 @@ mod tests {
     #[test]
     fn test_trigger_evaluate_true() {
         let trigger = TestTrigger;
-        let context = 123u32;
-        let result = trigger.evaluate(&context as &dyn Any);
+        let event = EventEnvelope::new("order.created", json!({}));
+        let result = trigger.evaluate(&TriggerContext::for_event(&event));
         assert!(result.unwrap());
     }
 
@@ -273,7 +307,7 @@ mod tests {
         fn is_active(&self) -> bool {
             false
         }
-        fn evaluate(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+        fn evaluate(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
             Ok(false)
         }
         fn get_conditions(&self) -> Vec<Box<dyn TriggerCondition>> {
@@ -285,7 +319,9 @@ mod tests {
     fn test_inactive_trigger() {
         let trigger = InactiveTrigger;
         assert!(!trigger.is_active());
-        let context = ();
-        assert!(!trigger.evaluate(&context as &dyn Any).unwrap());
+        let event = EventEnvelope::new("order.created", json!({}));
+        assert!(!trigger
+            .evaluate(&TriggerContext::for_event(&event))
+            .unwrap());
     }
 }
@@ -10,37 +10,39 @@
 //!
 //! - Encapsulates reusable, composable logic for evaluating whether a trigger should activate
 //! - Supports prioritization and human-readable descriptions for introspection and debugging
-//! - Enables flexible, type-erased context evaluation using `&dyn Any`
+//! - Evaluates against a typed [`TriggerContext`](crate::TriggerContext) holding the event and
+//!   pipeline state
 //!
 //! ## Usage
 //!
 //! Implement this trait to define custom trigger conditions. Each condition can be prioritized
-//! and described, and is evaluated against an arbitrary context (typically event data).
+//! and described, and is evaluated against the incoming event and the pipeline context it arrived in.
 //!
 //! ## Example
 //!
 //! ```rust
-//! use hexafn_trigger::TriggerCondition;
-//! use hexafn_core::HexaError;
-//! use std::any::Any;
+//! use hexafn_trigger::{TriggerCondition, TriggerContext};
+//! use hexafn_core::{EventEnvelope, HexaError};
+//! use serde_json::json;
 //!
-//! struct IsStringCondition;
+//! struct IsOrderEvent;
 //!
-//! impl TriggerCondition for IsStringCondition {
-//!     fn matches(&self, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
-//!         Ok(context.is::<String>())
+//! impl TriggerCondition for IsOrderEvent {
+//!     fn matches(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+//!         Ok(context.event_type().starts_with("order."))
 //!     }
-//!     fn description(&self) -> String { "Matches if context is a String".to_string() }
+//!     fn description(&self) -> String { "Matches order events".to_string() }
 //!     fn get_priority(&self) -> u32 { 10 }
 //! }
 //!
-//! let cond = IsStringCondition;
-//! let ctx = "hello".to_string();
-//! assert_eq!(cond.matches(&ctx as &dyn Any).unwrap(), true);
+//! let cond = IsOrderEvent;
+//! let event = EventEnvelope::new("order.created", json!({}));
+//! assert_eq!(cond.matches(&TriggerContext::for_event(&event)).unwrap(), true);
 //! ```
 
+use crate::domain::value_objects::TriggerContext;
 use hexafn_core::HexaError;
-use std::any::Any;
+use std::sync::Arc;
 
 /// Trait representing a condition for a trigger.
 ///
@@ -50,14 +52,13 @@ use std::any::Any;
 /// # Example
 ///
 /// ```rust
-/// use hexafn_trigger::TriggerCondition;
+/// use hexafn_trigger::{TriggerCondition, TriggerContext};
 /// use hexafn_core::HexaError;
-/// use std::any::Any;
 ///
 /// struct AlwaysTrueCondition;
 ///
 /// impl TriggerCondition for AlwaysTrueCondition {
-///     fn matches(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+///     fn matches(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
 ///         Ok(true)
 ///     }
 ///     fn description(&self) -> String {
@@ -73,7 +74,7 @@ pub trait TriggerCondition {
     ///
     /// # Arguments
     ///
-    /// * `context` - A reference to any context object (typically event data) to evaluate.
+    /// * `context` - The incoming event and the pipeline context it arrived in.
     ///
     /// # Returns
     ///
@@ -84,38 +85,37 @@ pub trait TriggerCondition {
     /// # Example
     ///
     /// ```rust
-    /// use hexafn_trigger::TriggerCondition;
-    /// use hexafn_core::HexaError;
-    /// use std::any::Any;
+    /// use hexafn_trigger::{TriggerCondition, TriggerContext};
+    /// use hexafn_core::{EventEnvelope, HexaError};
+    /// use serde_json::json;
     ///
-    /// struct TrueIfString;
+    /// struct LargeTotal;
     ///
-    /// impl TriggerCondition for TrueIfString {
-    ///     fn matches(&self, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
-    ///         Ok(context.is::<String>())
+    /// impl TriggerCondition for LargeTotal {
+    ///     fn matches(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+    ///         Ok(context.payload()["total"].as_f64().unwrap_or(0.0) > 100.0)
     ///     }
-    ///     fn description(&self) -> String { "Matches if context is String".to_string() }
+    ///     fn description(&self) -> String { "Matches if payload.total > 100".to_string() }
     ///     fn get_priority(&self) -> u32 { 1 }
     /// }
     ///
-    /// let cond = TrueIfString;
-    /// let ctx = "hello".to_string();
-    /// assert_eq!(cond.matches(&ctx as &dyn Any).unwrap(), true);
+    /// let cond = LargeTotal;
+    /// let event = EventEnvelope::new("order.created", json!({ "total": 120 }));
+    /// assert_eq!(cond.matches(&TriggerContext::for_event(&event)).unwrap(), true);
     /// ```
-    fn matches(&self, context: &dyn Any) -> Result<bool, Box<dyn HexaError>>;
+    fn matches(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>>;
 
     /// Returns a human-readable description of the condition.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use crate::hexafn_trigger::TriggerCondition;
+    /// use crate::hexafn_trigger::{TriggerCondition, TriggerContext};
     /// use hexafn_core::HexaError;
-    /// use std::any::Any;
     ///
     /// struct MyCondition;
     /// impl TriggerCondition for MyCondition {
-    ///     fn matches(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+    ///     fn matches(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
     ///     fn description(&self) -> String { "My custom condition".to_string() }
     ///     fn get_priority(&self) -> u32 { 0 }
     /// }
@@ -130,13 +130,12 @@ pub trait TriggerCondition {
     /// # Example
     ///
     /// ```rust
-    /// use hexafn_trigger::TriggerCondition;
+    /// use hexafn_trigger::{TriggerCondition, TriggerContext};
     /// use hexafn_core::HexaError;
-    /// use std::any::Any;
     ///
     /// struct PriorityCondition;
     /// impl TriggerCondition for PriorityCondition {
-    ///     fn matches(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> { Ok(false) }
+    ///     fn matches(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(false) }
     ///     fn description(&self) -> String { "Priority condition".to_string() }
     ///     fn get_priority(&self) -> u32 { 42 }
     /// }
@@ -147,15 +146,32 @@ pub trait TriggerCondition {
     fn get_priority(&self) -> u32;
 }
 
+/// Shared conditions delegate to the condition they point to, so one compiled
+/// condition tree can back several handles.
+impl<T: TriggerCondition + ?Sized> TriggerCondition for Arc<T> {
+    fn matches(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+        (**self).matches(context)
+    }
+
+    fn description(&self) -> String {
+        (**self).description()
+    }
+
+    fn get_priority(&self) -> u32 {
+        (**self).get_priority()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::any::Any;
+    use hexafn_core::EventEnvelope;
+    use serde_json::json;
 
     struct AlwaysTrueCondition;
 
     impl TriggerCondition for AlwaysTrueCondition {
-        fn matches(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+        fn matches(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
             Ok(true)
         }
         fn description(&self) -> String {
@@ -166,14 +182,14 @@ mod tests {
         }
     }
 
-    struct StringOnlyCondition;
+    struct OrderEventCondition;
 
-    impl TriggerCondition for StringOnlyCondition {
-        fn matches(&self, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
-            Ok(context.is::<String>())
+    impl TriggerCondition for OrderEventCondition {
+        fn matches(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+            Ok(context.event_type().starts_with("order."))
         }
         fn description(&self) -> String {
-            "Matches if context is String".to_string()
+            "Matches order events".to_string()
         }
         fn get_priority(&self) -> u32 {
             5
@@ -183,8 +199,8 @@ mod tests {
     #[test]
     fn test_always_true_condition_matches() {
         let cond = AlwaysTrueCondition;
-        let dummy = 42u32;
-        assert!(cond.matches(&dummy as &dyn Any).unwrap());
+        let event = EventEnvelope::new("user.created", json!({}));
+        assert!(cond.matches(&TriggerContext::for_event(&event)).unwrap());
     }
 
     #[test]
@@ -200,28 +216,28 @@ mod tests {
     }
 
     #[test]
-    fn test_string_only_condition_matches_string() {
-        let cond = StringOnlyCondition;
-        let ctx = "hello".to_string();
-        assert!(cond.matches(&ctx as &dyn Any).unwrap());
+    fn test_order_event_condition_matches_order_event() {
+        let cond = OrderEventCondition;
+        let event = EventEnvelope::new("order.created", json!({}));
+        assert!(cond.matches(&TriggerContext::for_event(&event)).unwrap());
     }
 
     #[test]
-    fn test_string_only_condition_matches_non_string() {
-        let cond = StringOnlyCondition;
-        let ctx = 123u32;
-        assert!(!cond.matches(&ctx as &dyn Any).unwrap());
+    fn test_order_event_condition_rejects_other_events() {
+        let cond = OrderEventCondition;
+        let event = EventEnvelope::new("user.created", json!({}));
+        assert!(!cond.matches(&TriggerContext::for_event(&event)).unwrap());
     }
 
     #[test]
-    fn test_string_only_condition_description() {
-        let cond = StringOnlyCondition;
-        assert_eq!(cond.description(), "Matches if context is String");
+    fn test_order_event_condition_description() {
+        let cond = OrderEventCondition;
+        assert_eq!(cond.description(), "Matches order events");
     }
 
     #[test]
-    fn test_string_only_condition_priority() {
-        let cond = StringOnlyCondition;
+    fn test_order_event_condition_priority() {
+        let cond = OrderEventCondition;
         assert_eq!(cond.get_priority(), 5);
     }
 }
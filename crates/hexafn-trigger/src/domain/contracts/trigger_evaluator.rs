@@ -4,8 +4,8 @@
 //! # TriggerEvaluator Trait
 //!
 //! This module defines the [`TriggerEvaluator`] trait, which provides the contract for managing and evaluating triggers
-//! in the hexaFn system. The trait enables registration, removal, listing, and evaluation of triggers against arbitrary
-//! contexts, supporting the **Filter** phase of the 6F Lifecycle Flow.
+//! in the hexaFn system. The trait enables registration, removal, listing, and evaluation of triggers against incoming
+//! events, supporting the **Filter** phase of the 6F Lifecycle Flow.
 //!
 //! ## Responsibilities
 //! - Register and unregister triggers at runtime
@@ -16,12 +16,13 @@
 //!
 //! ```rust
 //! use hexafn_trigger::domain::contracts::{Trigger, TriggerEvaluator, TriggerCondition};
-//! use hexafn_core::HexaError;
-//! use std::any::Any;
+//! use hexafn_trigger::TriggerContext;
+//! use hexafn_core::{EventEnvelope, HexaError};
+//! use serde_json::json;
 //!
 //! struct AlwaysTrueCondition;
 //! impl TriggerCondition for AlwaysTrueCondition {
-//!     fn matches(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+//!     fn matches(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
 //!     fn description(&self) -> String { "Always true".to_string() }
 //!     fn get_priority(&self) -> u32 { 0 }
 //! }
@@ -31,7 +32,7 @@
 //!     fn id(&self) -> String { "simple".to_string() }
 //!     fn name(&self) -> String { "Simple".to_string() }
 //!     fn is_active(&self) -> bool { true }
-//!     fn evaluate(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+//!     fn evaluate(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
 //!     fn get_conditions(&self) -> Vec<Box<dyn TriggerCondition>> { vec![Box::new(AlwaysTrueCondition)] }
 //! }
 //!
@@ -42,7 +43,7 @@
 //!     fn new() -> Self { Self { triggers: Vec::new() } }
 //! }
 //! impl TriggerEvaluator for InMemoryTriggerEvaluator {
-//!     fn evaluate(&self, trigger: &dyn Trigger, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+//!     fn evaluate(&self, trigger: &dyn Trigger, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
 //!         trigger.evaluate(context)
 //!     }
 //!     fn register_trigger(&mut self, trigger: Box<dyn Trigger>) -> Result<(), Box<dyn HexaError>> {
@@ -65,13 +66,14 @@
 //! evaluator.register_trigger(Box::new(SimpleTrigger)).unwrap();
 //! let triggers = evaluator.list_triggers();
 //! assert_eq!(triggers.len(), 1);
-//! let result = evaluator.evaluate(triggers[0], &42u32 as &dyn Any);
+//! let event = EventEnvelope::new("order.created", json!({}));
+//! let result = evaluator.evaluate(triggers[0], &TriggerContext::for_event(&event));
 //! assert_eq!(result.unwrap(), true);
 //! ```
 
 use super::trigger::Trigger;
+use crate::domain::value_objects::TriggerContext;
 use hexafn_core::HexaError;
-use std::any::Any;
 
 /// Trait for managing and evaluating triggers in the system.
 ///
@@ -82,19 +84,19 @@ use std::any::Any;
 ///
 /// ```rust
 /// use hexafn_trigger::domain::contracts::{Trigger, TriggerEvaluator};
+/// # use hexafn_trigger::TriggerContext;
 /// # use hexafn_core::HexaError;
-/// # use std::any::Any;
 /// struct DummyTrigger;
 /// impl Trigger for DummyTrigger {
 ///     fn id(&self) -> String { "dummy".to_string() }
 ///     fn name(&self) -> String { "Dummy".to_string() }
 ///     fn is_active(&self) -> bool { true }
-///     fn evaluate(&self, _context: &dyn Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+///     fn evaluate(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
 ///     fn get_conditions(&self) -> Vec<Box<dyn hexafn_trigger::domain::contracts::TriggerCondition>> { vec![] }
 /// }
 /// struct DummyEvaluator { triggers: Vec<Box<dyn Trigger>> }
 /// impl TriggerEvaluator for DummyEvaluator {
-///     fn evaluate(&self, trigger: &dyn Trigger, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+///     fn evaluate(&self, trigger: &dyn Trigger, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
 ///         trigger.evaluate(context)
 ///     }
 ///     fn register_trigger(&mut self, trigger: Box<dyn Trigger>) -> Result<(), Box<dyn HexaError>> {
@@ -116,7 +118,7 @@ pub trait TriggerEvaluator {
     ///
     /// # Arguments
     /// * `trigger` - The trigger to evaluate.
-    /// * `context` - The context (typically event data) to evaluate against.
+    /// * `context` - The incoming event and the pipeline context it arrived in.
     ///
     /// # Returns
     /// * `Ok(true)` if the trigger should fire.
@@ -127,12 +129,13 @@ pub trait TriggerEvaluator {
     ///
     /// ```
     /// use hexafn_trigger::domain::contracts::{Trigger, TriggerEvaluator, TriggerCondition};
-    /// use hexafn_core::HexaError;
-    /// use std::any::Any;
+    /// use hexafn_trigger::TriggerContext;
+    /// use hexafn_core::{EventEnvelope, HexaError};
+    /// use serde_json::json;
     ///
     /// struct TrueCondition;
     /// impl TriggerCondition for TrueCondition {
-    ///     fn matches(&self, _: &dyn Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+    ///     fn matches(&self, _: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
     ///     fn description(&self) -> String { "Always true".to_string() }
     ///     fn get_priority(&self) -> u32 { 0 }
     /// }
@@ -142,7 +145,7 @@ pub trait TriggerEvaluator {
     ///     fn id(&self) -> String { "t".to_string() }
     ///     fn name(&self) -> String { "T".to_string() }
     ///     fn is_active(&self) -> bool { true }
-    ///     fn evaluate(&self, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+    ///     fn evaluate(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
     ///         for cond in self.get_conditions() {
     ///             if !cond.matches(context)? {
     ///                 return Ok(false);
@@ -157,7 +160,7 @@ pub trait TriggerEvaluator {
     ///
     /// struct DummyEvaluator { triggers: Vec<Box<dyn Trigger>> }
     /// impl TriggerEvaluator for DummyEvaluator {
-    ///     fn evaluate(&self, trigger: &dyn Trigger, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+    ///     fn evaluate(&self, trigger: &dyn Trigger, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
     ///         trigger.evaluate(context)
     ///     }
     ///     fn register_trigger(&mut self, trigger: Box<dyn Trigger>) -> Result<(), Box<dyn HexaError>> {
@@ -176,13 +179,14 @@ pub trait TriggerEvaluator {
     ///
     /// let mut evaluator = DummyEvaluator { triggers: vec![Box::new(MyTrigger)] };
     /// let triggers = evaluator.list_triggers();
-    /// let ctx = 42u32;
-    /// assert_eq!(evaluator.evaluate(triggers[0], &ctx as &dyn Any).unwrap(), true);
+    /// let event = EventEnvelope::new("order.created", json!({}));
+    /// let ctx = TriggerContext::for_event(&event);
+    /// assert_eq!(evaluator.evaluate(triggers[0], &ctx).unwrap(), true);
     /// ```
     fn evaluate(
         &self,
         trigger: &dyn Trigger,
-        context: &dyn Any,
+        context: &TriggerContext<'_>,
     ) -> Result<bool, Box<dyn HexaError>>;
 
     /// Registers a new trigger in the evaluator.
@@ -204,12 +208,12 @@ pub trait TriggerEvaluator {
     ///     fn id(&self) -> String { "t".to_string() }
     ///     fn name(&self) -> String { "T".to_string() }
     ///     fn is_active(&self) -> bool { true }
-    ///     fn evaluate(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
     ///     fn get_conditions(&self) -> Vec<Box<dyn hexafn_trigger::domain::contracts::TriggerCondition>> { vec![] }
     /// }
     /// struct DummyEvaluator { triggers: Vec<Box<dyn Trigger>> }
     /// impl TriggerEvaluator for DummyEvaluator {
-    ///     fn evaluate(&self, _: &dyn Trigger, _: &dyn std::any::Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _: &dyn Trigger, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
     ///     fn register_trigger(&mut self, trigger: Box<dyn Trigger>) -> Result<(), Box<dyn HexaError>> {
     ///         self.triggers.push(trigger); Ok(())
     ///     }
@@ -241,12 +245,12 @@ pub trait TriggerEvaluator {
     ///     fn id(&self) -> String { "remove-me".to_string() }
     ///     fn name(&self) -> String { "".to_string() }
     ///     fn is_active(&self) -> bool { true }
-    ///     fn evaluate(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
     ///     fn get_conditions(&self) -> Vec<Box<dyn hexafn_trigger::domain::contracts::TriggerCondition>> { vec![] }
     /// }
     /// struct DummyEvaluator { triggers: Vec<Box<dyn Trigger>> }
     /// impl TriggerEvaluator for DummyEvaluator {
-    ///     fn evaluate(&self, _: &dyn Trigger, _: &dyn std::any::Any) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _: &dyn Trigger, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> { Ok(true) }
     ///     fn register_trigger(&mut self, trigger: Box<dyn Trigger>) -> Result<(), Box<dyn HexaError>> {
     ///         self.triggers.push(trigger); Ok(())
     ///     }
@@ -275,12 +279,12 @@ pub trait TriggerEvaluator {
     ///     fn id(&self) -> String { "t".to_string() }
     ///     fn name(&self) -> String { "".to_string() }
     ///     fn is_active(&self) -> bool { true }
-    ///     fn evaluate(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
     ///     fn get_conditions(&self) -> Vec<Box<dyn hexafn_trigger::domain::contracts::TriggerCondition>> { vec![] }
     /// }
     /// struct DummyEvaluator { triggers: Vec<Box<dyn Trigger>> }
     /// impl TriggerEvaluator for DummyEvaluator {
-    ///     fn evaluate(&self, _: &dyn Trigger, _: &dyn std::any::Any) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _: &dyn Trigger, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
     ///     fn register_trigger(&mut self, trigger: Box<dyn Trigger>) -> Result<(), Box<dyn hexafn_core::HexaError>> {
     ///         self.triggers.push(trigger); Ok(())
     ///     }
@@ -308,7 +312,7 @@ pub trait TriggerEvaluator {
     ///     fn id(&self) -> String { "active".to_string() }
     ///     fn name(&self) -> String { "".to_string() }
     ///     fn is_active(&self) -> bool { true }
-    ///     fn evaluate(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
     ///     fn get_conditions(&self) -> Vec<Box<dyn hexafn_trigger::domain::contracts::TriggerCondition>> { vec![] }
     /// }
     /// struct InactiveTrigger;
@@ -316,12 +320,12 @@ pub trait TriggerEvaluator {
     ///     fn id(&self) -> String { "inactive".to_string() }
     ///     fn name(&self) -> String { "".to_string() }
     ///     fn is_active(&self) -> bool { false }
-    ///     fn evaluate(&self, _: &dyn std::any::Any) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(false) }
+    ///     fn evaluate(&self, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(false) }
     ///     fn get_conditions(&self) -> Vec<Box<dyn hexafn_trigger::domain::contracts::TriggerCondition>> { vec![] }
     /// }
     /// struct DummyEvaluator { triggers: Vec<Box<dyn Trigger>> }
     /// impl TriggerEvaluator for DummyEvaluator {
-    ///     fn evaluate(&self, _: &dyn Trigger, _: &dyn std::any::Any) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
+    ///     fn evaluate(&self, _: &dyn Trigger, _: &hexafn_trigger::TriggerContext<'_>) -> Result<bool, Box<dyn hexafn_core::HexaError>> { Ok(true) }
     ///     fn register_trigger(&mut self, trigger: Box<dyn Trigger>) -> Result<(), Box<dyn hexafn_core::HexaError>> {
     ///         self.triggers.push(trigger); Ok(())
     ///     }
@@ -344,15 +348,16 @@ pub trait TriggerEvaluator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::any::Any;
+    use hexafn_core::EventEnvelope;
+    use serde_json::json;
 
     struct TestCondition;
     impl super::super::trigger_condition::TriggerCondition for TestCondition {
-        fn matches(&self, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
-            Ok(context.is::<u32>())
+        fn matches(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+            Ok(context.event_type() == "order.created")
         }
         fn description(&self) -> String {
-            "Matches if context is u32".to_string()
+            "Matches order.created events".to_string()
         }
         fn get_priority(&self) -> u32 {
             1
@@ -370,7 +375,7 @@ mod tests {
         fn is_active(&self) -> bool {
             true
         }
-        fn evaluate(&self, context: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+        fn evaluate(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
             for cond in self.get_conditions() {
                 if !cond.matches(context)? {
                     return Ok(false);
@@ -401,7 +406,7 @@ mod tests {
         fn evaluate(
             &self,
             trigger: &dyn Trigger,
-            context: &dyn Any,
+            context: &TriggerContext<'_>,
         ) -> Result<bool, Box<dyn HexaError>> {
             trigger.evaluate(context)
         }
@@ -462,7 +467,7 @@ mod tests {
             fn is_active(&self) -> bool {
                 false
             }
-            fn evaluate(&self, _: &dyn Any) -> Result<bool, Box<dyn HexaError>> {
+            fn evaluate(&self, _: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
                 Ok(false)
             }
             fn get_conditions(
@@ -489,8 +494,8 @@ mod tests {
             triggers: vec![Box::new(TestTrigger)],
         };
         let triggers = evaluator.list_triggers();
-        let ctx = 42u32;
-        let result = evaluator.evaluate(triggers[0], &ctx as &dyn Any);
+        let event = EventEnvelope::new("order.created", json!({}));
+        let result = evaluator.evaluate(triggers[0], &TriggerContext::for_event(&event));
         assert!(result.unwrap());
     }
 
@@ -500,8 +505,8 @@ mod tests {
             triggers: vec![Box::new(TestTrigger)],
         };
         let triggers = evaluator.list_triggers();
-        let ctx = "not a u32";
-        let result = evaluator.evaluate(triggers[0], &ctx as &dyn Any);
+        let event = EventEnvelope::new("order.cancelled", json!({}));
+        let result = evaluator.evaluate(triggers[0], &TriggerContext::for_event(&event));
         assert!(!result.unwrap());
     }
 }
@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # ExpressionTrigger (Trigger Module)
+//!
+//! This module provides [`ExpressionTrigger`], a [`Trigger`] whose condition is
+//! a [`TriggerExpression`] compiled against a [`PayloadSchema`]. It is what a
+//! [`TriggerDefinition`](crate::TriggerDefinition) from a config file builds.
+//!
+//! The expression is compiled once, when the trigger is created, and the
+//! trigger fires when the compiled condition matches. Compile errors surface
+//! from [`ExpressionTrigger::new`] instead of at evaluation time.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::EventEnvelope;
+//! use hexafn_trigger::{ExpressionTrigger, PayloadSchema, Trigger, TriggerContext};
+//! use serde_json::json;
+//!
+//! let trigger = ExpressionTrigger::new(
+//!     "big-orders",
+//!     "Big orders",
+//!     r#"event.type == "order.created" && payload.total >= 1000"#,
+//!     &PayloadSchema::any(),
+//! )
+//! .unwrap();
+//!
+//! let event = EventEnvelope::new("order.created", json!({ "total": 1500 }));
+//! assert!(trigger.evaluate(&TriggerContext::for_event(&event)).unwrap());
+//! assert_eq!(
+//!     trigger.get_conditions()[0].description(),
+//!     "(event.type == \"order.created\" AND payload total >= 1000)"
+//! );
+//! ```
+
+use crate::domain::contracts::{Trigger, TriggerCondition};
+use crate::domain::services::TriggerExpression;
+use crate::domain::value_objects::{DslError, PayloadSchema, TriggerContext};
+use hexafn_core::{EventPattern, HexaError};
+use std::fmt;
+use std::sync::Arc;
+
+/// Trigger defined by a DSL expression.
+#[derive(Clone)]
+pub struct ExpressionTrigger {
+    id: String,
+    name: String,
+    active: bool,
+    expression: TriggerExpression,
+    condition: Arc<dyn TriggerCondition>,
+}
+
+impl ExpressionTrigger {
+    /// Create an active trigger firing when `expression` matches.
+    ///
+    /// # Errors
+    ///
+    /// [`DslError`] if `expression` does not parse or does not type-check
+    /// against `schema`.
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        expression: &str,
+        schema: &PayloadSchema,
+    ) -> Result<Self, DslError> {
+        Self::from_expression(id, name, TriggerExpression::parse(expression)?, schema)
+    }
+
+    /// Create an active trigger from an already parsed expression.
+    ///
+    /// # Errors
+    ///
+    /// [`DslError::Type`] if `expression` does not type-check against `schema`.
+    pub fn from_expression(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        expression: TriggerExpression,
+        schema: &PayloadSchema,
+    ) -> Result<Self, DslError> {
+        let condition = Arc::from(expression.compile(schema)?);
+        Ok(Self {
+            id: id.into(),
+            name: name.into(),
+            active: true,
+            expression,
+            condition,
+        })
+    }
+
+    /// Set whether the trigger is active.
+    pub fn with_active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// The expression the trigger was built from.
+    pub fn expression(&self) -> &TriggerExpression {
+        &self.expression
+    }
+}
+
+impl Trigger for ExpressionTrigger {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    fn evaluate(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+        self.condition.matches(context)
+    }
+
+    fn get_conditions(&self) -> Vec<Box<dyn TriggerCondition>> {
+        vec![Box::new(Arc::clone(&self.condition))]
+    }
+
+    fn event_patterns(&self) -> Option<Vec<EventPattern>> {
+        self.expression.event_patterns()
+    }
+}
+
+impl fmt::Debug for ExpressionTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExpressionTrigger")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("active", &self.active)
+            .field("expression", &self.expression.source())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hexafn_core::{EventEnvelope, ParamKind};
+    use serde_json::json;
+
+    #[test]
+    fn test_type_errors_surface_at_construction() {
+        let schema = PayloadSchema::new()
+            .with_field("total", ParamKind::Number)
+            .unwrap();
+        let error = ExpressionTrigger::new("t", "T", "payload.totl > 1", &schema).unwrap_err();
+        assert_eq!(error.to_string(), "1:1: unknown payload field 'totl'");
+    }
+
+    #[test]
+    fn test_inactive_trigger_still_evaluates() {
+        let trigger = ExpressionTrigger::new("t", "T", "true", &PayloadSchema::new())
+            .unwrap()
+            .with_active(false);
+        assert!(!trigger.is_active());
+        let event = EventEnvelope::new("any", json!(null));
+        assert!(trigger
+            .evaluate(&TriggerContext::for_event(&event))
+            .unwrap());
+    }
+}
@@ -0,0 +1,6 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod expression_trigger;
+
+pub use expression_trigger::ExpressionTrigger;
@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! Syntax tree of trigger expressions, produced by the parser and consumed by
+//! the compiler. Every node keeps the span of the source text it came from.
+
+use crate::domain::value_objects::{FieldPath, Span};
+use serde_json::Value;
+
+/// Boolean expression.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Expr {
+    /// `true` or `false`
+    Bool(bool, Span),
+    /// `!expr`
+    Not(Box<Expr>, Span),
+    /// `a && b && ...`
+    All(Vec<Expr>, Span),
+    /// `a || b || ...`
+    Any(Vec<Expr>, Span),
+    /// Test of a single event value
+    Test(Test),
+}
+
+/// Test applied to one event value, e.g. `payload.total > 100`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Test {
+    pub target: Target,
+    pub target_span: Span,
+    pub op: TestOp,
+}
+
+/// Event value a test reads.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Target {
+    /// `event.type`
+    EventType,
+    /// `headers.<name>`
+    Header(String),
+    /// `payload.<path>`
+    Payload(FieldPath),
+}
+
+/// Comparison operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// What a test checks.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TestOp {
+    /// Bare path used as a condition; the value must be `true`
+    Truthy,
+    Compare(CompareOp, Literal),
+    In(Vec<Literal>),
+    NotIn(Vec<Literal>),
+    Matches(Literal),
+    Contains(Literal),
+    StartsWith(Literal),
+    EndsWith(Literal),
+    Exists,
+    Missing,
+}
+
+/// JSON literal.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Literal {
+    pub value: Value,
+    pub span: Span,
+}
@@ -0,0 +1,330 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! Type checker and compiler for trigger expressions.
+//!
+//! Each test is checked against the kind of the value it reads: `event.type`
+//! and headers are strings, payload fields take their kind from the
+//! [`PayloadSchema`]. A well-typed expression compiles into
+//! [`CompoundCondition`]s over [`AttributeCondition`] and [`FieldCondition`]
+//! leaves.
+
+use super::dsl_ast::{CompareOp, Expr, Literal, Target, Test, TestOp};
+use crate::domain::contracts::TriggerCondition;
+use crate::domain::value_objects::{
+    AttributeCondition, CompoundCondition, DslError, EventAttribute, FieldCondition,
+    FieldPredicate, Pattern, PayloadSchema, Span,
+};
+use hexafn_core::ParamKind;
+use serde_json::Value;
+
+/// Check `expr` against `schema` and build the condition tree it describes.
+pub(crate) fn compile(
+    source: &str,
+    expr: &Expr,
+    schema: &PayloadSchema,
+) -> Result<Box<dyn TriggerCondition>, DslError> {
+    Compiler { source, schema }.expr(expr)
+}
+
+struct Compiler<'a> {
+    source: &'a str,
+    schema: &'a PayloadSchema,
+}
+
+impl Compiler<'_> {
+    fn error(&self, span: Span, message: String) -> DslError {
+        DslError::type_error(self.source, span, message)
+    }
+
+    fn text(&self, span: Span) -> &str {
+        &self.source[span.start..span.end]
+    }
+
+    fn expr(&self, expr: &Expr) -> Result<Box<dyn TriggerCondition>, DslError> {
+        Ok(match expr {
+            Expr::Bool(true, _) => Box::new(CompoundCondition::all(Vec::new())),
+            Expr::Bool(false, _) => Box::new(CompoundCondition::any(Vec::new())),
+            Expr::Not(inner, _) => Box::new(CompoundCondition::not(self.expr(inner)?)),
+            Expr::All(terms, _) => Box::new(CompoundCondition::all(self.exprs(terms)?)),
+            Expr::Any(terms, _) => Box::new(CompoundCondition::any(self.exprs(terms)?)),
+            Expr::Test(test) => self.test(test)?,
+        })
+    }
+
+    fn exprs(&self, terms: &[Expr]) -> Result<Vec<Box<dyn TriggerCondition>>, DslError> {
+        terms.iter().map(|term| self.expr(term)).collect()
+    }
+
+    fn test(&self, test: &Test) -> Result<Box<dyn TriggerCondition>, DslError> {
+        let kind = match &test.target {
+            Target::EventType | Target::Header(_) => ParamKind::String,
+            Target::Payload(path) => self
+                .schema
+                .kind_of(path)
+                .map_err(|message| self.error(test.target_span, message))?,
+        };
+        let leaf = |predicate: FieldPredicate| -> Box<dyn TriggerCondition> {
+            match &test.target {
+                Target::EventType => {
+                    Box::new(AttributeCondition::new(EventAttribute::Type, predicate))
+                }
+                Target::Header(name) => Box::new(AttributeCondition::new(
+                    EventAttribute::Header(name.clone()),
+                    predicate,
+                )),
+                Target::Payload(path) => Box::new(FieldCondition {
+                    path: path.clone(),
+                    predicate,
+                    priority: None,
+                }),
+            }
+        };
+        let target = self.text(test.target_span);
+        let expect_kind = |allowed: &[ParamKind], needed: &str| {
+            if kind == ParamKind::Any || allowed.contains(&kind) {
+                Ok(())
+            } else {
+                Err(self.error(
+                    test.target_span,
+                    format!("`{target}` is {kind}, but {needed}"),
+                ))
+            }
+        };
+        Ok(match &test.op {
+            TestOp::Truthy => {
+                expect_kind(
+                    &[ParamKind::Boolean],
+                    "a condition on its own must be a boolean; compare it with a value",
+                )?;
+                leaf(FieldPredicate::Equals {
+                    value: Value::Bool(true),
+                })
+            }
+            TestOp::Exists => leaf(FieldPredicate::Exists),
+            TestOp::Missing => leaf(FieldPredicate::Missing),
+            TestOp::Compare(op @ (CompareOp::Eq | CompareOp::Ne), literal) => {
+                let value = self.operand(kind, target, literal)?;
+                leaf(match op {
+                    CompareOp::Eq => FieldPredicate::Equals { value },
+                    _ => FieldPredicate::NotEquals { value },
+                })
+            }
+            TestOp::Compare(op, literal) => {
+                expect_kind(
+                    &[ParamKind::Integer, ParamKind::Number],
+                    "ordering needs a number",
+                )?;
+                let Value::Number(value) = &literal.value else {
+                    return Err(self.mismatch(literal, "a number"));
+                };
+                let value = value.clone();
+                leaf(match op {
+                    CompareOp::Gt => FieldPredicate::GreaterThan { value },
+                    CompareOp::Ge => FieldPredicate::GreaterOrEqual { value },
+                    CompareOp::Lt => FieldPredicate::LessThan { value },
+                    _ => FieldPredicate::LessOrEqual { value },
+                })
+            }
+            TestOp::In(items) | TestOp::NotIn(items) => {
+                let values = items
+                    .iter()
+                    .map(|item| self.operand(kind, target, item))
+                    .collect::<Result<_, _>>()?;
+                let condition = leaf(FieldPredicate::In { values });
+                if matches!(test.op, TestOp::NotIn(_)) {
+                    Box::new(CompoundCondition::not(condition))
+                } else {
+                    condition
+                }
+            }
+            TestOp::Matches(literal) => {
+                expect_kind(&[ParamKind::String], "`matches` needs a string")?;
+                let pattern = Pattern::new(self.string(literal)?)
+                    .map_err(|error| self.error(literal.span, error.to_string()))?;
+                leaf(FieldPredicate::Matches { pattern })
+            }
+            TestOp::StartsWith(literal) => {
+                expect_kind(&[ParamKind::String], "`starts_with` needs a string")?;
+                let value = self.string(literal)?.to_string();
+                leaf(FieldPredicate::StartsWith { value })
+            }
+            TestOp::EndsWith(literal) => {
+                expect_kind(&[ParamKind::String], "`ends_with` needs a string")?;
+                let value = self.string(literal)?.to_string();
+                leaf(FieldPredicate::EndsWith { value })
+            }
+            TestOp::Contains(literal) => match kind {
+                ParamKind::String => {
+                    let value = self.string(literal)?.to_string();
+                    leaf(FieldPredicate::Contains { value })
+                }
+                ParamKind::Array => leaf(FieldPredicate::ArrayContains {
+                    value: literal.value.clone(),
+                }),
+                ParamKind::Any => match &literal.value {
+                    Value::String(text) => Box::new(CompoundCondition::any(vec![
+                        leaf(FieldPredicate::Contains {
+                            value: text.clone(),
+                        }),
+                        leaf(FieldPredicate::ArrayContains {
+                            value: literal.value.clone(),
+                        }),
+                    ])),
+                    value => leaf(FieldPredicate::ArrayContains {
+                        value: value.clone(),
+                    }),
+                },
+                _ => {
+                    return Err(self.error(
+                        test.target_span,
+                        format!("`{target}` is {kind}, but `contains` needs a string or an array"),
+                    ))
+                }
+            },
+        })
+    }
+
+    /// Literal compared for equality with a value of `kind`; `null` always fits.
+    fn operand(&self, kind: ParamKind, target: &str, literal: &Literal) -> Result<Value, DslError> {
+        if literal.value.is_null() || kind.matches(&literal.value) {
+            Ok(literal.value.clone())
+        } else {
+            Err(self.error(
+                literal.span,
+                format!(
+                    "`{target}` is {kind}, but this is {}",
+                    describe(&literal.value)
+                ),
+            ))
+        }
+    }
+
+    fn string<'l>(&self, literal: &'l Literal) -> Result<&'l str, DslError> {
+        literal
+            .value
+            .as_str()
+            .ok_or_else(|| self.mismatch(literal, "a string"))
+    }
+
+    fn mismatch(&self, literal: &Literal, expected: &str) -> DslError {
+        self.error(
+            literal.span,
+            format!("expected {expected}, found {}", describe(&literal.value)),
+        )
+    }
+}
+
+fn describe(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "a list",
+        Value::Object(_) => "an object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dsl_parser::parse;
+    use super::*;
+
+    fn compile_with(
+        source: &str,
+        schema: &PayloadSchema,
+    ) -> Result<Box<dyn TriggerCondition>, DslError> {
+        compile(source, &parse(source).unwrap(), schema)
+    }
+
+    fn type_error(source: &str, schema: &PayloadSchema) -> (Span, String) {
+        match compile_with(source, schema).err().unwrap() {
+            DslError::Type { span, message, .. } => (span, message),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    fn orders() -> PayloadSchema {
+        PayloadSchema::new()
+            .with_field("total", ParamKind::Number)
+            .unwrap()
+            .with_field("country", ParamKind::String)
+            .unwrap()
+            .with_field("tags", ParamKind::Array)
+            .unwrap()
+            .with_field("gift", ParamKind::Boolean)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_descriptions() {
+        let condition = compile_with(
+            r#"event.type == "order.created" && payload.total > 100 && !(payload.country in ["XX"])"#,
+            &orders(),
+        )
+        .unwrap();
+        assert_eq!(
+            condition.description(),
+            r#"(event.type == "order.created" AND payload total > 100 AND NOT payload country in ["XX"])"#
+        );
+        let tagged =
+            compile_with(r#"payload.tags contains "vip" || payload.gift"#, &orders()).unwrap();
+        assert_eq!(
+            tagged.description(),
+            r#"(payload gift == true OR payload tags has element "vip")"#
+        );
+    }
+
+    #[test]
+    fn test_type_errors() {
+        let schema = orders();
+        assert_eq!(
+            type_error(r#"payload.total == "big""#, &schema),
+            (
+                Span::new(17, 22),
+                "`payload.total` is a number, but this is a string".to_string()
+            )
+        );
+        assert_eq!(
+            type_error("payload.country > 3", &schema),
+            (
+                Span::new(0, 15),
+                "`payload.country` is a string, but ordering needs a number".to_string()
+            )
+        );
+        assert_eq!(
+            type_error("payload.weight < 3", &schema).1,
+            "unknown payload field 'weight'"
+        );
+        assert_eq!(
+            type_error("payload.total", &schema).1,
+            "`payload.total` is a number, but a condition on its own must be a boolean; compare it with a value"
+        );
+        assert_eq!(
+            type_error(r#"payload.country matches "[""#, &schema).0,
+            Span::new(24, 27)
+        );
+        assert_eq!(
+            type_error("headers.source in [\"web\", 1]", &schema),
+            (
+                Span::new(26, 27),
+                "`headers.source` is a string, but this is a number".to_string()
+            )
+        );
+        assert!(compile_with("payload.country == null", &schema).is_ok());
+    }
+
+    #[test]
+    fn test_untyped_contains_checks_strings_and_arrays() {
+        let condition =
+            compile_with(r#"payload.note contains "vip""#, &PayloadSchema::any()).unwrap();
+        let text =
+            hexafn_core::EventEnvelope::new("x", serde_json::json!({ "note": "a vip order" }));
+        let list = hexafn_core::EventEnvelope::new("x", serde_json::json!({ "note": ["vip"] }));
+        for event in [text, list] {
+            let context = crate::domain::value_objects::TriggerContext::for_event(&event);
+            assert!(condition.matches(&context).unwrap());
+        }
+    }
+}
@@ -0,0 +1,323 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! Tokenizer for trigger expressions.
+
+use crate::domain::value_objects::{DslError, Span};
+use serde_json::Number;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TokenKind {
+    /// Identifier or keyword; may contain `-` after the first character
+    Ident(String),
+    /// Double-quoted string, unescaped
+    Str(String),
+    Number(Number),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    Dot,
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    And,
+    Or,
+    Bang,
+    Eof,
+}
+
+impl Display for TokenKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            TokenKind::Ident(name) => return write!(f, "`{name}`"),
+            TokenKind::Str(text) => return write!(f, "string {text:?}"),
+            TokenKind::Number(n) => return write!(f, "number {n}"),
+            TokenKind::Eof => return f.write_str("end of input"),
+            TokenKind::LParen => "(",
+            TokenKind::RParen => ")",
+            TokenKind::LBracket => "[",
+            TokenKind::RBracket => "]",
+            TokenKind::Comma => ",",
+            TokenKind::Dot => ".",
+            TokenKind::Eq => "==",
+            TokenKind::Ne => "!=",
+            TokenKind::Gt => ">",
+            TokenKind::Ge => ">=",
+            TokenKind::Lt => "<",
+            TokenKind::Le => "<=",
+            TokenKind::And => "&&",
+            TokenKind::Or => "||",
+            TokenKind::Bang => "!",
+        };
+        write!(f, "`{symbol}`")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+/// Split `source` into tokens, ending with [`TokenKind::Eof`].
+pub(crate) fn tokenize(source: &str) -> Result<Vec<Token>, DslError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let byte = bytes[pos];
+        let two = |second: u8| bytes.get(pos + 1) == Some(&second);
+        let (kind, len) = match byte {
+            b' ' | b'\t' | b'\r' | b'\n' => {
+                pos += 1;
+                continue;
+            }
+            b'(' => (TokenKind::LParen, 1),
+            b')' => (TokenKind::RParen, 1),
+            b'[' => (TokenKind::LBracket, 1),
+            b']' => (TokenKind::RBracket, 1),
+            b',' => (TokenKind::Comma, 1),
+            b'.' => (TokenKind::Dot, 1),
+            b'=' if two(b'=') => (TokenKind::Eq, 2),
+            b'!' if two(b'=') => (TokenKind::Ne, 2),
+            b'!' => (TokenKind::Bang, 1),
+            b'>' if two(b'=') => (TokenKind::Ge, 2),
+            b'>' => (TokenKind::Gt, 1),
+            b'<' if two(b'=') => (TokenKind::Le, 2),
+            b'<' => (TokenKind::Lt, 1),
+            b'&' if two(b'&') => (TokenKind::And, 2),
+            b'|' if two(b'|') => (TokenKind::Or, 2),
+            b'"' => {
+                let (text, end) = string(source, start)?;
+                (TokenKind::Str(text), end - start)
+            }
+            b'0'..=b'9' => {
+                let after_dot = tokens
+                    .last()
+                    .is_some_and(|t: &Token| t.kind == TokenKind::Dot);
+                number(source, start, after_dot)?
+            }
+            b'-' if bytes.get(pos + 1).is_some_and(u8::is_ascii_digit) => {
+                number(source, start, false)?
+            }
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' => {
+                let len = bytes[start..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_alphanumeric() || **b == b'_' || **b == b'-')
+                    .count();
+                (
+                    TokenKind::Ident(source[start..start + len].to_string()),
+                    len,
+                )
+            }
+            _ => {
+                let c = source[start..].chars().next().unwrap_or_default();
+                let span = Span::new(start, start + c.len_utf8());
+                let message = match c {
+                    '=' => "unexpected `=`; use `==` to compare".to_string(),
+                    '&' | '|' => format!("unexpected `{c}`; use `{c}{c}`"),
+                    _ => format!("unexpected character {c:?}"),
+                };
+                return Err(DslError::syntax(source, span, message));
+            }
+        };
+        pos = start + len;
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, pos),
+        });
+    }
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        span: Span::new(source.len(), source.len()),
+    });
+    Ok(tokens)
+}
+
+/// Scan the string literal starting at the quote at `start`; returns its text
+/// and the offset after the closing quote.
+fn string(source: &str, start: usize) -> Result<(String, usize), DslError> {
+    let mut text = String::new();
+    let mut chars = source[start + 1..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        let at = start + 1 + i;
+        match c {
+            '"' => return Ok((text, at + 1)),
+            '\\' => {
+                let escape = chars.next().map(|(_, e)| e);
+                let unescaped = match escape {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('/') => '/',
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).map(|(_, h)| h).collect();
+                        u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .filter(|_| hex.len() == 4)
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| {
+                                DslError::syntax(
+                                    source,
+                                    Span::new(at, at + 2 + hex.len()),
+                                    "invalid unicode escape; expected `\\u` and 4 hex digits",
+                                )
+                            })?
+                    }
+                    _ => {
+                        let len = 1 + escape.map_or(0, char::len_utf8);
+                        return Err(DslError::syntax(
+                            source,
+                            Span::new(at, at + len),
+                            "invalid escape sequence",
+                        ));
+                    }
+                };
+                text.push(unescaped);
+            }
+            _ => text.push(c),
+        }
+    }
+    Err(DslError::syntax(
+        source,
+        Span::new(start, source.len()),
+        "unterminated string",
+    ))
+}
+
+/// Scan the number starting at `start`: an optional `-`, digits, an optional
+/// fraction and an optional exponent.
+///
+/// With `index_only`, as for a path segment after a `.`, only the digits are
+/// scanned, so `payload.m.0.1` reads as the indices `0` and `1`.
+fn number(source: &str, start: usize, index_only: bool) -> Result<(TokenKind, usize), DslError> {
+    let bytes = source.as_bytes();
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let mut end = start + usize::from(bytes[start] == b'-');
+    end += digits(end);
+    let mut integer = true;
+    if !index_only
+        && bytes.get(end) == Some(&b'.')
+        && bytes.get(end + 1).is_some_and(u8::is_ascii_digit)
+    {
+        integer = false;
+        end += 1 + digits(end + 1);
+    }
+    if !index_only && matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exponent = digits(end + 1 + sign);
+        if exponent > 0 {
+            integer = false;
+            end += 1 + sign + exponent;
+        }
+    }
+    let text = &source[start..end];
+    let number = if integer {
+        text.parse::<i64>()
+            .map(Number::from)
+            .or_else(|_| text.parse::<u64>().map(Number::from))
+            .ok()
+    } else {
+        text.parse::<f64>().ok().and_then(Number::from_f64)
+    };
+    let number = number.ok_or_else(|| {
+        DslError::syntax(
+            source,
+            Span::new(start, end),
+            format!("number {text} is out of range"),
+        )
+    })?;
+    Ok((TokenKind::Number(number), end - start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(source: &str) -> Vec<TokenKind> {
+        tokenize(source)
+            .unwrap()
+            .into_iter()
+            .map(|token| token.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_operators_and_literals() {
+        assert_eq!(
+            kinds(r#"a.b-c >= -1.5e2 || !"x\"é""#),
+            vec![
+                TokenKind::Ident("a".to_string()),
+                TokenKind::Dot,
+                TokenKind::Ident("b-c".to_string()),
+                TokenKind::Ge,
+                TokenKind::Number(Number::from_f64(-150.0).unwrap()),
+                TokenKind::Or,
+                TokenKind::Bang,
+                TokenKind::Str("x\"é".to_string()),
+                TokenKind::Eof,
+            ]
+        );
+        assert_eq!(
+            kinds("items.0.sku"),
+            vec![
+                TokenKind::Ident("items".to_string()),
+                TokenKind::Dot,
+                TokenKind::Number(Number::from(0)),
+                TokenKind::Dot,
+                TokenKind::Ident("sku".to_string()),
+                TokenKind::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors_point_at_the_offending_text() {
+        let cases = [
+            (
+                "a = 1",
+                Span::new(2, 3),
+                "unexpected `=`; use `==` to compare",
+            ),
+            ("a & b", Span::new(2, 3), "unexpected `&`; use `&&`"),
+            ("x == \"abc", Span::new(5, 9), "unterminated string"),
+            (r#""\q""#, Span::new(1, 3), "invalid escape sequence"),
+            (
+                "n > 99999999999999999999",
+                Span::new(4, 24),
+                "number 99999999999999999999 is out of range",
+            ),
+            ("a # b", Span::new(2, 3), "unexpected character '#'"),
+        ];
+        for (source, span, message) in cases {
+            match tokenize(source).unwrap_err() {
+                DslError::Syntax {
+                    span: actual,
+                    message: actual_message,
+                    ..
+                } => {
+                    assert_eq!(
+                        (actual, actual_message.as_str()),
+                        (span, message),
+                        "{source}"
+                    );
+                }
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,507 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! Recursive-descent parser for trigger expressions.
+//!
+//! ```text
+//! expr    := and ( "||" and )*
+//! and     := unary ( "&&" unary )*
+//! unary   := "!" unary | primary
+//! primary := "(" expr ")" | "true" | "false"
+//!          | ( "exists" | "missing" ) "(" target ")"
+//!          | target [ test ]
+//! test    := ( "==" | "!=" | ">" | ">=" | "<" | "<=" ) literal
+//!          | [ "not" ] "in" list
+//!          | ( "matches" | "contains" | "starts_with" | "ends_with" ) literal
+//! target  := "event" "." "type"
+//!          | "headers" ( "." name | "[" string "]" )
+//!          | "payload" ( "." name | "." integer | "[" ( integer | string ) "]" )*
+//! literal := string | number | "true" | "false" | "null" | list
+//! list    := "[" [ literal ( "," literal )* [ "," ] ] "]"
+//! ```
+
+use super::dsl_ast::{CompareOp, Expr, Literal, Target, Test, TestOp};
+use super::dsl_lexer::{tokenize, Token, TokenKind};
+use crate::domain::value_objects::{DslError, FieldPath, Span};
+use serde_json::Value;
+
+/// Deepest nesting of parentheses, negations and lists accepted.
+const MAX_DEPTH: usize = 64;
+
+/// Parse `source` into an expression tree.
+pub(crate) fn parse(source: &str) -> Result<Expr, DslError> {
+    let mut parser = Parser {
+        source,
+        tokens: tokenize(source)?,
+        pos: 0,
+        depth: 0,
+    };
+    let expr = parser.expr()?;
+    match parser.peek() {
+        TokenKind::Eof => Ok(expr),
+        found => Err(parser.error(format!(
+            "expected `&&`, `||` or end of input, found {found}"
+        ))),
+    }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    tokens: Vec<Token>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> &TokenKind {
+        &self.tokens[self.pos].kind
+    }
+
+    fn peek_ident(&self) -> Option<&str> {
+        match self.peek() {
+            TokenKind::Ident(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn span(&self) -> Span {
+        self.tokens[self.pos].span
+    }
+
+    fn prev_span(&self) -> Span {
+        self.tokens[self.pos.saturating_sub(1)].span
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if token.kind != TokenKind::Eof {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, kind: &TokenKind) -> bool {
+        if self.peek() == kind {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, kind: &TokenKind) -> Result<Span, DslError> {
+        if self.peek() == kind {
+            Ok(self.advance().span)
+        } else {
+            Err(self.error(format!("expected {kind}, found {}", self.peek())))
+        }
+    }
+
+    /// Syntax error at the current token.
+    fn error(&self, message: String) -> DslError {
+        DslError::syntax(self.source, self.span(), message)
+    }
+
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, DslError>,
+    ) -> Result<T, DslError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(format!("expression nests deeper than {MAX_DEPTH} levels")));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn expr(&mut self) -> Result<Expr, DslError> {
+        let first = self.and()?;
+        if self.peek() != &TokenKind::Or {
+            return Ok(first);
+        }
+        let mut terms = vec![first];
+        while self.eat(&TokenKind::Or) {
+            terms.push(self.and()?);
+        }
+        let span = span_of(&terms[0]).to(self.prev_span());
+        Ok(Expr::Any(terms, span))
+    }
+
+    fn and(&mut self) -> Result<Expr, DslError> {
+        let first = self.unary()?;
+        if self.peek() != &TokenKind::And {
+            return Ok(first);
+        }
+        let mut terms = vec![first];
+        while self.eat(&TokenKind::And) {
+            terms.push(self.unary()?);
+        }
+        let span = span_of(&terms[0]).to(self.prev_span());
+        Ok(Expr::All(terms, span))
+    }
+
+    fn unary(&mut self) -> Result<Expr, DslError> {
+        if self.peek() == &TokenKind::Bang {
+            let start = self.advance().span;
+            let inner = self.nested(Self::unary)?;
+            let span = start.to(self.prev_span());
+            return Ok(Expr::Not(Box::new(inner), span));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, DslError> {
+        if self.peek() == &TokenKind::LParen {
+            self.advance();
+            let inner = self.nested(Self::expr)?;
+            self.expect(&TokenKind::RParen)?;
+            return Ok(inner);
+        }
+        let Some(name) = self.peek_ident() else {
+            return Err(self.error(format!(
+                "expected a condition such as `payload.total > 100`, found {}",
+                self.peek()
+            )));
+        };
+        match name {
+            "true" | "false" => {
+                let value = name == "true";
+                Ok(Expr::Bool(value, self.advance().span))
+            }
+            "exists" | "missing" if self.tokens[self.pos + 1].kind == TokenKind::LParen => {
+                let op = if name == "exists" {
+                    TestOp::Exists
+                } else {
+                    TestOp::Missing
+                };
+                self.advance();
+                self.advance();
+                let (target, target_span) = self.target()?;
+                self.expect(&TokenKind::RParen)?;
+                Ok(Expr::Test(Test {
+                    target,
+                    target_span,
+                    op,
+                }))
+            }
+            _ => {
+                let (target, target_span) = self.target()?;
+                let op = self.test_op()?;
+                Ok(Expr::Test(Test {
+                    target,
+                    target_span,
+                    op,
+                }))
+            }
+        }
+    }
+
+    fn test_op(&mut self) -> Result<TestOp, DslError> {
+        let compare = match self.peek() {
+            TokenKind::Eq => Some(CompareOp::Eq),
+            TokenKind::Ne => Some(CompareOp::Ne),
+            TokenKind::Gt => Some(CompareOp::Gt),
+            TokenKind::Ge => Some(CompareOp::Ge),
+            TokenKind::Lt => Some(CompareOp::Lt),
+            TokenKind::Le => Some(CompareOp::Le),
+            _ => None,
+        };
+        if let Some(op) = compare {
+            self.advance();
+            return Ok(TestOp::Compare(op, self.literal()?));
+        }
+        let Some(keyword) = self.peek_ident() else {
+            return Ok(TestOp::Truthy);
+        };
+        let op: fn(Literal) -> TestOp = match keyword {
+            "in" => {
+                self.advance();
+                return Ok(TestOp::In(self.list()?));
+            }
+            "not" => {
+                self.advance();
+                if self.peek_ident() != Some("in") {
+                    return Err(
+                        self.error(format!("expected `in` after `not`, found {}", self.peek()))
+                    );
+                }
+                self.advance();
+                return Ok(TestOp::NotIn(self.list()?));
+            }
+            "matches" => TestOp::Matches,
+            "contains" => TestOp::Contains,
+            "starts_with" => TestOp::StartsWith,
+            "ends_with" => TestOp::EndsWith,
+            _ => {
+                return Err(self.error(format!(
+                    "expected an operator such as `==`, `in` or `matches`, found {}",
+                    self.peek()
+                )))
+            }
+        };
+        self.advance();
+        Ok(op(self.literal()?))
+    }
+
+    fn target(&mut self) -> Result<(Target, Span), DslError> {
+        let start = self.span();
+        let root = match self.peek_ident() {
+            Some(root @ ("event" | "headers" | "payload")) => root.to_string(),
+            _ => {
+                return Err(self.error(format!(
+                    "expected `event.type`, `headers.<name>` or `payload.<path>`, found {}",
+                    self.peek()
+                )))
+            }
+        };
+        self.advance();
+        let mut segments = Vec::new();
+        loop {
+            if self.eat(&TokenKind::Dot) {
+                let token = self.advance();
+                match token.kind {
+                    TokenKind::Ident(name) => segments.push(name),
+                    TokenKind::Number(n) if n.is_u64() => segments.push(n.to_string()),
+                    found => {
+                        return Err(DslError::syntax(
+                            self.source,
+                            token.span,
+                            format!("expected a field name after `.`, found {found}"),
+                        ))
+                    }
+                }
+            } else if self.eat(&TokenKind::LBracket) {
+                let token = self.advance();
+                match token.kind {
+                    TokenKind::Str(key) => segments.push(key),
+                    TokenKind::Number(n) if n.is_u64() => segments.push(n.to_string()),
+                    found => {
+                        return Err(DslError::syntax(
+                            self.source,
+                            token.span,
+                            format!("expected an index or a quoted key, found {found}"),
+                        ))
+                    }
+                }
+                self.expect(&TokenKind::RBracket)?;
+            } else {
+                break;
+            }
+        }
+        let span = start.to(self.prev_span());
+        let target = match (root.as_str(), segments.as_slice()) {
+            ("event", [attribute]) if attribute == "type" => Target::EventType,
+            ("event", _) => {
+                return Err(DslError::syntax(
+                    self.source,
+                    span,
+                    "unknown event attribute; expected `event.type`",
+                ))
+            }
+            ("headers", [name]) => Target::Header(name.clone()),
+            ("headers", _) => {
+                return Err(DslError::syntax(
+                    self.source,
+                    span,
+                    "expected a single header name, e.g. `headers.source`",
+                ))
+            }
+            _ => Target::Payload(FieldPath::from_segments(segments)),
+        };
+        Ok((target, span))
+    }
+
+    fn literal(&mut self) -> Result<Literal, DslError> {
+        let span = self.span();
+        let value = match self.peek().clone() {
+            TokenKind::Str(text) => Value::String(text),
+            TokenKind::Number(n) => Value::Number(n),
+            TokenKind::Ident(word) if word == "true" || word == "false" => {
+                Value::Bool(word == "true")
+            }
+            TokenKind::Ident(word) if word == "null" => Value::Null,
+            TokenKind::LBracket => {
+                let items = self.nested(Self::list)?;
+                let span = span.to(self.prev_span());
+                let values = items.into_iter().map(|item| item.value).collect();
+                return Ok(Literal {
+                    value: Value::Array(values),
+                    span,
+                });
+            }
+            found => return Err(self.error(format!("expected a literal, found {found}"))),
+        };
+        self.advance();
+        Ok(Literal { value, span })
+    }
+
+    fn list(&mut self) -> Result<Vec<Literal>, DslError> {
+        self.expect(&TokenKind::LBracket)?;
+        let mut items = Vec::new();
+        while self.peek() != &TokenKind::RBracket {
+            items.push(self.literal()?);
+            if !self.eat(&TokenKind::Comma) && self.peek() != &TokenKind::RBracket {
+                return Err(self.error(format!("expected `,` or `]`, found {}", self.peek())));
+            }
+        }
+        self.advance();
+        Ok(items)
+    }
+}
+
+pub(crate) fn span_of(expr: &Expr) -> Span {
+    match expr {
+        Expr::Bool(_, span) | Expr::Not(_, span) | Expr::All(_, span) | Expr::Any(_, span) => *span,
+        Expr::Test(test) => {
+            let end = match &test.op {
+                TestOp::Truthy | TestOp::Exists | TestOp::Missing => test.target_span,
+                TestOp::Compare(_, literal)
+                | TestOp::Matches(literal)
+                | TestOp::Contains(literal)
+                | TestOp::StartsWith(literal)
+                | TestOp::EndsWith(literal) => literal.span,
+                TestOp::In(items) | TestOp::NotIn(items) => {
+                    items.last().map_or(test.target_span, |item| item.span)
+                }
+            };
+            test.target_span.to(end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn syntax_error(source: &str) -> (Span, String) {
+        match parse(source).unwrap_err() {
+            DslError::Syntax { span, message, .. } => (span, message),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_precedence() {
+        let expr = parse("event.type == \"a\" || payload.x > 1 && !payload.flag").unwrap();
+        let Expr::Any(terms, span) = expr else {
+            panic!("expected ||");
+        };
+        assert_eq!(span, Span::new(0, 51));
+        assert!(matches!(
+            terms[0],
+            Expr::Test(Test {
+                target: Target::EventType,
+                ..
+            })
+        ));
+        let Expr::All(conjuncts, _) = &terms[1] else {
+            panic!("expected &&");
+        };
+        assert!(matches!(conjuncts[1], Expr::Not(..)));
+    }
+
+    #[test]
+    fn test_targets() {
+        let test = |source: &str| match parse(source).unwrap() {
+            Expr::Test(test) => test,
+            other => panic!("unexpected {other:?}"),
+        };
+        let sku = test(r#"payload.items[0]["sku code"] starts_with "A""#);
+        assert_eq!(
+            sku.target,
+            Target::Payload(FieldPath::from_segments(vec![
+                "items".to_string(),
+                "0".to_string(),
+                "sku code".to_string()
+            ]))
+        );
+        assert_eq!(sku.target_span, Span::new(0, 28));
+        assert_eq!(
+            test("headers[\"x-id\"] == 1").target,
+            Target::Header("x-id".to_string())
+        );
+        assert_eq!(test("exists(payload)").op, TestOp::Exists);
+        let TestOp::NotIn(items) = test("payload.c not in [\"XX\", [1],]").op else {
+            panic!("expected not in");
+        };
+        assert_eq!(items[1].value, json!([1]));
+    }
+
+    #[test]
+    fn test_dotted_numeric_indices() {
+        let Expr::Test(test) = parse("payload.m.0.1 == 1.5").unwrap() else {
+            panic!("expected a test");
+        };
+        assert_eq!(
+            test.target,
+            Target::Payload(FieldPath::from_segments(vec![
+                "m".to_string(),
+                "0".to_string(),
+                "1".to_string()
+            ]))
+        );
+        assert_eq!(test.target_span, Span::new(0, 13));
+        assert_eq!(
+            test.op,
+            TestOp::Compare(
+                CompareOp::Eq,
+                Literal {
+                    value: json!(1.5),
+                    span: Span::new(17, 20),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        assert_eq!(
+            syntax_error("payload.total > 1 payload"),
+            (
+                Span::new(18, 25),
+                "expected `&&`, `||` or end of input, found `payload`".to_string()
+            )
+        );
+        assert_eq!(
+            syntax_error("event.id == \"x\""),
+            (
+                Span::new(0, 8),
+                "unknown event attribute; expected `event.type`".to_string()
+            )
+        );
+        assert_eq!(
+            syntax_error("(payload.a == 1"),
+            (
+                Span::new(15, 15),
+                "expected `)`, found end of input".to_string()
+            )
+        );
+        assert_eq!(
+            syntax_error("user.age > 18").1,
+            "expected `event.type`, `headers.<name>` or `payload.<path>`, found `user`"
+        );
+        assert_eq!(
+            syntax_error("payload.a not [1]").1,
+            "expected `in` after `not`, found `[`"
+        );
+        assert_eq!(
+            syntax_error("payload.a in [1 2]").1,
+            "expected `,` or `]`, found number 2"
+        );
+        assert_eq!(syntax_error("payload.a like \"x\"").0, Span::new(10, 14));
+    }
+
+    #[test]
+    fn test_nesting_is_bounded() {
+        let deep = format!(
+            "{}true{}",
+            "(".repeat(MAX_DEPTH + 1),
+            ")".repeat(MAX_DEPTH + 1)
+        );
+        assert!(syntax_error(&deep).1.contains("nests deeper"));
+        let ok = format!("{}true{}", "(".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH));
+        assert!(parse(&ok).is_ok());
+    }
+}
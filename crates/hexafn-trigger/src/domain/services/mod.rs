@@ -0,0 +1,12 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod dsl_ast;
+mod dsl_compiler;
+mod dsl_lexer;
+mod dsl_parser;
+mod trigger_expression;
+mod trigger_registry;
+
+pub use trigger_expression::TriggerExpression;
+pub use trigger_registry::TriggerRegistry;
@@ -0,0 +1,228 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # TriggerExpression (Trigger Module)
+//!
+//! This module provides [`TriggerExpression`], a parsed trigger condition
+//! written in the trigger DSL, so triggers can be declared in configuration
+//! rather than in Rust.
+//!
+//! An expression combines tests with `&&`, `||`, `!` and parentheses. A test
+//! reads `event.type`, `headers.<name>` or `payload.<path>` and applies one of
+//! `==`, `!=`, `>`, `>=`, `<`, `<=`, `in [...]`, `not in [...]`, `matches`,
+//! `contains`, `starts_with` or `ends_with`; `exists(...)` and `missing(...)`
+//! check presence, and a bare boolean field is a test on its own. Literals are
+//! JSON strings, numbers, `true`, `false`, `null` and lists.
+//!
+//! [`TriggerExpression::parse`] reports syntax errors,
+//! [`TriggerExpression::check`] type-checks against a [`PayloadSchema`], and
+//! [`TriggerExpression::compile`] builds the [`TriggerCondition`] tree. Every
+//! [`DslError`] carries the span of the offending text.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventEnvelope, ParamKind};
+//! use hexafn_trigger::{PayloadSchema, TriggerContext, TriggerExpression};
+//! use serde_json::json;
+//!
+//! let expression = TriggerExpression::parse(
+//!     r#"event.type == "order.created" && payload.total > 100 && !(payload.country in ["XX"])"#,
+//! )
+//! .unwrap();
+//!
+//! let schema = PayloadSchema::new()
+//!     .with_field("total", ParamKind::Number)
+//!     .unwrap()
+//!     .with_field("country", ParamKind::String)
+//!     .unwrap();
+//! let condition = expression.compile(&schema).unwrap();
+//!
+//! let event = EventEnvelope::new("order.created", json!({ "total": 250, "country": "DE" }));
+//! assert!(condition.matches(&TriggerContext::for_event(&event)).unwrap());
+//!
+//! let error = TriggerExpression::parse("payload.total > \"100\"")
+//!     .unwrap()
+//!     .check(&schema)
+//!     .unwrap_err();
+//! assert_eq!(error.to_string(), "1:17: expected a number, found a string");
+//! ```
+
+use super::dsl_ast::{CompareOp, Expr, Target, TestOp};
+use super::{dsl_compiler, dsl_parser};
+use crate::domain::contracts::TriggerCondition;
+use crate::domain::value_objects::{DslError, PayloadSchema};
+use hexafn_core::EventPattern;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Syntactically valid trigger expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggerExpression {
+    source: String,
+    expr: Expr,
+}
+
+impl TriggerExpression {
+    /// Parse `source`.
+    ///
+    /// # Errors
+    ///
+    /// [`DslError::Syntax`] pointing at the first token that does not fit the
+    /// grammar.
+    pub fn parse(source: &str) -> Result<Self, DslError> {
+        Ok(Self {
+            expr: dsl_parser::parse(source)?,
+            source: source.to_string(),
+        })
+    }
+
+    /// The text the expression was parsed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Type-check the expression against `schema`.
+    ///
+    /// # Errors
+    ///
+    /// [`DslError::Type`] for the first test that reads an undeclared field or
+    /// compares a value with a literal of the wrong kind.
+    pub fn check(&self, schema: &PayloadSchema) -> Result<(), DslError> {
+        self.compile(schema).map(drop)
+    }
+
+    /// Type-check the expression against `schema` and build its condition.
+    ///
+    /// # Errors
+    ///
+    /// As for [`check`](Self::check).
+    pub fn compile(&self, schema: &PayloadSchema) -> Result<Box<dyn TriggerCondition>, DslError> {
+        dsl_compiler::compile(&self.source, &self.expr, schema)
+    }
+
+    /// Event types the expression can match, or `None` if it does not pin
+    /// `event.type` down.
+    ///
+    /// Types come from `event.type == "..."` and `event.type in [...]` tests
+    /// that every match must pass: `&&` intersects them and `||` unites them.
+    pub fn event_patterns(&self) -> Option<Vec<EventPattern>> {
+        event_types(&self.expr)?
+            .iter()
+            .map(|event_type| EventPattern::parse(event_type).ok())
+            .collect()
+    }
+}
+
+/// Event types `expr` can be true for; `None` means any.
+fn event_types(expr: &Expr) -> Option<BTreeSet<String>> {
+    match expr {
+        Expr::Bool(false, _) => Some(BTreeSet::new()),
+        Expr::Test(test) if test.target == Target::EventType => match &test.op {
+            TestOp::Compare(CompareOp::Eq, literal) => literal
+                .value
+                .as_str()
+                .map(|text| BTreeSet::from([text.to_string()])),
+            TestOp::In(items) => items
+                .iter()
+                .map(|item| match &item.value {
+                    Value::String(text) => Some(text.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        },
+        Expr::All(terms, _) => terms
+            .iter()
+            .filter_map(event_types)
+            .reduce(|left, right| left.intersection(&right).cloned().collect()),
+        Expr::Any(terms, _) => terms.iter().try_fold(BTreeSet::new(), |mut all, term| {
+            all.extend(event_types(term)?);
+            Some(all)
+        }),
+        _ => None,
+    }
+}
+
+impl FromStr for TriggerExpression {
+    type Err = DslError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+impl Display for TriggerExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::TriggerContext;
+    use hexafn_core::EventEnvelope;
+    use serde_json::json;
+
+    #[test]
+    fn test_evaluates_headers_and_presence() {
+        let condition =
+            "headers.source == \"web\" && exists(payload.user.id) && missing(payload.test)"
+                .parse::<TriggerExpression>()
+                .unwrap()
+                .compile(&PayloadSchema::any())
+                .unwrap();
+        let event = EventEnvelope::new("signup", json!({ "user": { "id": 7 } }))
+            .with_header("source", "web");
+        assert!(condition
+            .matches(&TriggerContext::for_event(&event))
+            .unwrap());
+        let test_event = EventEnvelope::new("signup", json!({ "user": { "id": 7 }, "test": true }))
+            .with_header("source", "web");
+        assert!(!condition
+            .matches(&TriggerContext::for_event(&test_event))
+            .unwrap());
+    }
+
+    #[test]
+    fn test_event_patterns() {
+        let patterns = |source: &str| {
+            TriggerExpression::parse(source)
+                .unwrap()
+                .event_patterns()
+                .map(|patterns| {
+                    patterns
+                        .iter()
+                        .map(|pattern| pattern.as_str().to_string())
+                        .collect::<Vec<_>>()
+                })
+        };
+        assert_eq!(
+            patterns(
+                r#"event.type in ["a", "b", "c"] && payload.x > 1 && event.type != "a" && event.type in ["b", "c", "d"]"#
+            ),
+            Some(vec!["b".to_string(), "c".to_string()])
+        );
+        assert_eq!(
+            patterns(r#"event.type == "a" || (event.type == "b" && payload.x)"#),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(patterns(r#"event.type == "a" || payload.x"#), None);
+        assert_eq!(patterns(r#"!(event.type == "a")"#), None);
+        assert_eq!(patterns("false"), Some(vec![]));
+    }
+
+    #[test]
+    fn test_errors_report_lines() {
+        let source = "payload.a == 1 &&\n  payload.b ==";
+        let error = TriggerExpression::parse(source).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2:15: expected a literal, found end of input"
+        );
+        assert_eq!(error.render(source).lines().nth(1), Some("  payload.b =="));
+    }
+}
@@ -0,0 +1,404 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # TriggerRegistry (Trigger Module)
+//!
+//! This module provides [`TriggerRegistry`], the production
+//! [`TriggerEvaluator`]. It holds registered triggers indexed by the event
+//! types they declare through [`Trigger::event_patterns`], so evaluating an
+//! event only touches the triggers that can fire for it.
+//!
+//! ## Indexing
+//!
+//! - Exact patterns such as `order.created` are looked up by event type
+//! - Wildcard patterns starting with a literal segment, such as `order.*`, are
+//!   bucketed by that segment and matched against the event type
+//! - Triggers without patterns, or with patterns like `**`, are candidates for
+//!   every event
+//!
+//! Candidates are evaluated in registration order; inactive triggers are
+//! skipped. Ids are unique: registering an id twice fails with
+//! [`TriggerError::DuplicateTrigger`], and unregistering an unknown id with
+//! [`TriggerError::TriggerNotFound`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventEnvelope, HexaError, HexaErrorKind};
+//! use hexafn_trigger::{
+//!     ExpressionTrigger, PayloadSchema, TriggerContext, TriggerEvaluator, TriggerRegistry,
+//! };
+//! use serde_json::json;
+//!
+//! let schema = PayloadSchema::any();
+//! let mut registry = TriggerRegistry::new();
+//! for (id, when) in [
+//!     ("big-orders", r#"event.type == "order.created" && payload.total > 100"#),
+//!     ("refunds", r#"event.type in ["order.refunded", "payment.refunded"]"#),
+//!     ("vip", "payload.vip"),
+//! ] {
+//!     let trigger = ExpressionTrigger::new(id, id, when, &schema).unwrap();
+//!     registry.register_trigger(Box::new(trigger)).unwrap();
+//! }
+//!
+//! let event = EventEnvelope::new("order.created", json!({ "total": 250, "vip": true }));
+//! let evaluation = registry.evaluate_event(&TriggerContext::for_event(&event));
+//! assert_eq!(evaluation.fired, vec!["big-orders".to_string(), "vip".to_string()]);
+//! assert_eq!(evaluation.evaluated, 2);
+//!
+//! let duplicate = ExpressionTrigger::new("vip", "VIP", "true", &schema).unwrap();
+//! let err = registry.register_trigger(Box::new(duplicate)).unwrap_err();
+//! assert_eq!(err.error_kind(), HexaErrorKind::Conflict);
+//!
+//! let err = registry.unregister_trigger("missing").unwrap_err();
+//! assert_eq!(err.error_code(), "trigger.registry.not_found");
+//! ```
+
+use crate::domain::contracts::{Trigger, TriggerEvaluator};
+use crate::domain::value_objects::{TriggerContext, TriggerError, TriggerEvaluation};
+use hexafn_core::{EventPattern, HexaError};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// Registration sequence number; orders evaluation.
+type Seq = u64;
+
+/// Registered triggers, indexed by the event types they fire for.
+#[derive(Default)]
+pub struct TriggerRegistry {
+    next_seq: Seq,
+    entries: BTreeMap<Seq, Entry>,
+    ids: HashMap<String, Seq>,
+    /// Exact event type to triggers
+    exact: HashMap<String, HashSet<Seq>>,
+    /// First segment of a wildcard pattern to triggers
+    prefixed: HashMap<String, HashSet<Seq>>,
+    /// Triggers that are candidates for every event
+    unindexed: HashSet<Seq>,
+}
+
+struct Entry {
+    trigger: Box<dyn Trigger>,
+    patterns: Option<Vec<EventPattern>>,
+}
+
+impl Entry {
+    fn accepts(&self, event_type: &str) -> bool {
+        self.patterns.as_ref().map_or(true, |patterns| {
+            patterns.iter().any(|p| p.matches(event_type))
+        })
+    }
+}
+
+/// Where a pattern is filed in the index.
+enum Slot<'p> {
+    Exact(&'p str),
+    Prefixed(&'p str),
+    Unindexed,
+}
+
+fn slot(pattern: &EventPattern) -> Slot<'_> {
+    if pattern.is_exact() {
+        return Slot::Exact(pattern.as_str());
+    }
+    match pattern.as_str().split('.').next() {
+        Some(first) if first != "*" && first != "**" => Slot::Prefixed(first),
+        _ => Slot::Unindexed,
+    }
+}
+
+impl TriggerRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of registered triggers.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no trigger is registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The trigger registered under `id`.
+    pub fn get(&self, id: &str) -> Option<&dyn Trigger> {
+        let seq = self.ids.get(id)?;
+        self.entries.get(seq).map(|entry| entry.trigger.as_ref())
+    }
+
+    /// Evaluate every active trigger that can fire for the event in `context`.
+    pub fn evaluate_event(&self, context: &TriggerContext<'_>) -> TriggerEvaluation {
+        let mut evaluation = TriggerEvaluation::default();
+        for entry in self.candidates(context.event_type()) {
+            if !entry.trigger.is_active() {
+                continue;
+            }
+            evaluation.evaluated += 1;
+            match entry.trigger.evaluate(context) {
+                Ok(true) => evaluation.fired.push(entry.trigger.id()),
+                Ok(false) => {}
+                Err(error) => evaluation.failed.push((entry.trigger.id(), error)),
+            }
+        }
+        evaluation
+    }
+
+    /// Triggers whose patterns accept `event_type`, in registration order.
+    fn candidates<'a>(&'a self, event_type: &'a str) -> impl Iterator<Item = &'a Entry> + 'a {
+        let first = event_type.split('.').next().unwrap_or_default();
+        let mut seqs: Vec<Seq> = self
+            .exact
+            .get(event_type)
+            .into_iter()
+            .chain(self.prefixed.get(first))
+            .chain([&self.unindexed])
+            .flatten()
+            .copied()
+            .collect();
+        seqs.sort_unstable();
+        seqs.dedup();
+        seqs.into_iter()
+            .filter_map(|seq| self.entries.get(&seq))
+            .filter(move |entry| entry.accepts(event_type))
+    }
+
+    fn index(&mut self, seq: Seq, patterns: Option<&[EventPattern]>) {
+        let Some(patterns) = patterns else {
+            self.unindexed.insert(seq);
+            return;
+        };
+        for pattern in patterns {
+            match slot(pattern) {
+                Slot::Exact(key) => self.exact.entry(key.to_string()).or_default().insert(seq),
+                Slot::Prefixed(key) => self
+                    .prefixed
+                    .entry(key.to_string())
+                    .or_default()
+                    .insert(seq),
+                Slot::Unindexed => self.unindexed.insert(seq),
+            };
+        }
+    }
+
+    fn unindex(&mut self, seq: Seq, patterns: Option<&[EventPattern]>) {
+        fn remove(map: &mut HashMap<String, HashSet<Seq>>, key: &str, seq: Seq) {
+            if let Some(seqs) = map.get_mut(key) {
+                seqs.remove(&seq);
+                if seqs.is_empty() {
+                    map.remove(key);
+                }
+            }
+        }
+        let Some(patterns) = patterns else {
+            self.unindexed.remove(&seq);
+            return;
+        };
+        for pattern in patterns {
+            match slot(pattern) {
+                Slot::Exact(key) => remove(&mut self.exact, key, seq),
+                Slot::Prefixed(key) => remove(&mut self.prefixed, key, seq),
+                Slot::Unindexed => {
+                    self.unindexed.remove(&seq);
+                }
+            }
+        }
+    }
+}
+
+impl TriggerEvaluator for TriggerRegistry {
+    fn evaluate(
+        &self,
+        trigger: &dyn Trigger,
+        context: &TriggerContext<'_>,
+    ) -> Result<bool, Box<dyn HexaError>> {
+        trigger.evaluate(context)
+    }
+
+    fn register_trigger(&mut self, trigger: Box<dyn Trigger>) -> Result<(), Box<dyn HexaError>> {
+        let id = trigger.id();
+        if self.ids.contains_key(&id) {
+            return Err(Box::new(TriggerError::DuplicateTrigger { id }));
+        }
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        let patterns = trigger.event_patterns();
+        self.index(seq, patterns.as_deref());
+        self.ids.insert(id, seq);
+        self.entries.insert(seq, Entry { trigger, patterns });
+        Ok(())
+    }
+
+    fn unregister_trigger(&mut self, id: &str) -> Result<(), Box<dyn HexaError>> {
+        let seq = self
+            .ids
+            .remove(id)
+            .ok_or_else(|| TriggerError::TriggerNotFound { id: id.to_string() })?;
+        if let Some(entry) = self.entries.remove(&seq) {
+            self.unindex(seq, entry.patterns.as_deref());
+        }
+        Ok(())
+    }
+
+    fn list_triggers(&self) -> Vec<&dyn Trigger> {
+        self.entries
+            .values()
+            .map(|entry| entry.trigger.as_ref())
+            .collect()
+    }
+
+    fn get_active_triggers(&self) -> Vec<&dyn Trigger> {
+        self.entries
+            .values()
+            .map(|entry| entry.trigger.as_ref())
+            .filter(|trigger| trigger.is_active())
+            .collect()
+    }
+}
+
+impl fmt::Debug for TriggerRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TriggerRegistry")
+            .field("triggers", &self.ids.len())
+            .field("exact_types", &self.exact.len())
+            .field("prefixes", &self.prefixed.len())
+            .field("unindexed", &self.unindexed.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::TriggerCondition;
+    use crate::domain::entities::ExpressionTrigger;
+    use crate::domain::value_objects::PayloadSchema;
+    use hexafn_core::{EventEnvelope, GenericHexaError, HexaErrorKind};
+    use serde_json::json;
+
+    struct PatternTrigger {
+        id: &'static str,
+        patterns: &'static [&'static str],
+    }
+
+    impl Trigger for PatternTrigger {
+        fn id(&self) -> String {
+            self.id.to_string()
+        }
+        fn name(&self) -> String {
+            self.id.to_string()
+        }
+        fn is_active(&self) -> bool {
+            true
+        }
+        fn evaluate(&self, _: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+            if self.id == "broken" {
+                return Err(Box::new(GenericHexaError::new("test.broken", "broken")));
+            }
+            Ok(true)
+        }
+        fn get_conditions(&self) -> Vec<Box<dyn TriggerCondition>> {
+            vec![]
+        }
+        fn event_patterns(&self) -> Option<Vec<EventPattern>> {
+            Some(
+                self.patterns
+                    .iter()
+                    .map(|p| EventPattern::parse(p).unwrap())
+                    .collect(),
+            )
+        }
+    }
+
+    fn fire(registry: &TriggerRegistry, event_type: &str) -> TriggerEvaluation {
+        let event = EventEnvelope::new(event_type, json!({}));
+        registry.evaluate_event(&TriggerContext::for_event(&event))
+    }
+
+    #[test]
+    fn test_wildcard_patterns() {
+        let mut registry = TriggerRegistry::new();
+        for (id, patterns) in [
+            ("any-order", &["order.*"][..]),
+            ("everything", &["**"][..]),
+            ("created", &["order.created", "user.created"][..]),
+            ("deep", &["order.*.shipped"][..]),
+        ] {
+            registry
+                .register_trigger(Box::new(PatternTrigger { id, patterns }))
+                .unwrap();
+        }
+        assert_eq!(
+            fire(&registry, "order.created").fired,
+            ["any-order", "everything", "created"]
+        );
+        assert_eq!(
+            fire(&registry, "order.eu.shipped").fired,
+            ["everything", "deep"]
+        );
+        assert_eq!(
+            fire(&registry, "user.created").fired,
+            ["everything", "created"]
+        );
+    }
+
+    #[test]
+    fn test_failures_do_not_stop_evaluation() {
+        let mut registry = TriggerRegistry::new();
+        for id in ["broken", "fine"] {
+            registry
+                .register_trigger(Box::new(PatternTrigger {
+                    id,
+                    patterns: &["x"],
+                }))
+                .unwrap();
+        }
+        let evaluation = fire(&registry, "x");
+        assert_eq!(evaluation.fired, ["fine"]);
+        assert_eq!(evaluation.failed[0].0, "broken");
+        assert!(!evaluation.is_ok());
+    }
+
+    #[test]
+    fn test_unregister_removes_from_index() {
+        let mut registry = TriggerRegistry::new();
+        registry
+            .register_trigger(Box::new(PatternTrigger {
+                id: "a",
+                patterns: &["x", "y.*"],
+            }))
+            .unwrap();
+        registry.unregister_trigger("a").unwrap();
+        assert!(registry.is_empty());
+        assert_eq!(fire(&registry, "x").evaluated, 0);
+        assert!(registry.exact.is_empty() && registry.prefixed.is_empty());
+
+        let err = registry.unregister_trigger("a").unwrap_err();
+        assert_eq!(err.error_kind(), HexaErrorKind::NotFound);
+        registry
+            .register_trigger(Box::new(PatternTrigger {
+                id: "a",
+                patterns: &["x"],
+            }))
+            .unwrap();
+        assert!(registry.get("a").is_some());
+    }
+
+    #[test]
+    fn test_scales_with_indexed_triggers() {
+        let schema = PayloadSchema::any();
+        let mut registry = TriggerRegistry::new();
+        for n in 0..20_000 {
+            let when = format!("event.type == \"type.{n}\" && payload.n == {n}");
+            let trigger = ExpressionTrigger::new(format!("t{n}"), "", &when, &schema).unwrap();
+            registry.register_trigger(Box::new(trigger)).unwrap();
+        }
+        let event = EventEnvelope::new("type.12345", json!({ "n": 12345 }));
+        let evaluation = registry.evaluate_event(&TriggerContext::for_event(&event));
+        assert_eq!(evaluation.fired, ["t12345"]);
+        assert_eq!(evaluation.evaluated, 1);
+        assert_eq!(registry.len(), 20_000);
+    }
+}
@@ -0,0 +1,179 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # AttributeCondition (Trigger Module)
+//!
+//! This module defines [`AttributeCondition`], the stock [`TriggerCondition`]
+//! that applies a [`FieldPredicate`] to an attribute of the incoming event
+//! rather than to its payload, and [`EventAttribute`], the attributes it can
+//! address: the event type and individual headers.
+//!
+//! Attributes are strings, so the predicate sees a JSON string, or nothing for
+//! a header the event does not carry. Attribute checks need no payload lookup,
+//! so the default priority is half the predicate's
+//! [`cost`](FieldPredicate::cost) and they run before payload conditions.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::EventEnvelope;
+//! use hexafn_trigger::{
+//!     AttributeCondition, EventAttribute, FieldPredicate, TriggerCondition, TriggerContext,
+//! };
+//! use serde_json::json;
+//!
+//! let orders = AttributeCondition::new(
+//!     EventAttribute::Type,
+//!     FieldPredicate::StartsWith { value: "order.".to_string() },
+//! );
+//! assert_eq!(orders.description(), "event.type starts with \"order.\"");
+//!
+//! let event = EventEnvelope::new("order.created", json!({}));
+//! assert!(orders.matches(&TriggerContext::for_event(&event)).unwrap());
+//!
+//! let from_web: AttributeCondition = serde_json::from_value(json!({
+//!     "attribute": "headers.source",
+//!     "op": "eq",
+//!     "value": "web"
+//! }))
+//! .unwrap();
+//! assert!(!from_web.matches(&TriggerContext::for_event(&event)).unwrap());
+//! ```
+
+use crate::domain::contracts::TriggerCondition;
+use crate::domain::value_objects::{FieldPredicate, TriggerContext};
+use hexafn_core::HexaError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+
+/// Attribute of an incoming event.
+///
+/// Serializes as `event.type` or `headers.<name>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum EventAttribute {
+    /// The event type, e.g. `order.created`
+    Type,
+    /// The header with this name
+    Header(String),
+}
+
+impl EventAttribute {
+    fn value(&self, context: &TriggerContext<'_>) -> Option<Value> {
+        match self {
+            EventAttribute::Type => Some(Value::from(context.event_type())),
+            EventAttribute::Header(name) => context.header(name).map(Value::from),
+        }
+    }
+}
+
+impl Display for EventAttribute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventAttribute::Type => f.write_str("event.type"),
+            EventAttribute::Header(name) => write!(f, "headers.{name}"),
+        }
+    }
+}
+
+impl TryFrom<String> for EventAttribute {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        match text.strip_prefix("headers.") {
+            _ if text == "event.type" => Ok(EventAttribute::Type),
+            Some(name) if !name.is_empty() => Ok(EventAttribute::Header(name.to_string())),
+            _ => Err(format!(
+                "unknown event attribute '{text}'; expected 'event.type' or 'headers.<name>'"
+            )),
+        }
+    }
+}
+
+impl From<EventAttribute> for String {
+    fn from(attribute: EventAttribute) -> Self {
+        attribute.to_string()
+    }
+}
+
+/// Condition on an event attribute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeCondition {
+    /// Attribute the predicate is applied to
+    pub attribute: EventAttribute,
+    /// Test applied to the attribute
+    #[serde(flatten)]
+    pub predicate: FieldPredicate,
+    /// Explicit priority; lower runs first
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+}
+
+impl AttributeCondition {
+    /// Apply `predicate` to `attribute`.
+    pub fn new(attribute: EventAttribute, predicate: FieldPredicate) -> Self {
+        Self {
+            attribute,
+            predicate,
+            priority: None,
+        }
+    }
+
+    /// Override the cost-based default priority.
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+}
+
+impl TriggerCondition for AttributeCondition {
+    fn matches(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+        Ok(self.predicate.test(self.attribute.value(context).as_ref()))
+    }
+
+    fn description(&self) -> String {
+        format!("{} {}", self.attribute, self.predicate)
+    }
+
+    fn get_priority(&self) -> u32 {
+        self.priority.unwrap_or_else(|| self.predicate.cost() / 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hexafn_core::EventEnvelope;
+    use serde_json::json;
+
+    #[test]
+    fn test_missing_header() {
+        let event = EventEnvelope::new("ping", json!({})).with_header("source", "web");
+        let context = TriggerContext::for_event(&event);
+        let traced = AttributeCondition::new(
+            EventAttribute::Header("trace-id".to_string()),
+            FieldPredicate::Exists,
+        );
+        assert!(!traced.matches(&context).unwrap());
+        let web = AttributeCondition::new(
+            EventAttribute::Header("source".to_string()),
+            FieldPredicate::Equals {
+                value: json!("web"),
+            },
+        );
+        assert!(web.matches(&context).unwrap());
+        assert!(web.get_priority() < FieldPredicate::Exists.cost());
+    }
+
+    #[test]
+    fn test_attribute_names() {
+        for bad in ["event.id", "headers.", "payload.total"] {
+            assert!(EventAttribute::try_from(bad.to_string()).is_err(), "{bad}");
+        }
+        assert_eq!(
+            EventAttribute::try_from("headers.x-request-id".to_string()).unwrap(),
+            EventAttribute::Header("x-request-id".to_string())
+        );
+    }
+}
@@ -0,0 +1,315 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # CompoundCondition (Trigger Module)
+//!
+//! This module defines [`CompoundCondition`], a [`TriggerCondition`] built from
+//! other conditions and combined as described by a [`CompoundType`]: all of
+//! them, any of them, none of them (`Not`), or at least `n` of them.
+//! Compound conditions nest, so any boolean rule can be expressed as a tree.
+//!
+//! Children are ordered by [`TriggerCondition::get_priority`] when the compound
+//! is built (ties keep their given order) and evaluated in that order, stopping
+//! as soon as the result is known, so cheap conditions should carry low
+//! priorities. An error from a child ends evaluation and is returned as is.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::EventEnvelope;
+//! use hexafn_trigger::{
+//!     CompoundCondition, FieldCondition, FieldPredicate, TriggerCondition, TriggerContext,
+//! };
+//! use serde_json::json;
+//!
+//! let big_order = FieldCondition::new("total", FieldPredicate::GreaterThan { value: 100.into() }).unwrap();
+//! let blocked = FieldCondition::new("country", FieldPredicate::In { values: vec![json!("XX")] }).unwrap();
+//! let rule = CompoundCondition::all(vec![
+//!     Box::new(big_order),
+//!     Box::new(CompoundCondition::not(Box::new(blocked))),
+//! ]);
+//! assert_eq!(rule.description(), r#"(payload total > 100 AND NOT payload country in ["XX"])"#);
+//!
+//! let event = EventEnvelope::new("order.created", json!({ "total": 120, "country": "DE" }));
+//! assert!(rule.matches(&TriggerContext::for_event(&event)).unwrap());
+//! ```
+
+use crate::domain::contracts::TriggerCondition;
+use crate::domain::value_objects::{CompoundType, TriggerContext};
+use hexafn_core::HexaError;
+use std::fmt;
+
+/// Condition combining child conditions.
+pub struct CompoundCondition {
+    compound: CompoundType,
+    conditions: Vec<Box<dyn TriggerCondition>>,
+    priority: Option<u32>,
+}
+
+impl CompoundCondition {
+    /// Combine `conditions` as described by `compound`.
+    ///
+    /// A `Not` compound matches when none of its children match, so with a
+    /// single child it is that child negated, as built by
+    /// [`CompoundCondition::not`].
+    pub fn new(compound: CompoundType, mut conditions: Vec<Box<dyn TriggerCondition>>) -> Self {
+        conditions.sort_by_key(|condition| condition.get_priority());
+        Self {
+            compound,
+            conditions,
+            priority: None,
+        }
+    }
+
+    /// Match when every condition matches.
+    pub fn all(conditions: Vec<Box<dyn TriggerCondition>>) -> Self {
+        Self::new(CompoundType::All, conditions)
+    }
+
+    /// Match when at least one condition matches.
+    pub fn any(conditions: Vec<Box<dyn TriggerCondition>>) -> Self {
+        Self::new(CompoundType::Any, conditions)
+    }
+
+    /// Match when `condition` does not.
+    pub fn not(condition: Box<dyn TriggerCondition>) -> Self {
+        Self::new(CompoundType::Not, vec![condition])
+    }
+
+    /// Match when at least `n` of `conditions` match.
+    pub fn at_least(n: usize, conditions: Vec<Box<dyn TriggerCondition>>) -> Self {
+        Self::new(CompoundType::AtLeast(n), conditions)
+    }
+
+    /// Override the default priority, which is the highest child priority.
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// How the children are combined.
+    pub fn compound_type(&self) -> CompoundType {
+        self.compound
+    }
+
+    /// Child conditions in evaluation order.
+    pub fn conditions(&self) -> &[Box<dyn TriggerCondition>] {
+        &self.conditions
+    }
+
+    fn any_match(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+        for condition in &self.conditions {
+            if condition.matches(context)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl TriggerCondition for CompoundCondition {
+    fn matches(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+        match self.compound {
+            CompoundType::All => {
+                for condition in &self.conditions {
+                    if !condition.matches(context)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            CompoundType::Any => self.any_match(context),
+            CompoundType::Not => Ok(!self.any_match(context)?),
+            CompoundType::AtLeast(n) => {
+                let mut matched = 0;
+                for (checked, condition) in self.conditions.iter().enumerate() {
+                    if matched >= n {
+                        break;
+                    }
+                    if matched + (self.conditions.len() - checked) < n {
+                        return Ok(false);
+                    }
+                    if condition.matches(context)? {
+                        matched += 1;
+                    }
+                }
+                Ok(matched >= n)
+            }
+        }
+    }
+
+    fn description(&self) -> String {
+        let parts: Vec<String> = self.conditions.iter().map(|c| c.description()).collect();
+        match self.compound {
+            CompoundType::All if parts.is_empty() => "always".to_string(),
+            CompoundType::Any if parts.is_empty() => "never".to_string(),
+            CompoundType::All => format!("({})", parts.join(" AND ")),
+            CompoundType::Any => format!("({})", parts.join(" OR ")),
+            CompoundType::Not if parts.is_empty() => "always".to_string(),
+            CompoundType::Not if parts.len() == 1 => format!("NOT {}", parts[0]),
+            CompoundType::Not => format!("NOT ({})", parts.join(" OR ")),
+            CompoundType::AtLeast(_) => format!("{} ({})", self.compound, parts.join(", ")),
+        }
+    }
+
+    fn get_priority(&self) -> u32 {
+        self.priority.unwrap_or_else(|| {
+            self.conditions
+                .iter()
+                .map(|condition| condition.get_priority())
+                .max()
+                .unwrap_or(0)
+        })
+    }
+}
+
+impl fmt::Debug for CompoundCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompoundCondition")
+            .field("compound", &self.compound)
+            .field("description", &self.description())
+            .field("priority", &self.get_priority())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hexafn_core::EventEnvelope;
+    use serde_json::json;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Log = Rc<RefCell<Vec<&'static str>>>;
+
+    /// Fixed result that records its name in a shared log when evaluated.
+    struct Probe {
+        name: &'static str,
+        result: bool,
+        priority: u32,
+        log: Log,
+    }
+
+    impl TriggerCondition for Probe {
+        fn matches(&self, _context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+            self.log.borrow_mut().push(self.name);
+            Ok(self.result)
+        }
+        fn description(&self) -> String {
+            self.name.to_string()
+        }
+        fn get_priority(&self) -> u32 {
+            self.priority
+        }
+    }
+
+    fn probes(specs: &[(&'static str, bool, u32)]) -> (Vec<Box<dyn TriggerCondition>>, Log) {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let conditions = specs
+            .iter()
+            .map(|&(name, result, priority)| {
+                Box::new(Probe {
+                    name,
+                    result,
+                    priority,
+                    log: Rc::clone(&log),
+                }) as Box<dyn TriggerCondition>
+            })
+            .collect();
+        (conditions, log)
+    }
+
+    fn evaluate(condition: &CompoundCondition) -> bool {
+        let event = EventEnvelope::new("test", json!({}));
+        condition
+            .matches(&TriggerContext::for_event(&event))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_all_short_circuits_in_priority_order() {
+        let (conditions, log) =
+            probes(&[("slow", true, 30), ("cheap", false, 1), ("mid", true, 10)]);
+        let all = CompoundCondition::all(conditions);
+        assert!(!evaluate(&all));
+        assert_eq!(*log.borrow(), ["cheap"]);
+        assert_eq!(all.description(), "(cheap AND mid AND slow)");
+        assert_eq!(all.get_priority(), 30);
+    }
+
+    #[test]
+    fn test_any_stops_at_first_match() {
+        let (conditions, log) = probes(&[("b", true, 2), ("a", false, 1), ("c", true, 3)]);
+        assert!(evaluate(&CompoundCondition::any(conditions)));
+        assert_eq!(*log.borrow(), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_at_least_stops_when_decided() {
+        let (conditions, log) = probes(&[("a", true, 1), ("b", true, 2), ("c", true, 3)]);
+        assert!(evaluate(&CompoundCondition::at_least(2, conditions)));
+        assert_eq!(*log.borrow(), ["a", "b"]);
+
+        let (conditions, log) = probes(&[("a", false, 1), ("b", false, 2), ("c", true, 3)]);
+        let two_of = CompoundCondition::at_least(2, conditions);
+        assert!(!evaluate(&two_of));
+        assert_eq!(*log.borrow(), ["a", "b"]);
+        assert_eq!(two_of.description(), "at least 2 of (a, b, c)");
+
+        let (conditions, _) = probes(&[("a", false, 1)]);
+        assert!(evaluate(&CompoundCondition::at_least(0, conditions)));
+    }
+
+    #[test]
+    fn test_empty_and_negated() {
+        assert!(evaluate(&CompoundCondition::all(vec![])));
+        assert!(!evaluate(&CompoundCondition::any(vec![])));
+        assert_eq!(CompoundCondition::any(vec![]).description(), "never");
+
+        let (mut conditions, _) = probes(&[("x", true, 7)]);
+        let not = CompoundCondition::not(conditions.remove(0)).with_priority(2);
+        assert!(!evaluate(&not));
+        assert_eq!(not.description(), "NOT x");
+        assert_eq!(not.get_priority(), 2);
+        assert_eq!(not.compound_type(), CompoundType::Not);
+
+        let (conditions, log) = probes(&[("b", true, 2), ("a", false, 1)]);
+        let neither = CompoundCondition::new(CompoundType::Not, conditions);
+        assert!(!evaluate(&neither));
+        assert_eq!(*log.borrow(), ["a", "b"]);
+        assert_eq!(neither.description(), "NOT (a OR b)");
+
+        let (conditions, _) = probes(&[("a", false, 1), ("b", false, 2)]);
+        assert!(evaluate(&CompoundCondition::new(
+            CompoundType::Not,
+            conditions
+        )));
+        let empty = CompoundCondition::new(CompoundType::Not, vec![]);
+        assert!(evaluate(&empty));
+        assert_eq!(empty.description(), "always");
+    }
+
+    #[test]
+    fn test_errors_propagate() {
+        struct Failing;
+        impl TriggerCondition for Failing {
+            fn matches(&self, _: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+                Err(Box::new(hexafn_core::GenericHexaError::new(
+                    "trigger.test.failed",
+                    "probe failed",
+                )))
+            }
+            fn description(&self) -> String {
+                "failing".to_string()
+            }
+            fn get_priority(&self) -> u32 {
+                0
+            }
+        }
+        let event = EventEnvelope::new("test", json!({}));
+        let any = CompoundCondition::any(vec![Box::new(Failing)]);
+        let err = any.matches(&TriggerContext::for_event(&event)).unwrap_err();
+        assert_eq!(err.error_code(), "trigger.test.failed");
+    }
+}
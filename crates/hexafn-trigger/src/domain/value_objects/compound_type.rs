@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # CompoundType (Trigger Module)
+//!
+//! This module defines [`CompoundType`], the way a
+//! [`CompoundCondition`](crate::CompoundCondition) combines the results of its
+//! child conditions.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_trigger::CompoundType;
+//!
+//! assert_eq!(CompoundType::AtLeast(2).to_string(), "at least 2 of");
+//! let parsed: CompoundType = serde_json::from_str(r#"{"at_least":2}"#).unwrap();
+//! assert_eq!(parsed, CompoundType::AtLeast(2));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// How a compound condition combines its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompoundType {
+    /// Every child matches; no children always match
+    All,
+    /// At least one child matches; no children never match
+    Any,
+    /// No child matches; with one child, that child does not match
+    Not,
+    /// At least `n` children match
+    AtLeast(usize),
+}
+
+impl Display for CompoundType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompoundType::All => f.write_str("all of"),
+            CompoundType::Any => f.write_str("any of"),
+            CompoundType::Not => f.write_str("not"),
+            CompoundType::AtLeast(n) => write!(f, "at least {n} of"),
+        }
+    }
+}
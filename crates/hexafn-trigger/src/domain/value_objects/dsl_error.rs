@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # DSL Errors (Trigger Module)
+//!
+//! This module defines [`DslError`], the concrete [`HexaError`] raised when a
+//! trigger expression cannot be parsed or does not type-check, and [`Span`],
+//! the byte range of the source text it points at.
+//!
+//! Every error carries the span of the offending token or sub-expression along
+//! with its 1-based line and column; [`DslError::render`] prints the source line
+//! with the span underlined.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::HexaError;
+//! use hexafn_trigger::TriggerExpression;
+//!
+//! let source = r#"event.type == "order.created" && payload.total >"#;
+//! let error = TriggerExpression::parse(source).unwrap_err();
+//! assert_eq!(error.error_code(), "trigger.dsl.syntax");
+//! assert_eq!(error.to_string(), "1:49: expected a literal, found end of input");
+//! assert_eq!(
+//!     error.render(source),
+//!     format!("1:49: expected a literal, found end of input\n{source}\n{}^", " ".repeat(48))
+//! );
+//! ```
+
+use hexafn_core::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use thiserror::Error;
+
+/// Byte range `start..end` of an expression's source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Offset of the first byte
+    pub start: usize,
+    /// Offset one past the last byte
+    pub end: usize,
+}
+
+impl Span {
+    /// Span covering `start..end`.
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Smallest span covering both `self` and `other`.
+    pub(crate) fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    /// 1-based line and column (in characters) of the span start.
+    pub fn line_column(&self, source: &str) -> (usize, usize) {
+        let before = &source[..self.start.min(source.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+}
+
+/// Errors produced while parsing or type-checking a trigger expression.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DslError {
+    /// The text is not a well-formed expression.
+    #[error("{line}:{column}: {message}")]
+    Syntax {
+        /// Offending token
+        span: Span,
+        /// Line of the span start
+        line: usize,
+        /// Column of the span start
+        column: usize,
+        /// What was wrong
+        message: String,
+    },
+
+    /// The expression is well-formed but does not fit the payload schema.
+    #[error("{line}:{column}: {message}")]
+    Type {
+        /// Offending sub-expression
+        span: Span,
+        /// Line of the span start
+        line: usize,
+        /// Column of the span start
+        column: usize,
+        /// What was wrong
+        message: String,
+    },
+}
+
+impl DslError {
+    pub(crate) fn syntax(source: &str, span: Span, message: impl Into<String>) -> Self {
+        let (line, column) = span.line_column(source);
+        DslError::Syntax {
+            span,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn type_error(source: &str, span: Span, message: impl Into<String>) -> Self {
+        let (line, column) = span.line_column(source);
+        DslError::Type {
+            span,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Where in the source the error was found.
+    pub fn span(&self) -> Span {
+        match self {
+            DslError::Syntax { span, .. } | DslError::Type { span, .. } => *span,
+        }
+    }
+
+    /// The error followed by the offending source line with the span
+    /// underlined by carets.
+    pub fn render(&self, source: &str) -> String {
+        let span = self.span();
+        let start = span.start.min(source.len());
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+        let indent = source[line_start..start].chars().count();
+        let width = source[start..span.end.clamp(start, line_end)]
+            .chars()
+            .count()
+            .max(1);
+        format!(
+            "{self}\n{}\n{}{}",
+            &source[line_start..line_end],
+            " ".repeat(indent),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl HexaError for DslError {
+    fn error_code(&self) -> &str {
+        match self {
+            DslError::Syntax { .. } => "trigger.dsl.syntax",
+            DslError::Type { .. } => "trigger.dsl.type",
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            DslError::Syntax { .. } => "Trigger expression cannot be parsed",
+            DslError::Type { .. } => "Trigger expression does not fit the payload schema",
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        HexaErrorKind::Validation
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        HexaErrorSeverity::Medium
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_column_counts_characters() {
+        let source = "a &&\n  ünï == 1";
+        let span = Span::new(source.find("==").unwrap(), source.find("==").unwrap() + 2);
+        assert_eq!(span.line_column(source), (2, 7));
+
+        let error = DslError::type_error(source, span, "bad");
+        assert_eq!(error.to_string(), "2:7: bad");
+        assert_eq!(error.render(source), "2:7: bad\n  ünï == 1\n      ^^");
+    }
+}
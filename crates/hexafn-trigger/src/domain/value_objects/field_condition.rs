@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # FieldCondition (Trigger Module)
+//!
+//! This module defines [`FieldCondition`], the stock [`TriggerCondition`] that
+//! applies a [`FieldPredicate`] to the event payload value at a [`FieldPath`].
+//! It covers equality, numeric comparison, set membership, regex and string
+//! matching, presence checks and array membership, so that rules such as
+//! `payload.user.age > 18` need no hand-written condition.
+//!
+//! A condition serializes as its path, the predicate's `op` and operands, and an
+//! optional `priority`. Without one, the priority is the predicate's
+//! [`cost`](FieldPredicate::cost), so cheap comparisons rank before regexes.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::EventEnvelope;
+//! use hexafn_trigger::{FieldCondition, FieldPredicate, TriggerCondition, TriggerContext};
+//! use serde_json::json;
+//!
+//! let adult = FieldCondition::new("user.age", FieldPredicate::GreaterThan { value: 18.into() }).unwrap();
+//! assert_eq!(adult.description(), "payload user.age > 18");
+//!
+//! let event = EventEnvelope::new("user.registered", json!({ "user": { "age": 21 } }));
+//! assert!(adult.matches(&TriggerContext::for_event(&event)).unwrap());
+//!
+//! let vip: FieldCondition = serde_json::from_value(json!({
+//!     "path": "/tags",
+//!     "op": "array_contains",
+//!     "value": "vip",
+//!     "priority": 1
+//! }))
+//! .unwrap();
+//! assert!(!vip.matches(&TriggerContext::for_event(&event)).unwrap());
+//! assert_eq!(vip.get_priority(), 1);
+//! ```
+
+use crate::domain::contracts::TriggerCondition;
+use crate::domain::value_objects::{FieldPath, FieldPredicate, TriggerContext, TriggerError};
+use hexafn_core::HexaError;
+use serde::{Deserialize, Serialize};
+
+/// Condition on a single payload field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldCondition {
+    /// Field the predicate is applied to
+    pub path: FieldPath,
+    /// Test applied to the field
+    #[serde(flatten)]
+    pub predicate: FieldPredicate,
+    /// Explicit priority; lower runs first
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+}
+
+impl FieldCondition {
+    /// Apply `predicate` to the payload field at `path`.
+    ///
+    /// # Errors
+    ///
+    /// [`TriggerError::InvalidPath`] if `path` cannot be parsed.
+    pub fn new(path: &str, predicate: FieldPredicate) -> Result<Self, TriggerError> {
+        Ok(Self {
+            path: FieldPath::parse(path)?,
+            predicate,
+            priority: None,
+        })
+    }
+
+    /// Override the cost-based default priority.
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+}
+
+impl TriggerCondition for FieldCondition {
+    fn matches(&self, context: &TriggerContext<'_>) -> Result<bool, Box<dyn HexaError>> {
+        Ok(self.predicate.test(self.path.resolve(context.payload())))
+    }
+
+    fn description(&self) -> String {
+        if self.path.as_str().is_empty() {
+            format!("payload {}", self.predicate)
+        } else {
+            format!("payload {} {}", self.path, self.predicate)
+        }
+    }
+
+    fn get_priority(&self) -> u32 {
+        self.priority.unwrap_or_else(|| self.predicate.cost())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::Pattern;
+    use hexafn_core::EventEnvelope;
+    use serde_json::json;
+
+    fn matches(condition: &FieldCondition, payload: serde_json::Value) -> bool {
+        let event = EventEnvelope::new("test", payload);
+        condition
+            .matches(&TriggerContext::for_event(&event))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_resolves_path_against_payload() {
+        let sku = FieldCondition::new(
+            "items[1].sku",
+            FieldPredicate::StartsWith {
+                value: "B-".to_string(),
+            },
+        )
+        .unwrap();
+        assert!(matches(
+            &sku,
+            json!({ "items": [{ "sku": "A-1" }, { "sku": "B-2" }] })
+        ));
+        assert!(!matches(&sku, json!({ "items": [{ "sku": "B-1" }] })));
+        assert_eq!(sku.description(), "payload items[1].sku starts with \"B-\"");
+    }
+
+    #[test]
+    fn test_default_priority_follows_cost() {
+        let cheap = FieldCondition::new("a", FieldPredicate::Exists).unwrap();
+        let regex = FieldCondition::new(
+            "a",
+            FieldPredicate::Matches {
+                pattern: Pattern::new("x+").unwrap(),
+            },
+        )
+        .unwrap();
+        assert!(cheap.get_priority() < regex.get_priority());
+        assert_eq!(regex.with_priority(0).get_priority(), 0);
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let condition = FieldCondition::new("", FieldPredicate::Equals { value: json!(null) })
+            .unwrap()
+            .with_priority(3);
+        let value = serde_json::to_value(&condition).unwrap();
+        assert_eq!(
+            value,
+            json!({ "path": "", "op": "eq", "value": null, "priority": 3 })
+        );
+        assert_eq!(
+            serde_json::from_value::<FieldCondition>(value).unwrap(),
+            condition
+        );
+        assert_eq!(condition.description(), "payload == null");
+    }
+}
@@ -0,0 +1,254 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # FieldPath (Trigger Module)
+//!
+//! This module defines [`FieldPath`], the address of a value inside an event
+//! payload. A path is written either as a JSON pointer (RFC 6901), such as
+//! `/user/age` or `/items/0/sku`, or as a dotted path with optional bracketed
+//! indices, such as `user.age` or `items[0].sku`. The empty string addresses the
+//! whole payload in both forms.
+//!
+//! Numeric segments index arrays; on objects they are ordinary keys. A path
+//! serializes as the text it was parsed from.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_trigger::FieldPath;
+//! use serde_json::json;
+//!
+//! let payload = json!({ "user": { "age": 21 }, "items": [{ "sku": "A-1" }] });
+//!
+//! let age = FieldPath::parse("user.age").unwrap();
+//! assert_eq!(age.resolve(&payload), Some(&json!(21)));
+//!
+//! let sku = FieldPath::parse("/items/0/sku").unwrap();
+//! assert_eq!(sku, FieldPath::parse("items[0].sku").unwrap());
+//! assert_eq!(sku.resolve(&payload), Some(&json!("A-1")));
+//!
+//! assert!(FieldPath::parse("user.email").unwrap().resolve(&payload).is_none());
+//! ```
+
+use crate::domain::value_objects::TriggerError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+
+/// Location of a value inside a JSON payload.
+///
+/// Two paths are equal when they address the same segments, whichever form
+/// they were written in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct FieldPath {
+    text: String,
+    segments: Vec<String>,
+}
+
+impl FieldPath {
+    /// Parse a JSON pointer (leading `/`) or a dotted path.
+    ///
+    /// # Errors
+    ///
+    /// [`TriggerError::InvalidPath`] for a bad `~` escape in a pointer, or an
+    /// empty segment or unbalanced bracket in a dotted path.
+    pub fn parse(text: &str) -> Result<Self, TriggerError> {
+        let segments = if text.is_empty() {
+            Ok(Vec::new())
+        } else if let Some(pointer) = text.strip_prefix('/') {
+            pointer
+                .split('/')
+                .map(unescape_pointer_segment)
+                .collect::<Result<_, _>>()
+        } else {
+            dotted_segments(text)
+        }
+        .map_err(|reason| TriggerError::InvalidPath {
+            path: text.to_string(),
+            reason,
+        })?;
+        Ok(Self {
+            text: text.to_string(),
+            segments,
+        })
+    }
+
+    /// Build a path from unescaped segments. It is written as a dotted path
+    /// when every segment allows it, and as a JSON pointer otherwise.
+    pub fn from_segments(segments: Vec<String>) -> Self {
+        let dotted = segments
+            .first()
+            .map_or(true, |first| !first.starts_with('/'))
+            && segments
+                .iter()
+                .all(|s| !s.is_empty() && !s.contains(['.', '[', ']']));
+        let text = if dotted {
+            segments.join(".")
+        } else {
+            segments
+                .iter()
+                .map(|s| format!("/{}", s.replace('~', "~0").replace('/', "~1")))
+                .collect()
+        };
+        Self { text, segments }
+    }
+
+    /// The path as written.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Keys and indices from the root, unescaped.
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// The value at this path, if every segment exists.
+    pub fn resolve<'v>(&self, root: &'v Value) -> Option<&'v Value> {
+        self.segments
+            .iter()
+            .try_fold(root, |value, segment| match value {
+                Value::Object(object) => object.get(segment),
+                Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+                _ => None,
+            })
+    }
+}
+
+fn unescape_pointer_segment(segment: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(segment.len());
+    let mut chars = segment.chars();
+    while let Some(c) = chars.next() {
+        if c != '~' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('0') => out.push('~'),
+            Some('1') => out.push('/'),
+            _ => return Err("'~' must be followed by '0' or '1'".to_string()),
+        }
+    }
+    Ok(out)
+}
+
+fn dotted_segments(text: &str) -> Result<Vec<String>, String> {
+    let mut segments = Vec::new();
+    for part in text.split('.') {
+        let (key, mut indices) = match part.find('[') {
+            Some(open) => part.split_at(open),
+            None => (part, ""),
+        };
+        if key.is_empty() && indices.is_empty() {
+            return Err("empty segment".to_string());
+        }
+        if !key.is_empty() {
+            segments.push(key.to_string());
+        }
+        while !indices.is_empty() {
+            let close = indices
+                .find(']')
+                .filter(|_| indices.starts_with('['))
+                .ok_or_else(|| format!("malformed index in '{part}'"))?;
+            let index = &indices[1..close];
+            if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(format!("index '{index}' is not a non-negative integer"));
+            }
+            segments.push(index.to_string());
+            indices = &indices[close + 1..];
+        }
+    }
+    Ok(segments)
+}
+
+impl PartialEq for FieldPath {
+    fn eq(&self, other: &Self) -> bool {
+        self.segments == other.segments
+    }
+}
+
+impl Eq for FieldPath {}
+
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl TryFrom<String> for FieldPath {
+    type Error = TriggerError;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        Self::parse(&text)
+    }
+}
+
+impl From<FieldPath> for String {
+    fn from(path: FieldPath) -> Self {
+        path.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_pointer_escapes() {
+        let path = FieldPath::parse("/a~1b/c~0d").unwrap();
+        assert_eq!(path.segments(), ["a/b", "c~d"]);
+        assert_eq!(
+            path.resolve(&json!({ "a/b": { "c~d": true } })),
+            Some(&json!(true))
+        );
+        assert!(FieldPath::parse("/a~2").is_err());
+    }
+
+    #[test]
+    fn test_dotted_paths() {
+        assert_eq!(
+            FieldPath::parse("matrix[1][0].x").unwrap().segments(),
+            ["matrix", "1", "0", "x"]
+        );
+        assert_eq!(FieldPath::parse("[2]").unwrap().segments(), ["2"]);
+        for bad in ["a.", ".a", "a[", "a[x]", "a[1]b", "a[-1]"] {
+            assert!(FieldPath::parse(bad).is_err(), "{bad} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_root_and_numeric_keys() {
+        let root = FieldPath::parse("").unwrap();
+        let payload = json!({ "0": "zero" });
+        assert_eq!(root.resolve(&payload), Some(&payload));
+        assert_eq!(
+            FieldPath::parse("0").unwrap().resolve(&payload),
+            Some(&json!("zero"))
+        );
+    }
+
+    #[test]
+    fn test_from_segments_round_trips() {
+        for segments in [vec!["items", "0", "sku"], vec!["a.b", "c/d~"], vec!["/x"]] {
+            let segments: Vec<String> = segments.into_iter().map(String::from).collect();
+            let path = FieldPath::from_segments(segments.clone());
+            assert_eq!(
+                FieldPath::parse(path.as_str()).unwrap().segments(),
+                segments
+            );
+        }
+        assert_eq!(
+            FieldPath::from_segments(vec!["a.b".to_string()]).as_str(),
+            "/a.b"
+        );
+    }
+
+    #[test]
+    fn test_serializes_as_written() {
+        let path: FieldPath = serde_json::from_value(json!("items[0].sku")).unwrap();
+        assert_eq!(serde_json::to_value(&path).unwrap(), json!("items[0].sku"));
+        assert!(serde_json::from_value::<FieldPath>(json!("a..b")).is_err());
+    }
+}
@@ -0,0 +1,370 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # FieldPredicate (Trigger Module)
+//!
+//! This module defines [`FieldPredicate`], the test a
+//! [`FieldCondition`](crate::FieldCondition) applies to the payload value at its
+//! path, and [`Pattern`], a compiled regular expression that serializes as its
+//! source text.
+//!
+//! Predicates serialize with an `op` tag, e.g. `{ "op": "gt", "value": 18 }`.
+//! They never fail: a value of the wrong kind simply does not match. Only
+//! [`FieldPredicate::Missing`] and [`FieldPredicate::NotEquals`] match an absent
+//! field, and [`FieldPredicate::Exists`] matches a present `null`. Numbers
+//! compare by value, so `1` equals `1.0`. Two integers compare exactly, so ids
+//! beyond 2^53 are told apart; a float on either side compares as `f64`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_trigger::{FieldPredicate, Pattern};
+//! use serde_json::json;
+//!
+//! let adult = FieldPredicate::GreaterOrEqual { value: 18.into() };
+//! assert!(adult.test(Some(&json!(21))));
+//! assert!(!adult.test(Some(&json!("21"))));
+//! assert!(!adult.test(None));
+//!
+//! let sku = FieldPredicate::Matches { pattern: Pattern::new("^[A-Z]-\\d+$").unwrap() };
+//! assert!(sku.test(Some(&json!("A-12"))));
+//!
+//! let parsed: FieldPredicate = serde_json::from_value(json!({ "op": "in", "values": ["EU", "US"] })).unwrap();
+//! assert!(parsed.test(Some(&json!("EU"))));
+//! assert_eq!(parsed.to_string(), r#"in ["EU","US"]"#);
+//! ```
+
+use crate::domain::value_objects::TriggerError;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// Compiled regular expression.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Pattern(Regex);
+
+impl Pattern {
+    /// Compile `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// [`TriggerError::InvalidPattern`] if it is not a valid regular expression.
+    pub fn new(pattern: &str) -> Result<Self, TriggerError> {
+        Regex::new(pattern)
+            .map(Self)
+            .map_err(|e| TriggerError::InvalidPattern {
+                pattern: pattern.to_string(),
+                reason: e.to_string(),
+            })
+    }
+
+    /// The source text.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Whether `text` contains a match.
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.is_match(text)
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl TryFrom<String> for Pattern {
+    type Error = TriggerError;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        Self::new(&pattern)
+    }
+}
+
+impl From<Pattern> for String {
+    fn from(pattern: Pattern) -> Self {
+        pattern.as_str().to_string()
+    }
+}
+
+/// Test applied to a single payload value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum FieldPredicate {
+    /// The value equals `value`.
+    #[serde(rename = "eq")]
+    Equals {
+        /// Expected value
+        value: Value,
+    },
+    /// The value is absent or differs from `value`.
+    #[serde(rename = "ne")]
+    NotEquals {
+        /// Rejected value
+        value: Value,
+    },
+    /// The value is a number greater than `value`.
+    #[serde(rename = "gt")]
+    GreaterThan {
+        /// Exclusive lower bound
+        value: Number,
+    },
+    /// The value is a number greater than or equal to `value`.
+    #[serde(rename = "gte")]
+    GreaterOrEqual {
+        /// Inclusive lower bound
+        value: Number,
+    },
+    /// The value is a number less than `value`.
+    #[serde(rename = "lt")]
+    LessThan {
+        /// Exclusive upper bound
+        value: Number,
+    },
+    /// The value is a number less than or equal to `value`.
+    #[serde(rename = "lte")]
+    LessOrEqual {
+        /// Inclusive upper bound
+        value: Number,
+    },
+    /// The value equals one of `values`.
+    In {
+        /// Accepted values
+        values: Vec<Value>,
+    },
+    /// The value is a string containing a match of `pattern`.
+    Matches {
+        /// Regular expression; anchor it with `^` and `$` to match whole strings
+        pattern: Pattern,
+    },
+    /// The field is present, even if `null`.
+    Exists,
+    /// The field is absent.
+    Missing,
+    /// The value is a string starting with `value`.
+    StartsWith {
+        /// Required prefix
+        value: String,
+    },
+    /// The value is a string ending with `value`.
+    EndsWith {
+        /// Required suffix
+        value: String,
+    },
+    /// The value is a string containing `value`.
+    Contains {
+        /// Required substring
+        value: String,
+    },
+    /// The value is an array with an element equal to `value`.
+    ArrayContains {
+        /// Required element
+        value: Value,
+    },
+}
+
+impl FieldPredicate {
+    /// Apply the predicate to the value found at a path, or `None` if the path
+    /// does not exist.
+    pub fn test(&self, value: Option<&Value>) -> bool {
+        let Some(value) = value else {
+            return matches!(
+                self,
+                FieldPredicate::Missing | FieldPredicate::NotEquals { .. }
+            );
+        };
+        match self {
+            FieldPredicate::Equals { value: expected } => json_eq(value, expected),
+            FieldPredicate::NotEquals { value: rejected } => !json_eq(value, rejected),
+            FieldPredicate::GreaterThan { value: bound } => number(value, bound, Ordering::is_gt),
+            FieldPredicate::GreaterOrEqual { value: bound } => {
+                number(value, bound, Ordering::is_ge)
+            }
+            FieldPredicate::LessThan { value: bound } => number(value, bound, Ordering::is_lt),
+            FieldPredicate::LessOrEqual { value: bound } => number(value, bound, Ordering::is_le),
+            FieldPredicate::In { values } => values.iter().any(|v| json_eq(value, v)),
+            FieldPredicate::Matches { pattern } => string(value, |s| pattern.is_match(s)),
+            FieldPredicate::Exists => true,
+            FieldPredicate::Missing => false,
+            FieldPredicate::StartsWith { value: prefix } => {
+                string(value, |s| s.starts_with(prefix))
+            }
+            FieldPredicate::EndsWith { value: suffix } => string(value, |s| s.ends_with(suffix)),
+            FieldPredicate::Contains { value: needle } => string(value, |s| s.contains(needle)),
+            FieldPredicate::ArrayContains { value: element } => value
+                .as_array()
+                .is_some_and(|items| items.iter().any(|item| json_eq(item, element))),
+        }
+    }
+
+    /// Relative evaluation cost, used as the default condition priority.
+    /// Regular expressions and set lookups rank after simple comparisons.
+    pub fn cost(&self) -> u32 {
+        match self {
+            FieldPredicate::Matches { .. } => 30,
+            FieldPredicate::In { .. } | FieldPredicate::ArrayContains { .. } => 20,
+            _ => 10,
+        }
+    }
+}
+
+/// JSON equality that compares numbers by value.
+fn json_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => compare_numbers(x, y) == Some(Ordering::Equal),
+        (Value::Array(xs), Value::Array(ys)) => {
+            xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| json_eq(x, y))
+        }
+        (Value::Object(xs), Value::Object(ys)) => {
+            xs.len() == ys.len()
+                && xs
+                    .iter()
+                    .all(|(key, x)| ys.get(key).is_some_and(|y| json_eq(x, y)))
+        }
+        _ => a == b,
+    }
+}
+
+/// Order two numbers: exactly if both are integers, as `f64` otherwise.
+fn compare_numbers(x: &Number, y: &Number) -> Option<Ordering> {
+    fn integer(n: &Number) -> Option<i128> {
+        n.as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from))
+    }
+    match (integer(x), integer(y)) {
+        (Some(x), Some(y)) => Some(x.cmp(&y)),
+        _ => x.as_f64()?.partial_cmp(&y.as_f64()?),
+    }
+}
+
+fn number(value: &Value, bound: &Number, test: impl FnOnce(Ordering) -> bool) -> bool {
+    match value {
+        Value::Number(n) => compare_numbers(n, bound).is_some_and(test),
+        _ => false,
+    }
+}
+
+fn string(value: &Value, test: impl FnOnce(&str) -> bool) -> bool {
+    value.as_str().is_some_and(test)
+}
+
+impl Display for FieldPredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldPredicate::Equals { value } => write!(f, "== {value}"),
+            FieldPredicate::NotEquals { value } => write!(f, "!= {value}"),
+            FieldPredicate::GreaterThan { value } => write!(f, "> {value}"),
+            FieldPredicate::GreaterOrEqual { value } => write!(f, ">= {value}"),
+            FieldPredicate::LessThan { value } => write!(f, "< {value}"),
+            FieldPredicate::LessOrEqual { value } => write!(f, "<= {value}"),
+            FieldPredicate::In { values } => write!(f, "in {}", Value::from(values.clone())),
+            FieldPredicate::Matches { pattern } => write!(f, "matches /{}/", pattern.as_str()),
+            FieldPredicate::Exists => f.write_str("exists"),
+            FieldPredicate::Missing => f.write_str("is missing"),
+            FieldPredicate::StartsWith { value } => write!(f, "starts with {value:?}"),
+            FieldPredicate::EndsWith { value } => write!(f, "ends with {value:?}"),
+            FieldPredicate::Contains { value } => write!(f, "contains {value:?}"),
+            FieldPredicate::ArrayContains { value } => write!(f, "has element {value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_absent_fields() {
+        let absent = [
+            (FieldPredicate::Missing, true),
+            (FieldPredicate::NotEquals { value: json!(1) }, true),
+            (FieldPredicate::Exists, false),
+            (FieldPredicate::Equals { value: Value::Null }, false),
+            (FieldPredicate::LessThan { value: 0.into() }, false),
+        ];
+        for (predicate, expected) in absent {
+            assert_eq!(predicate.test(None), expected, "{predicate}");
+        }
+        assert!(FieldPredicate::Exists.test(Some(&Value::Null)));
+    }
+
+    #[test]
+    fn test_numbers_compare_by_value() {
+        let one = FieldPredicate::Equals { value: json!(1) };
+        assert!(one.test(Some(&json!(1.0))));
+        assert!(FieldPredicate::ArrayContains { value: json!(2) }.test(Some(&json!([1, 2.0]))));
+        assert!(FieldPredicate::Equals {
+            value: json!({ "n": [1] })
+        }
+        .test(Some(&json!({ "n": [1.0] }))));
+        assert!(!FieldPredicate::GreaterThan { value: 18.into() }.test(Some(&json!(18))));
+        let half = Number::from_f64(17.5).unwrap();
+        assert!(FieldPredicate::GreaterThan { value: half }.test(Some(&json!(18))));
+    }
+
+    #[test]
+    fn test_large_integers_compare_exactly() {
+        let id: u64 = 9_007_199_254_740_993;
+        let equals = FieldPredicate::Equals { value: json!(id) };
+        assert!(equals.test(Some(&json!(id))));
+        assert!(!equals.test(Some(&json!(id - 1))));
+        assert!(FieldPredicate::In {
+            values: vec![json!(id - 1), json!(id)]
+        }
+        .test(Some(&json!(id))));
+
+        let after = FieldPredicate::GreaterThan {
+            value: (id - 1).into(),
+        };
+        assert!(after.test(Some(&json!(id))));
+        assert!(!after.test(Some(&json!(id - 1))));
+        assert!(FieldPredicate::LessThan { value: id.into() }.test(Some(&json!(id - 1))));
+        assert!(FieldPredicate::LessThan { value: 0.into() }.test(Some(&json!(i64::MIN))));
+    }
+
+    #[test]
+    fn test_string_predicates_reject_other_kinds() {
+        let value = Some(&json!(42));
+        assert!(!FieldPredicate::Contains {
+            value: "4".to_string()
+        }
+        .test(value));
+        assert!(!FieldPredicate::StartsWith {
+            value: "4".to_string()
+        }
+        .test(value));
+        assert!(!FieldPredicate::Matches {
+            pattern: Pattern::new("4").unwrap()
+        }
+        .test(value));
+        assert!(FieldPredicate::EndsWith {
+            value: ".com".to_string()
+        }
+        .test(Some(&json!("a@b.com"))));
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let predicates = json!([
+            { "op": "eq", "value": "paid" },
+            { "op": "lte", "value": 5.5 },
+            { "op": "matches", "pattern": "^v\\d+$" },
+            { "op": "missing" },
+            { "op": "array_contains", "value": "vip" }
+        ]);
+        let parsed: Vec<FieldPredicate> = serde_json::from_value(predicates.clone()).unwrap();
+        assert_eq!(parsed[2].to_string(), "matches /^v\\d+$/");
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), predicates);
+
+        let err =
+            serde_json::from_value::<FieldPredicate>(json!({ "op": "matches", "pattern": "(" }));
+        assert!(err.unwrap_err().to_string().contains("invalid pattern '('"));
+    }
+}
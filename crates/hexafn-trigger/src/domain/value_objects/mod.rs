@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+mod attribute_condition;
+mod compound_condition;
+mod compound_type;
+mod dsl_error;
+mod field_condition;
+mod field_path;
+mod field_predicate;
+mod payload_schema;
+mod trigger_context;
+mod trigger_definition;
+mod trigger_error;
+mod trigger_evaluation;
+
+pub use attribute_condition::{AttributeCondition, EventAttribute};
+pub use compound_condition::CompoundCondition;
+pub use compound_type::CompoundType;
+pub use dsl_error::{DslError, Span};
+pub use field_condition::FieldCondition;
+pub use field_path::FieldPath;
+pub use field_predicate::{FieldPredicate, Pattern};
+pub use payload_schema::PayloadSchema;
+pub use trigger_context::TriggerContext;
+pub use trigger_definition::TriggerDefinition;
+pub use trigger_error::TriggerError;
+pub use trigger_evaluation::TriggerEvaluation;
@@ -0,0 +1,196 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # PayloadSchema (Trigger Module)
+//!
+//! This module defines [`PayloadSchema`], the declared shape of the event
+//! payloads a trigger expression is written against. It maps field paths to a
+//! [`ParamKind`] and is what the [`TriggerExpression`](crate::TriggerExpression)
+//! type checker consults for every `payload.*` reference.
+//!
+//! A field nested under one declared as an object (or `any`) is accepted with
+//! kind `any`. Fields that are not declared at all are rejected unless
+//! [`PayloadSchema::allow_unknown`] is set; [`PayloadSchema::any`] accepts every
+//! path, which turns type checking of payload fields off.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::ParamKind;
+//! use hexafn_trigger::{FieldPath, PayloadSchema};
+//! use serde_json::json;
+//!
+//! let schema: PayloadSchema = serde_json::from_value(json!({
+//!     "fields": { "total": "number", "customer": "object", "customer.tier": "string" }
+//! }))
+//! .unwrap();
+//!
+//! let kind = |path: &str| schema.kind_of(&FieldPath::parse(path).unwrap());
+//! assert_eq!(kind("total"), Ok(ParamKind::Number));
+//! assert_eq!(kind("customer.tier"), Ok(ParamKind::String));
+//! assert_eq!(kind("customer.address.city"), Ok(ParamKind::Any));
+//! assert_eq!(kind("total.cents"), Err("payload field 'total' is a number".to_string()));
+//! assert_eq!(kind("country"), Err("unknown payload field 'country'".to_string()));
+//! ```
+
+use crate::domain::value_objects::{FieldPath, TriggerError};
+use hexafn_core::ParamKind;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Declared payload fields and their kinds.
+///
+/// The default schema declares nothing and rejects every payload field.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawPayloadSchema", into = "RawPayloadSchema")]
+pub struct PayloadSchema {
+    fields: Vec<(FieldPath, ParamKind)>,
+    allow_unknown: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawPayloadSchema {
+    #[serde(default)]
+    fields: BTreeMap<String, ParamKind>,
+    #[serde(default)]
+    allow_unknown: bool,
+}
+
+impl PayloadSchema {
+    /// Create a schema declaring no fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a schema that accepts every field with kind `any`.
+    pub fn any() -> Self {
+        Self::new().allow_unknown()
+    }
+
+    /// Declare the field at `path`, replacing an earlier declaration.
+    ///
+    /// # Errors
+    ///
+    /// [`TriggerError::InvalidPath`] if `path` cannot be parsed.
+    pub fn with_field(mut self, path: &str, kind: ParamKind) -> Result<Self, TriggerError> {
+        let path = FieldPath::parse(path)?;
+        self.fields.retain(|(declared, _)| *declared != path);
+        self.fields.push((path, kind));
+        Ok(self)
+    }
+
+    /// Accept undeclared fields with kind `any`.
+    pub fn allow_unknown(mut self) -> Self {
+        self.allow_unknown = true;
+        self
+    }
+
+    /// Declared fields, in declaration order.
+    pub fn fields(&self) -> impl Iterator<Item = (&FieldPath, ParamKind)> {
+        self.fields.iter().map(|(path, kind)| (path, *kind))
+    }
+
+    /// Kind of the value at `path`, or why the path is not part of the schema.
+    pub fn kind_of(&self, path: &FieldPath) -> Result<ParamKind, String> {
+        let segments = path.segments();
+        let mut container: Option<(&FieldPath, ParamKind)> = None;
+        for (declared, kind) in &self.fields {
+            let prefix = declared.segments();
+            if prefix == segments {
+                return Ok(*kind);
+            }
+            let longer = container.map_or(true, |(best, _)| prefix.len() > best.segments().len());
+            if segments.starts_with(prefix) && longer {
+                container = Some((declared, *kind));
+            }
+        }
+        match container {
+            Some((_, ParamKind::Object | ParamKind::Array | ParamKind::Any)) => Ok(ParamKind::Any),
+            Some((declared, kind)) => Err(format!("payload field '{declared}' is {kind}")),
+            None if self.allow_unknown => Ok(ParamKind::Any),
+            None => Err(format!("unknown payload field '{path}'")),
+        }
+    }
+}
+
+impl TryFrom<RawPayloadSchema> for PayloadSchema {
+    type Error = TriggerError;
+
+    fn try_from(raw: RawPayloadSchema) -> Result<Self, Self::Error> {
+        let mut schema = PayloadSchema {
+            allow_unknown: raw.allow_unknown,
+            ..PayloadSchema::new()
+        };
+        for (path, kind) in raw.fields {
+            schema = schema.with_field(&path, kind)?;
+        }
+        Ok(schema)
+    }
+}
+
+impl From<PayloadSchema> for RawPayloadSchema {
+    fn from(schema: PayloadSchema) -> Self {
+        RawPayloadSchema {
+            fields: schema
+                .fields
+                .into_iter()
+                .map(|(path, kind)| (path.into(), kind))
+                .collect(),
+            allow_unknown: schema.allow_unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(text: &str) -> FieldPath {
+        FieldPath::parse(text).unwrap()
+    }
+
+    #[test]
+    fn test_longest_declared_prefix_wins() {
+        let schema = PayloadSchema::new()
+            .with_field("order", ParamKind::Object)
+            .unwrap()
+            .with_field("/order/id", ParamKind::String)
+            .unwrap();
+        assert_eq!(schema.kind_of(&path("order.id")), Ok(ParamKind::String));
+        assert_eq!(
+            schema.kind_of(&path("order.id.x")),
+            Err("payload field '/order/id' is a string".to_string())
+        );
+        assert_eq!(schema.kind_of(&path("order.lines[0]")), Ok(ParamKind::Any));
+    }
+
+    #[test]
+    fn test_allow_unknown() {
+        assert_eq!(
+            PayloadSchema::any().kind_of(&path("x.y")),
+            Ok(ParamKind::Any)
+        );
+        assert!(PayloadSchema::new().kind_of(&path("x")).is_err());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let schema = PayloadSchema::new()
+            .with_field("total", ParamKind::Number)
+            .unwrap()
+            .allow_unknown();
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({ "fields": { "total": "number" }, "allow_unknown": true })
+        );
+        assert_eq!(
+            serde_json::from_value::<PayloadSchema>(value).unwrap(),
+            schema
+        );
+        assert!(serde_json::from_value::<PayloadSchema>(
+            serde_json::json!({ "fields": { "a..b": "string" } })
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # TriggerContext (Trigger Module)
+//!
+//! This module defines [`TriggerContext`], the typed input that
+//! [`Trigger`](crate::Trigger)s, [`TriggerCondition`](crate::TriggerCondition)s and
+//! [`TriggerEvaluator`](crate::TriggerEvaluator)s evaluate against.
+//!
+//! A context pairs the incoming event, as an [`EventEnvelope`] carrying its type,
+//! payload, headers and timestamp, with the [`PipelineContext`] of the run the
+//! event belongs to. Both are borrowed when the caller already holds them; a
+//! context built from an event alone owns a fresh pipeline context whose
+//! metadata records that event as the origin.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{EventEnvelope, PipelineContext};
+//! use hexafn_trigger::TriggerContext;
+//! use serde_json::json;
+//!
+//! let event = EventEnvelope::new("order.created", json!({ "total": 120 }))
+//!     .with_header("source", "checkout");
+//! let mut pipeline = PipelineContext::new();
+//! pipeline.set("tenant".to_string(), json!("acme")).unwrap();
+//!
+//! let context = TriggerContext::new(&event, &pipeline);
+//! assert_eq!(context.event_type(), "order.created");
+//! assert_eq!(context.payload()["total"], 120);
+//! assert_eq!(context.header("source"), Some("checkout"));
+//! assert_eq!(context.pipeline().get("tenant"), Some(&json!("acme")));
+//!
+//! let bare = TriggerContext::for_event(&event);
+//! assert_eq!(bare.pipeline().metadata().event_type.as_deref(), Some("order.created"));
+//! ```
+
+use chrono::{DateTime, Utc};
+use hexafn_core::{Event, EventEnvelope, PipelineContext};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// Event and pipeline state a trigger is evaluated against.
+#[derive(Debug, Clone)]
+pub struct TriggerContext<'a> {
+    event: Cow<'a, EventEnvelope>,
+    pipeline: Cow<'a, PipelineContext>,
+}
+
+impl<'a> TriggerContext<'a> {
+    /// Evaluate `event` within the run described by `pipeline`.
+    pub fn new(event: &'a EventEnvelope, pipeline: &'a PipelineContext) -> Self {
+        Self {
+            event: Cow::Borrowed(event),
+            pipeline: Cow::Borrowed(pipeline),
+        }
+    }
+
+    /// Evaluate `event` outside any pipeline run.
+    pub fn for_event(event: &'a EventEnvelope) -> Self {
+        Self {
+            event: Cow::Borrowed(event),
+            pipeline: Cow::Owned(PipelineContext::from_event(event)),
+        }
+    }
+
+    /// Evaluate any [`Event`] outside any pipeline run, capturing it into an
+    /// envelope without headers.
+    pub fn from_event<E: Event + ?Sized>(event: &E) -> TriggerContext<'static> {
+        TriggerContext {
+            event: Cow::Owned(EventEnvelope::from_event(event)),
+            pipeline: Cow::Owned(PipelineContext::from_event(event)),
+        }
+    }
+
+    /// The incoming event.
+    pub fn event(&self) -> &EventEnvelope {
+        &self.event
+    }
+
+    /// Type of the incoming event, e.g. `order.created`.
+    pub fn event_type(&self) -> &str {
+        &self.event.event_type
+    }
+
+    /// Payload of the incoming event.
+    pub fn payload(&self) -> &Value {
+        &self.event.payload
+    }
+
+    /// Headers of the incoming event.
+    pub fn headers(&self) -> &BTreeMap<String, String> {
+        &self.event.headers
+    }
+
+    /// Header value for `name`, if present.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.event.header(name)
+    }
+
+    /// When the incoming event occurred.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.event.timestamp
+    }
+
+    /// State of the pipeline run the event belongs to.
+    pub fn pipeline(&self) -> &PipelineContext {
+        &self.pipeline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hexafn_core::EventId;
+    use serde_json::json;
+
+    struct Ping(EventId);
+
+    impl Event for Ping {
+        fn event_type(&self) -> &str {
+            "ping"
+        }
+        fn event_id(&self) -> &EventId {
+            &self.0
+        }
+        fn timestamp(&self) -> DateTime<Utc> {
+            DateTime::UNIX_EPOCH
+        }
+        fn payload(&self) -> Value {
+            json!({ "seq": 7 })
+        }
+    }
+
+    #[test]
+    fn test_from_event_owns_its_parts() {
+        let context = TriggerContext::from_event(&Ping(EventId::new()));
+        assert_eq!(context.event_type(), "ping");
+        assert_eq!(context.payload(), &json!({ "seq": 7 }));
+        assert_eq!(context.timestamp(), DateTime::UNIX_EPOCH);
+        assert!(context.headers().is_empty());
+        assert_eq!(
+            context.pipeline().metadata().event_id.as_ref(),
+            Some(&context.event().id)
+        );
+    }
+}
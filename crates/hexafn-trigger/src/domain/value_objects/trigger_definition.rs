@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # TriggerDefinition (Trigger Module)
+//!
+//! This module defines [`TriggerDefinition`], the serializable form of an
+//! [`ExpressionTrigger`] as it appears in configuration files: an id, a name,
+//! the `when` expression and the [`PayloadSchema`] it is checked against.
+//!
+//! `active` defaults to `true`. Without a `schema`, payload fields are not
+//! type-checked.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_trigger::{Trigger, TriggerDefinition};
+//! use serde_json::json;
+//!
+//! let definition: TriggerDefinition = serde_json::from_value(json!({
+//!     "id": "eu-orders",
+//!     "name": "EU orders",
+//!     "when": "event.type == \"order.created\" && payload.region == \"EU\"",
+//!     "schema": { "fields": { "region": "string" } }
+//! }))
+//! .unwrap();
+//! let trigger = definition.build().unwrap();
+//! assert_eq!(trigger.id(), "eu-orders");
+//! assert!(trigger.is_active());
+//!
+//! let typo = TriggerDefinition { when: "payload.regon == \"EU\"".to_string(), ..definition };
+//! assert_eq!(
+//!     typo.build().unwrap_err().to_string(),
+//!     "1:1: unknown payload field 'regon'"
+//! );
+//! ```
+
+use crate::domain::entities::ExpressionTrigger;
+use crate::domain::value_objects::{DslError, PayloadSchema};
+use serde::{Deserialize, Serialize};
+
+/// Declarative trigger, as written in configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriggerDefinition {
+    /// Unique trigger id
+    pub id: String,
+    /// Human-readable name
+    pub name: String,
+    /// Whether the trigger is active
+    #[serde(default = "active_by_default")]
+    pub active: bool,
+    /// Trigger expression the event must satisfy
+    pub when: String,
+    /// Payload fields `when` may reference
+    #[serde(default = "PayloadSchema::any")]
+    pub schema: PayloadSchema,
+}
+
+fn active_by_default() -> bool {
+    true
+}
+
+impl TriggerDefinition {
+    /// Parse and compile the definition into a trigger.
+    ///
+    /// # Errors
+    ///
+    /// [`DslError`] if `when` does not parse or does not type-check against
+    /// `schema`.
+    pub fn build(&self) -> Result<ExpressionTrigger, DslError> {
+        Ok(ExpressionTrigger::new(
+            self.id.as_str(),
+            self.name.as_str(),
+            &self.when,
+            &self.schema,
+        )?
+        .with_active(self.active))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::contracts::Trigger;
+    use serde_json::json;
+
+    #[test]
+    fn test_defaults() {
+        let definition: TriggerDefinition = serde_json::from_value(json!({
+            "id": "t",
+            "name": "T",
+            "active": false,
+            "when": "payload.anything.at.all == 1"
+        }))
+        .unwrap();
+        assert_eq!(definition.schema, PayloadSchema::any());
+        assert!(!definition.build().unwrap().is_active());
+    }
+}
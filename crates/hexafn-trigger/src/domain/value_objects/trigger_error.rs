@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # Trigger Errors (Trigger Module)
+//!
+//! This module defines [`TriggerError`], the concrete [`HexaError`] raised by
+//! the trigger crate when a condition is built from invalid parts or a trigger
+//! registry operation names the wrong trigger.
+//!
+//! All variants use hierarchical `trigger.*` error codes.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::{HexaError, HexaErrorKind};
+//! use hexafn_trigger::FieldPath;
+//!
+//! let error = FieldPath::parse("user..age").unwrap_err();
+//! assert_eq!(error.error_code(), "trigger.condition.invalid_path");
+//! assert_eq!(error.error_kind(), HexaErrorKind::Validation);
+//! ```
+
+use hexafn_core::{HexaError, HexaErrorKind, HexaErrorSeverity};
+use thiserror::Error;
+
+/// Errors produced by the trigger crate.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TriggerError {
+    /// A field path is neither a valid JSON pointer nor a valid dotted path.
+    #[error("invalid field path '{path}': {reason}")]
+    InvalidPath {
+        /// The rejected path
+        path: String,
+        /// Why it was rejected
+        reason: String,
+    },
+
+    /// A regular expression does not compile.
+    #[error("invalid pattern '{pattern}': {reason}")]
+    InvalidPattern {
+        /// The rejected pattern
+        pattern: String,
+        /// Compiler message
+        reason: String,
+    },
+
+    /// A trigger with this id is already registered.
+    #[error("trigger '{id}' is already registered")]
+    DuplicateTrigger {
+        /// The conflicting id
+        id: String,
+    },
+
+    /// No trigger with this id is registered.
+    #[error("trigger '{id}' is not registered")]
+    TriggerNotFound {
+        /// The unknown id
+        id: String,
+    },
+}
+
+impl HexaError for TriggerError {
+    fn error_code(&self) -> &str {
+        match self {
+            TriggerError::InvalidPath { .. } => "trigger.condition.invalid_path",
+            TriggerError::InvalidPattern { .. } => "trigger.condition.invalid_pattern",
+            TriggerError::DuplicateTrigger { .. } => "trigger.registry.duplicate",
+            TriggerError::TriggerNotFound { .. } => "trigger.registry.not_found",
+        }
+    }
+
+    fn error_message(&self) -> &str {
+        match self {
+            TriggerError::InvalidPath { .. } => "Condition field path is invalid",
+            TriggerError::InvalidPattern { .. } => "Condition pattern is not a valid regex",
+            TriggerError::DuplicateTrigger { .. } => "Trigger id is already registered",
+            TriggerError::TriggerNotFound { .. } => "The requested trigger could not be found",
+        }
+    }
+
+    fn error_kind(&self) -> HexaErrorKind {
+        match self {
+            TriggerError::InvalidPath { .. } | TriggerError::InvalidPattern { .. } => {
+                HexaErrorKind::Validation
+            }
+            TriggerError::DuplicateTrigger { .. } => HexaErrorKind::Conflict,
+            TriggerError::TriggerNotFound { .. } => HexaErrorKind::NotFound,
+        }
+    }
+
+    fn error_severity(&self) -> HexaErrorSeverity {
+        HexaErrorSeverity::Medium
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2025 Husamettin ARABACI
+// SPDX-License-Identifier: MIT
+
+//! # TriggerEvaluation (Trigger Module)
+//!
+//! This module defines [`TriggerEvaluation`], the outcome of evaluating every
+//! registered trigger against one incoming event with
+//! [`TriggerRegistry::evaluate_event`](crate::TriggerRegistry::evaluate_event).
+//!
+//! It lists the triggers that fired, in registration order, and the triggers
+//! whose evaluation failed together with their errors. A failing trigger does
+//! not stop the others from being evaluated.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hexafn_core::EventEnvelope;
+//! use hexafn_trigger::{ExpressionTrigger, PayloadSchema, TriggerContext, TriggerEvaluator, TriggerRegistry};
+//! use serde_json::json;
+//!
+//! let mut registry = TriggerRegistry::new();
+//! let trigger = ExpressionTrigger::new("vip", "VIP", "payload.vip", &PayloadSchema::any()).unwrap();
+//! registry.register_trigger(Box::new(trigger)).unwrap();
+//!
+//! let event = EventEnvelope::new("signup", json!({ "vip": true }));
+//! let evaluation = registry.evaluate_event(&TriggerContext::for_event(&event));
+//! assert_eq!(evaluation.fired, vec!["vip".to_string()]);
+//! assert!(evaluation.has_fired("vip"));
+//! assert!(evaluation.is_ok());
+//! ```
+
+use hexafn_core::HexaError;
+
+/// Triggers that fired for one event.
+#[derive(Debug, Default)]
+pub struct TriggerEvaluation {
+    /// Ids of the triggers that fired, in registration order
+    pub fired: Vec<String>,
+    /// Ids of the triggers whose evaluation failed, with the error
+    pub failed: Vec<(String, Box<dyn HexaError>)>,
+    /// Number of triggers evaluated after index lookup
+    pub evaluated: usize,
+}
+
+impl TriggerEvaluation {
+    /// Whether the trigger with this id fired.
+    pub fn has_fired(&self, id: &str) -> bool {
+        self.fired.iter().any(|fired| fired == id)
+    }
+
+    /// Whether every evaluated trigger completed without error.
+    pub fn is_ok(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
@@ -5,3 +5,21 @@ pub mod domain;
 pub use domain::contracts::Trigger;
 pub use domain::contracts::TriggerCondition;
 pub use domain::contracts::TriggerEvaluator;
+
+pub use domain::entities::ExpressionTrigger;
+
+pub use domain::services::TriggerExpression;
+pub use domain::services::TriggerRegistry;
+
+pub use domain::value_objects::CompoundCondition;
+pub use domain::value_objects::CompoundType;
+pub use domain::value_objects::FieldCondition;
+pub use domain::value_objects::FieldPath;
+pub use domain::value_objects::PayloadSchema;
+pub use domain::value_objects::TriggerContext;
+pub use domain::value_objects::TriggerDefinition;
+pub use domain::value_objects::TriggerError;
+pub use domain::value_objects::TriggerEvaluation;
+pub use domain::value_objects::{AttributeCondition, EventAttribute};
+pub use domain::value_objects::{DslError, Span};
+pub use domain::value_objects::{FieldPredicate, Pattern};